@@ -7,7 +7,8 @@
 
 use std::sync::Arc;
 
-use runtime_common::{AccountId, Balance, Block, Nonce};
+use polkadot_primitives::Moment;
+use runtime_common::{AccountId, Balance, Block, IncrementableU256, Nonce};
 
 use sc_client_api::AuxStore;
 use sc_transaction_pool_api::TransactionPool;
@@ -39,10 +40,19 @@ where
 		+ Sync
 		+ 'static,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: pallet_marketplace_rpc::MarketplaceRuntimeApi<
+			Block,
+			AccountId,
+			Balance,
+			Moment,
+			IncrementableU256,
+			u32,
+		>,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + Sync + Send + 'static,
 {
+	use pallet_marketplace_rpc::{Marketplace, MarketplaceApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
@@ -50,6 +60,7 @@ where
 	let FullDeps { client, pool } = deps;
 
 	module.merge(System::new(client.clone(), pool).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(Marketplace::new(client).into_rpc())?;
 	Ok(module)
 }
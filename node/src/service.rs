@@ -38,7 +38,8 @@ use sp_runtime::{app_crypto::AppCrypto, traits::BlakeTwo256};
 use substrate_prometheus_endpoint::Registry;
 
 // Local Runtime types
-use runtime_common::{AccountId, AuraId, Balance, Block, Hash, Nonce};
+use polkadot_primitives::Moment;
+use runtime_common::{AccountId, AuraId, Balance, Block, Hash, IncrementableU256, Nonce};
 
 /// Testnet Native executor type.
 pub struct TestnetRuntimeExecutor;
@@ -224,7 +225,14 @@ where
 		+ cumulus_primitives_aura::AuraUnincludedSegmentApi<Block>
 		+ cumulus_primitives_core::CollectCollationInfo<Block>
 		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
-		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+		+ pallet_marketplace_rpc::MarketplaceRuntimeApi<
+			Block,
+			AccountId,
+			Balance,
+			Moment,
+			IncrementableU256,
+			u32,
+		> + substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	sc_client_api::StateBackendFor<TFullBackend<Block>, Block>:
 		sc_client_api::StateBackend<BlakeTwo256>,
 	Executor: NativeExecutionDispatch + 'static,
@@ -497,7 +505,14 @@ where
 		+ sp_consensus_aura::AuraApi<Block, <<AuraId as AppCrypto>::Pair as Pair>::Public>
 		+ cumulus_primitives_core::CollectCollationInfo<Block>
 		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
-		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+		+ pallet_marketplace_rpc::MarketplaceRuntimeApi<
+			Block,
+			AccountId,
+			Balance,
+			Moment,
+			IncrementableU256,
+			u32,
+		> + substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 {
 	// NOTE: because we use Aura here explicitly, we can use `CollatorSybilResistance::Resistant`
 	// when starting the network.
@@ -571,7 +586,14 @@ where
 		+ cumulus_primitives_core::CollectCollationInfo<Block>
 		+ sp_consensus_aura::AuraApi<Block, <<AuraId as AppCrypto>::Pair as Pair>::Public>
 		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
-		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+		+ pallet_marketplace_rpc::MarketplaceRuntimeApi<
+			Block,
+			AccountId,
+			Balance,
+			Moment,
+			IncrementableU256,
+			u32,
+		> + substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 {
 	start_node_impl::<RuntimeApi, Executor, _, _, Net>(
 		parachain_config,
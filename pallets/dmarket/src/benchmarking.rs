@@ -69,10 +69,11 @@ fn mint_nft<T: Config>(nft_id: ItemId, caller: T::AccountId) {
 #[benchmarks(where T::AccountId: From<AccountId20>, T::Signature: From<EthereumSignature>)]
 pub mod benchmarks {
 	use super::*;
+	use crate::eip712::TypedData;
 	use account::{AccountId20, EthereumSignature, EthereumSigner};
 	use pallet_timestamp::Pallet as Timestamp;
 
-	use sp_runtime::traits::IdentifyAccount;
+	use sp_runtime::traits::{Hash, IdentifyAccount};
 
 	fn sign_trade<T: Config>(
 		sender: &T::AccountId,
@@ -84,10 +85,39 @@ pub mod benchmarks {
 	where
 		T::Signature: From<EthereumSignature>,
 	{
-		let ask_message: Vec<u8> = Dmarket::<T>::get_ask_message(sender, fee_address, trade);
+		// EIP-712 signatures are only verified after the SCALE encoded message fails to verify.
+		let ask_message = Dmarket::<T>::ask_message(sender, fee_address, trade);
+		let ask_hashed = keccak_256(&ask_message.signing_payload());
+
+		let bid_message = Dmarket::<T>::bid_message(sender, fee_address, trade);
+		let bid_hashed = keccak_256(&bid_message.signing_payload());
+
+		TradeSignatures {
+			ask_signature: EthereumSignature::from(
+				ecdsa_sign_prehashed(0.into(), &seller_signer, &ask_hashed).unwrap(),
+			)
+			.into(),
+			bid_signature: EthereumSignature::from(
+				ecdsa_sign_prehashed(1.into(), &buyer_signer, &bid_hashed).unwrap(),
+			)
+			.into(),
+		}
+	}
+
+	fn sign_batch_trade<T: Config>(
+		sender: &T::AccountId,
+		fee_address: &T::AccountId,
+		trade: &BatchTradeParamsOf<T>,
+		seller_signer: Public,
+		buyer_signer: Public,
+	) -> TradeSignatures<T::Signature>
+	where
+		T::Signature: From<EthereumSignature>,
+	{
+		let ask_message: Vec<u8> = Dmarket::<T>::get_batch_ask_message(sender, fee_address, trade);
 		let ask_hashed = keccak_256(&ask_message);
 
-		let bid_message: Vec<u8> = Dmarket::<T>::get_bid_message(sender, fee_address, trade);
+		let bid_message: Vec<u8> = Dmarket::<T>::get_batch_bid_message(sender, fee_address, trade);
 		let bid_hashed = keccak_256(&bid_message);
 
 		TradeSignatures {
@@ -126,7 +156,7 @@ pub mod benchmarks {
 	}
 
 	#[benchmark]
-	fn force_set_collection() {
+	fn force_enable_collection() {
 		let collection_id = T::BenchmarkHelper::collection(0);
 		let caller: T::AccountId = funded_and_whitelisted_account::<T>("caller", 0);
 		mint_nft::<T>(1, caller);
@@ -134,11 +164,24 @@ pub mod benchmarks {
 		#[extrinsic_call]
 		_(RawOrigin::Root, collection_id);
 
-		assert_last_event::<T>(Event::CollectionUpdated { collection_id }.into());
+		assert_last_event::<T>(Event::CollectionEnabled { collection_id }.into());
 	}
 
 	#[benchmark]
-	fn execute_trade() {
+	fn force_disable_collection() {
+		let collection_id = T::BenchmarkHelper::collection(0);
+		let caller: T::AccountId = funded_and_whitelisted_account::<T>("caller", 0);
+		mint_nft::<T>(1, caller);
+		assert_ok!(Dmarket::<T>::force_enable_collection(RawOrigin::Root.into(), collection_id));
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, collection_id);
+
+		assert_last_event::<T>(Event::CollectionDisabled { collection_id }.into());
+	}
+
+	#[benchmark]
+	fn execute_trade(n: Linear<0, { T::MaxFeeRecipients::get() }>) {
 		let sender: T::AccountId = funded_and_whitelisted_account::<T>("sender", 0);
 		let (seller, seller_public, buyer, buyer_public) = trade_participants::<T>();
 		let fee_address: T::AccountId = funded_and_whitelisted_account::<T>("fee_address", 0);
@@ -146,15 +189,27 @@ pub mod benchmarks {
 		let collection_id = T::BenchmarkHelper::collection(0);
 		let item = 1;
 		mint_nft::<T>(item, seller.clone());
-		assert_ok!(Dmarket::<T>::force_set_collection(RawOrigin::Root.into(), collection_id));
+		assert_ok!(Dmarket::<T>::force_enable_collection(RawOrigin::Root.into(), collection_id));
 
 		let expiration = Timestamp::<T>::get() + T::BenchmarkHelper::timestamp(1000);
+		let ed = <T as Config>::Currency::minimum_balance();
+		let fee_recipients: FeeRecipientsOf<T> = (0..n)
+			.map(|i| FeeRecipient { account: account("recipient", i, SEED), amount: ed })
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
 		let trade = TradeParams {
-			price: BalanceOf::<T>::from(100u16),
+			price: ed * BalanceOf::<T>::from(100u16),
 			fee: BalanceOf::<T>::from(1u8),
 			ask_expiration: expiration,
 			bid_expiration: expiration,
+			collection: collection_id,
 			item,
+			ask_nonce: 0,
+			bid_nonce: 0,
+			fee_recipients,
+			buyer_fee: BalanceOf::<T>::from(1u8),
+			asset: None,
 		};
 		let signatures =
 			sign_trade::<T>(&sender, &fee_address, &trade, seller_public, buyer_public);
@@ -170,9 +225,182 @@ pub mod benchmarks {
 		);
 
 		assert_last_event::<T>(
-			Event::Trade { seller, buyer, item, price: trade.price, fee: trade.fee }.into(),
+			Event::Trade {
+				seller,
+				buyer,
+				collection: collection_id,
+				item,
+				price: trade.price,
+				fee: trade.fee,
+				fee_recipients: trade.fee_recipients,
+				buyer_fee: trade.buyer_fee,
+				asset: trade.asset,
+			}
+			.into(),
 		);
 	}
 
+	#[benchmark]
+	fn execute_batch_trade(
+		n: Linear<1, { T::MaxBatchItems::get() }>,
+		f: Linear<0, { T::MaxFeeRecipients::get() }>,
+	) {
+		let sender: T::AccountId = funded_and_whitelisted_account::<T>("sender", 0);
+		let (seller, seller_public, buyer, buyer_public) = trade_participants::<T>();
+		let fee_address: T::AccountId = funded_and_whitelisted_account::<T>("fee_address", 0);
+
+		let collection_id = T::BenchmarkHelper::collection(0);
+		mint_nft::<T>(0, seller.clone());
+		for item in 1..n {
+			assert_ok!(Nfts::<T>::mint_into(
+				&collection_id,
+				&(item as ItemId),
+				&seller,
+				&ItemConfig::default(),
+				true
+			));
+		}
+		assert_ok!(Dmarket::<T>::force_enable_collection(RawOrigin::Root.into(), collection_id));
+
+		let expiration = Timestamp::<T>::get() + T::BenchmarkHelper::timestamp(1000);
+		let items: BatchItemsOf<T> = (0..n as ItemId).collect::<Vec<_>>().try_into().unwrap();
+		let ed = <T as Config>::Currency::minimum_balance();
+		let fee_recipients: FeeRecipientsOf<T> = (0..f)
+			.map(|i| FeeRecipient { account: account("recipient", i, SEED), amount: ed })
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		let trade = BatchTradeParams {
+			unit_price: ed * BalanceOf::<T>::from(100u16),
+			fee: BalanceOf::<T>::from(1u8),
+			collection: collection_id,
+			items: items.clone(),
+			max_quantity: n,
+			quantity: n,
+			ask_expiration: expiration,
+			bid_expiration: expiration,
+			ask_nonce: 0,
+			bid_nonce: 0,
+			fee_recipients,
+			buyer_fee: BalanceOf::<T>::from(1u8),
+			asset: None,
+		};
+		let signatures =
+			sign_batch_trade::<T>(&sender, &fee_address, &trade, seller_public, buyer_public);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(sender),
+			seller.clone(),
+			buyer.clone(),
+			trade.clone(),
+			signatures,
+			fee_address,
+		);
+
+		assert_last_event::<T>(
+			Event::BatchTrade {
+				seller,
+				buyer,
+				collection: collection_id,
+				items,
+				unit_price: trade.unit_price,
+				fee: trade.fee,
+				fee_recipients: trade.fee_recipients,
+				buyer_fee: trade.buyer_fee,
+				asset: trade.asset,
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn cancel_ask() {
+		let sender: T::AccountId = funded_and_whitelisted_account::<T>("sender", 0);
+		let (seller, seller_public, _, buyer_public) = trade_participants::<T>();
+		let fee_address: T::AccountId = funded_and_whitelisted_account::<T>("fee_address", 0);
+
+		let expiration = Timestamp::<T>::get() + T::BenchmarkHelper::timestamp(1000);
+		let trade = TradeParams {
+			price: BalanceOf::<T>::from(100u16),
+			fee: BalanceOf::<T>::from(1u8),
+			ask_expiration: expiration,
+			bid_expiration: expiration,
+			collection: T::BenchmarkHelper::collection(0),
+			item: 1,
+			ask_nonce: 0,
+			bid_nonce: 0,
+			fee_recipients: Default::default(),
+			buyer_fee: BalanceOf::<T>::from(0u8),
+			asset: None,
+		};
+		let signatures =
+			sign_trade::<T>(&sender, &fee_address, &trade, seller_public, buyer_public);
+		mint_nft::<T>(1, seller.clone());
+		let hash = Dmarket::<T>::hash_ask_data(&seller, &trade);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(seller.clone()), sender, fee_address, trade, signatures.ask_signature);
+
+		assert_last_event::<T>(Event::AskCancelled { who: seller, hash }.into());
+	}
+
+	#[benchmark]
+	fn cancel_bid() {
+		let sender: T::AccountId = funded_and_whitelisted_account::<T>("sender", 0);
+		let (_, seller_public, buyer, buyer_public) = trade_participants::<T>();
+		let fee_address: T::AccountId = funded_and_whitelisted_account::<T>("fee_address", 0);
+
+		let expiration = Timestamp::<T>::get() + T::BenchmarkHelper::timestamp(1000);
+		let trade = TradeParams {
+			price: BalanceOf::<T>::from(100u16),
+			fee: BalanceOf::<T>::from(1u8),
+			ask_expiration: expiration,
+			bid_expiration: expiration,
+			collection: T::BenchmarkHelper::collection(0),
+			item: 1,
+			ask_nonce: 0,
+			bid_nonce: 0,
+			fee_recipients: Default::default(),
+			buyer_fee: BalanceOf::<T>::from(0u8),
+			asset: None,
+		};
+		let signatures =
+			sign_trade::<T>(&sender, &fee_address, &trade, seller_public, buyer_public);
+		let hash = Dmarket::<T>::hash_bid_data(&buyer, &trade);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(buyer.clone()), sender, fee_address, trade, signatures.bid_signature);
+
+		assert_last_event::<T>(Event::BidCancelled { who: buyer, hash }.into());
+	}
+
+	#[benchmark]
+	fn bump_nonce() {
+		let caller: T::AccountId = funded_and_whitelisted_account::<T>("caller", 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert_last_event::<T>(Event::NonceBumped { who: caller, nonce: 1 }.into());
+	}
+
+	#[benchmark]
+	fn prune_next_closed_order() {
+		let caller: T::AccountId = funded_and_whitelisted_account::<T>("caller", 0);
+		let expiration = Timestamp::<T>::get() + T::BenchmarkHelper::timestamp(1000);
+		let hash = T::Hashing::hash_of(&expiration);
+		ClosedAsks::<T>::insert(hash, OrderData { caller: caller.clone(), fee_address: caller });
+		Dmarket::<T>::index_closed_order(ClosedOrder::Ask(hash), expiration);
+		Timestamp::<T>::set_timestamp(Dmarket::<T>::expiration_slot(expiration));
+
+		#[block]
+		{
+			assert!(Dmarket::<T>::prune_next_closed_order());
+		}
+
+		assert!(!ClosedAsks::<T>::contains_key(hash));
+	}
+
 	impl_benchmark_test_suite!(Dmarket, crate::mock::new_test_ext(), crate::mock::Test);
 }
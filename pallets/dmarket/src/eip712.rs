@@ -0,0 +1,372 @@
+//! EIP-712 typed data for the Dmarket ask and bid messages.
+//!
+//! Ethereum wallets display typed data as human-readable fields instead of an opaque byte blob.
+//! The signed digest is `keccak256(0x1901 ‖ domainSeparator ‖ hashStruct(message))`, and since
+//! `EthereumSignature` hashes the signed bytes with keccak256 on verification, the signing
+//! payload is the preimage `0x1901 ‖ domainSeparator ‖ hashStruct(message)`.
+//!
+//! Numeric values are converted from their SCALE encoding, which is little endian for the
+//! fixed-width unsigned integers used by the messages, and accounts are expected to be 20-byte
+//! Ethereum addresses. Fee recipients are encoded as an array of `FeeRecipient` structs, and the
+//! asset of the trade as the SCALE encoding of the optional asset id, i.e. `0x00` for the native
+//! currency.
+
+use crate::{AskMessage, BidMessage, Domain, FeeRecipient};
+use alloc::{
+	format,
+	string::{String, ToString},
+	vec::Vec,
+};
+use parity_scale_codec::Encode;
+use sp_io::hashing::keccak_256;
+
+/// The name of the EIP-712 signing domain.
+pub const DOMAIN_NAME: &str = "Dmarket";
+
+/// The EIP-712 type of the signing domain.
+pub const DOMAIN_TYPE: &str = "EIP712Domain(string name,string version)";
+
+/// The struct type of a fee recipient.
+pub const FEE_RECIPIENT: StructType =
+	StructType { name: "FeeRecipient", members: &[("account", "address"), ("amount", "uint256")] };
+
+/// A struct type referenced by the fields of a message.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct StructType {
+	/// The name of the struct type.
+	pub name: &'static str,
+	/// The names and Solidity types of the struct members.
+	pub members: &'static [(&'static str, &'static str)],
+}
+
+impl StructType {
+	/// The EIP-712 encoding of the struct type, e.g. `FeeRecipient(address account,...)`.
+	pub fn encode_type(&self) -> String {
+		let members = self
+			.members
+			.iter()
+			.map(|(name, solidity_type)| format!("{solidity_type} {name}"))
+			.collect::<Vec<_>>()
+			.join(",");
+		format!("{}({})", self.name, members)
+	}
+
+	/// The `hashStruct` of a struct holding `values`, in the order of the members.
+	pub fn hash_struct(&self, values: &[Value]) -> [u8; 32] {
+		let mut data = keccak_256(self.encode_type().as_bytes()).to_vec();
+		for value in values {
+			data.extend_from_slice(&value.encode_data());
+		}
+		keccak_256(&data)
+	}
+}
+
+/// A value of a typed data field.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Value {
+	/// A `bytes8` value.
+	Bytes8(Domain),
+	/// An `address` value, holding the encoded account.
+	Address(Vec<u8>),
+	/// A `bytes` value.
+	Bytes(Vec<u8>),
+	/// A `uint<bits>` value, in big endian.
+	Uint(u16, [u8; 32]),
+	/// An array of structs, each holding the values of the struct members.
+	Array(StructType, Vec<Vec<Value>>),
+}
+
+impl Value {
+	/// Builds an `address` value out of an encodable account.
+	pub fn address<A: Encode>(account: &A) -> Self {
+		Value::Address(account.encode())
+	}
+
+	/// Builds a `FeeRecipient[]` value out of a list of fee recipients.
+	pub fn fee_recipients<A: Encode, B: Encode>(recipients: &[FeeRecipient<A, B>]) -> Self {
+		let recipients = recipients
+			.iter()
+			.map(|recipient| {
+				[Value::address(&recipient.account), Value::uint(256, &recipient.amount)].into()
+			})
+			.collect();
+		Value::Array(FEE_RECIPIENT, recipients)
+	}
+
+	/// Builds a `uint<bits>` value out of an encodable unsigned integer.
+	pub fn uint<V: Encode>(bits: u16, value: &V) -> Self {
+		let mut word = [0u8; 32];
+		for (i, byte) in value.encode().into_iter().take(32).enumerate() {
+			word[31 - i] = byte;
+		}
+		Value::Uint(bits, word)
+	}
+
+	/// The Solidity type of the value.
+	pub fn solidity_type(&self) -> String {
+		match self {
+			Value::Bytes8(_) => "bytes8".into(),
+			Value::Address(_) => "address".into(),
+			Value::Bytes(_) => "bytes".into(),
+			Value::Uint(bits, _) => format!("uint{bits}"),
+			Value::Array(struct_type, _) => format!("{}[]", struct_type.name),
+		}
+	}
+
+	/// The value encoded as a 32-byte word, as defined by `encodeData`.
+	pub fn encode_data(&self) -> [u8; 32] {
+		let mut word = [0u8; 32];
+		match self {
+			Value::Bytes8(bytes) => word[..8].copy_from_slice(bytes),
+			Value::Address(account) => {
+				let len = account.len().min(32);
+				word[32 - len..].copy_from_slice(&account[..len]);
+			},
+			Value::Bytes(bytes) => word = keccak_256(bytes),
+			Value::Uint(_, value) => word = *value,
+			Value::Array(struct_type, elements) => {
+				let hashes = elements
+					.iter()
+					.flat_map(|values| struct_type.hash_struct(values))
+					.collect::<Vec<_>>();
+				word = keccak_256(&hashes);
+			},
+		}
+		word
+	}
+
+	/// The value as it appears in the JSON message of the typed data.
+	pub fn to_json(&self) -> String {
+		match self {
+			Value::Bytes8(bytes) => format!("\"0x{}\"", hex(bytes)),
+			Value::Address(account) => format!("\"0x{}\"", hex(account)),
+			Value::Bytes(bytes) => format!("\"0x{}\"", hex(bytes)),
+			Value::Uint(_, value) => format!("\"{}\"", decimal(*value)),
+			Value::Array(struct_type, elements) => {
+				let elements = elements
+					.iter()
+					.map(|values| {
+						let members = struct_type
+							.members
+							.iter()
+							.zip(values)
+							.map(|((name, _), value)| format!("\"{}\":{}", name, value.to_json()))
+							.collect::<Vec<_>>()
+							.join(",");
+						format!("{{{members}}}")
+					})
+					.collect::<Vec<_>>()
+					.join(",");
+				format!("[{elements}]")
+			},
+		}
+	}
+}
+
+/// A message that can be signed as EIP-712 typed data.
+pub trait TypedData {
+	/// The name of the struct type, e.g. `Ask`.
+	const PRIMARY_TYPE: &'static str;
+
+	/// The version of the signed message schema, used as the version of the signing domain.
+	fn version(&self) -> u8;
+
+	/// The named fields of the message, in their declaration order.
+	fn fields(&self) -> Vec<(&'static str, Value)>;
+
+	/// The struct types referenced by the fields, sorted by name.
+	fn struct_types(&self) -> Vec<StructType> {
+		let mut struct_types = Vec::new();
+		let referenced = self.fields().into_iter().filter_map(|(_, value)| match value {
+			Value::Array(struct_type, _) => Some(struct_type),
+			_ => None,
+		});
+		for struct_type in referenced {
+			if !struct_types.contains(&struct_type) {
+				struct_types.push(struct_type);
+			}
+		}
+		struct_types.sort_by_key(|struct_type| struct_type.name);
+		struct_types
+	}
+
+	/// The EIP-712 encoding of the struct type, e.g. `Ask(bytes8 domain,...)FeeRecipient(...)`.
+	fn type_string(&self) -> String {
+		let members = self
+			.fields()
+			.iter()
+			.map(|(name, value)| format!("{} {}", value.solidity_type(), name))
+			.collect::<Vec<_>>()
+			.join(",");
+		let referenced = self
+			.struct_types()
+			.iter()
+			.map(StructType::encode_type)
+			.collect::<Vec<_>>()
+			.concat();
+		format!("{}({}){}", Self::PRIMARY_TYPE, members, referenced)
+	}
+
+	/// The `hashStruct` of the message.
+	fn hash_struct(&self) -> [u8; 32] {
+		let mut data = keccak_256(self.type_string().as_bytes()).to_vec();
+		for (_, value) in self.fields() {
+			data.extend_from_slice(&value.encode_data());
+		}
+		keccak_256(&data)
+	}
+
+	/// The separator of the signing domain.
+	fn domain_separator(&self) -> [u8; 32] {
+		let mut data = keccak_256(DOMAIN_TYPE.as_bytes()).to_vec();
+		data.extend_from_slice(&keccak_256(DOMAIN_NAME.as_bytes()));
+		data.extend_from_slice(&keccak_256(self.version().to_string().as_bytes()));
+		keccak_256(&data)
+	}
+
+	/// The bytes to be verified against an EIP-712 signature of the message.
+	fn signing_payload(&self) -> Vec<u8> {
+		let mut payload = [0x19, 0x01].to_vec();
+		payload.extend_from_slice(&self.domain_separator());
+		payload.extend_from_slice(&self.hash_struct());
+		payload
+	}
+
+	/// The typed data JSON, as expected by `eth_signTypedData_v4`.
+	fn typed_data_json(&self) -> Vec<u8> {
+		let fields = self.fields();
+		let types = fields
+			.iter()
+			.map(|(name, value)| {
+				format!("{{\"name\":\"{}\",\"type\":\"{}\"}}", name, value.solidity_type())
+			})
+			.collect::<Vec<_>>()
+			.join(",");
+		let referenced = self
+			.struct_types()
+			.iter()
+			.map(|struct_type| {
+				let members = struct_type
+					.members
+					.iter()
+					.map(|(name, solidity_type)| {
+						format!("{{\"name\":\"{name}\",\"type\":\"{solidity_type}\"}}")
+					})
+					.collect::<Vec<_>>()
+					.join(",");
+				format!(",\"{}\":[{}]", struct_type.name, members)
+			})
+			.collect::<Vec<_>>()
+			.concat();
+		let message = fields
+			.iter()
+			.map(|(name, value)| format!("\"{}\":{}", name, value.to_json()))
+			.collect::<Vec<_>>()
+			.join(",");
+
+		format!(
+			concat!(
+				"{{\"types\":{{\"EIP712Domain\":[{{\"name\":\"name\",\"type\":\"string\"}},",
+				"{{\"name\":\"version\",\"type\":\"string\"}}],\"{primary}\":[{types}]{referenced}}},",
+				"\"primaryType\":\"{primary}\",",
+				"\"domain\":{{\"name\":\"{name}\",\"version\":\"{version}\"}},",
+				"\"message\":{{{message}}}}}"
+			),
+			primary = Self::PRIMARY_TYPE,
+			name = DOMAIN_NAME,
+			version = self.version(),
+		)
+		.into_bytes()
+	}
+}
+
+impl<Account, Amount, CollectionId, ItemId, Expiration, AssetId> TypedData
+	for AskMessage<Account, Amount, CollectionId, ItemId, Expiration, AssetId>
+where
+	Account: Encode,
+	Amount: Encode,
+	CollectionId: Encode,
+	ItemId: Encode,
+	Expiration: Encode,
+	AssetId: Encode,
+{
+	const PRIMARY_TYPE: &'static str = "Ask";
+
+	fn version(&self) -> u8 {
+		self.version
+	}
+
+	fn fields(&self) -> Vec<(&'static str, Value)> {
+		[
+			("domain", Value::Bytes8(self.domain)),
+			("sender", Value::address(&self.sender)),
+			("feeAddress", Value::address(&self.fee_address)),
+			("collection", Value::uint(256, &self.collection)),
+			("item", Value::uint(128, &self.item)),
+			("price", Value::uint(256, &self.price)),
+			("asset", Value::Bytes(self.asset.encode())),
+			("feeRecipients", Value::fee_recipients(&self.fee_recipients)),
+			("nonce", Value::uint(32, &self.nonce)),
+			("expiration", Value::uint(64, &self.expiration)),
+		]
+		.into()
+	}
+}
+
+impl<Account, Amount, CollectionId, ItemId, Expiration, AssetId> TypedData
+	for BidMessage<Account, Amount, CollectionId, ItemId, Expiration, AssetId>
+where
+	Account: Encode,
+	Amount: Encode,
+	CollectionId: Encode,
+	ItemId: Encode,
+	Expiration: Encode,
+	AssetId: Encode,
+{
+	const PRIMARY_TYPE: &'static str = "Bid";
+
+	fn version(&self) -> u8 {
+		self.version
+	}
+
+	fn fields(&self) -> Vec<(&'static str, Value)> {
+		[
+			("domain", Value::Bytes8(self.domain)),
+			("sender", Value::address(&self.sender)),
+			("feeAddress", Value::address(&self.fee_address)),
+			("collection", Value::uint(256, &self.collection)),
+			("item", Value::uint(128, &self.item)),
+			("price", Value::uint(256, &self.price)),
+			("asset", Value::Bytes(self.asset.encode())),
+			("fee", Value::uint(256, &self.fee)),
+			("feeRecipients", Value::fee_recipients(&self.fee_recipients)),
+			("buyerFee", Value::uint(256, &self.buyer_fee)),
+			("nonce", Value::uint(32, &self.nonce)),
+			("expiration", Value::uint(64, &self.expiration)),
+		]
+		.into()
+	}
+}
+
+fn hex(bytes: &[u8]) -> String {
+	bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Formats a big endian unsigned integer in decimal.
+fn decimal(mut value: [u8; 32]) -> String {
+	let mut digits = Vec::new();
+	while value.iter().any(|byte| *byte != 0) {
+		let mut remainder = 0u16;
+		for byte in value.iter_mut() {
+			let current = (remainder << 8) | *byte as u16;
+			*byte = (current / 10) as u8;
+			remainder = current % 10;
+		}
+		digits.push(b'0' + remainder as u8);
+	}
+	if digits.is_empty() {
+		digits.push(b'0');
+	}
+	digits.reverse();
+	String::from_utf8(digits).unwrap_or_default()
+}
@@ -7,6 +7,8 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -15,6 +17,8 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod eip712;
+pub mod migrations;
 mod types;
 use parity_scale_codec::Codec;
 pub use types::*;
@@ -29,14 +33,17 @@ pub mod pallet {
 	use crate::Item;
 
 	use super::*;
+	use crate::eip712::TypedData;
 	use frame_support::{
 		ensure,
 		pallet_prelude::*,
 		traits::{
 			fungible::{Inspect, Mutate},
+			fungibles,
 			nonfungibles_v2::{Inspect as NftInspect, Transfer},
-			tokens::Preservation::Preserve,
+			tokens::{Fortitude::Polite, Preservation, Preservation::Preserve, Provenance::Extant},
 		},
+		weights::WeightMeter,
 	};
 	use frame_system::{ensure_signed, pallet_prelude::*};
 
@@ -45,11 +52,15 @@ pub mod pallet {
 	use sp_runtime::traits::Hash;
 	use sp_runtime::{
 		DispatchError,
-		traits::{IdentifyAccount, Verify},
+		traits::{CheckedAdd, CheckedMul, IdentifyAccount, Saturating, Verify, Zero},
 	};
 	use sp_std::vec::Vec;
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -65,6 +76,10 @@ pub mod pallet {
 		/// The currency trait.
 		type Currency: Inspect<Self::AccountId> + Mutate<Self::AccountId>;
 
+		/// The fungible assets trades can be settled in, besides the native currency.
+		type Assets: fungibles::Inspect<Self::AccountId, Balance = BalanceOf<Self>>
+			+ fungibles::Mutate<Self::AccountId>;
+
 		/// Off-Chain signature type.
 		///
 		/// Can verify whether a `Self::Signer` created a signature.
@@ -79,6 +94,21 @@ pub mod pallet {
 		#[pallet::constant]
 		type Domain: Get<Domain>;
 
+		/// The granularity of the closed order expiration index.
+		///
+		/// Closed orders are indexed by their expiration rounded up to a multiple of this value,
+		/// so the `on_idle` sweeper can walk the index in order.
+		#[pallet::constant]
+		type ExpirationGranularity: Get<Self::Moment>;
+
+		/// The maximum number of items a seller can offer in a single batch trade.
+		#[pallet::constant]
+		type MaxBatchItems: Get<u32>;
+
+		/// The maximum number of fee recipients paid out of the price of a trade.
+		#[pallet::constant]
+		type MaxFeeRecipients: Get<u32>;
+
 		/// Type representing the weight of this pallet
 		type WeightInfo: WeightInfo;
 
@@ -87,28 +117,94 @@ pub mod pallet {
 		type BenchmarkHelper: BenchmarkHelper<Self::CollectionId, Self::Moment>;
 	}
 
+	/// The executed asks by the hash of their trade data, kept until their expiration to prevent
+	/// replays.
 	#[pallet::storage]
 	pub type ClosedAsks<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, OrderDataOf<T>>;
 
+	/// The executed bids by the hash of their trade data, kept until their expiration to prevent
+	/// replays.
 	#[pallet::storage]
 	pub type ClosedBids<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, OrderDataOf<T>>;
 
+	/// The quantity left to be sold of the partially filled batch asks, by the hash of their
+	/// trade data.
+	///
+	/// Once the whole quantity is sold the ask moves to `ClosedAsks`.
 	#[pallet::storage]
-	pub type DmarketCollection<T: Config> = StorageValue<_, T::CollectionId, OptionQuery>;
+	pub type RemainingAskQuantity<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, u32>;
+
+	/// The nonce every ask and bid signed by an account must carry to be executed.
+	///
+	/// Bumping it voids all the outstanding orders signed by the account.
+	#[pallet::storage]
+	pub type Nonces<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// An index of the closed asks and bids by their expiration slot.
+	///
+	/// The expiration slot of an order is its expiration rounded up to a multiple of
+	/// `ExpirationGranularity`.
+	#[pallet::storage]
+	pub type ClosedOrderExpirations<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::Moment,
+		Blake2_128Concat,
+		ClosedOrder<T::Hash>,
+		(),
+		OptionQuery,
+	>;
+
+	/// The next expiration slot to be swept by the `on_idle` hook.
+	#[pallet::storage]
+	pub type NextExpirationSlot<T: Config> = StorageValue<_, T::Moment, OptionQuery>;
+
+	/// The collections whose items can be traded through Dmarket.
+	#[pallet::storage]
+	pub type DmarketCollections<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, (), OptionQuery>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// The fee signer account was updated.
+		/// The Dmarket collection was updated by the retired `force_set_collection` call.
 		CollectionUpdated { collection_id: T::CollectionId },
 		/// A successful trade is executed.
 		Trade {
 			buyer: T::AccountId,
 			seller: T::AccountId,
+			collection: T::CollectionId,
 			item: Item,
 			price: BalanceOf<T>,
 			fee: BalanceOf<T>,
+			fee_recipients: FeeRecipientsOf<T>,
+			buyer_fee: BalanceOf<T>,
+			/// The asset the trade was settled in, the native currency if `None`.
+			asset: Option<AssetIdOf<T>>,
 		},
+		/// A collection was enabled for Dmarket trades.
+		CollectionEnabled { collection_id: T::CollectionId },
+		/// A collection was disabled for Dmarket trades.
+		CollectionDisabled { collection_id: T::CollectionId },
+		/// A successful batch trade is executed.
+		BatchTrade {
+			buyer: T::AccountId,
+			seller: T::AccountId,
+			collection: T::CollectionId,
+			items: BatchItemsOf<T>,
+			unit_price: BalanceOf<T>,
+			fee: BalanceOf<T>,
+			fee_recipients: FeeRecipientsOf<T>,
+			buyer_fee: BalanceOf<T>,
+			/// The asset the trade was settled in, the native currency if `None`.
+			asset: Option<AssetIdOf<T>>,
+		},
+		/// An ask was cancelled by its signer before being executed.
+		AskCancelled { who: T::AccountId, hash: T::Hash },
+		/// A bid was cancelled by its signer before being executed.
+		BidCancelled { who: T::AccountId, hash: T::Hash },
+		/// The nonce of an account was bumped, voiding its outstanding signed orders.
+		NonceBumped { who: T::AccountId, nonce: u32 },
 	}
 
 	#[pallet::error]
@@ -135,17 +231,66 @@ pub mod pallet {
 		BuyerIsSeller,
 		/// Invalid Signed message.
 		BadSignedMessage,
-		/// Dmarket collection already set to the provided value.
-		CollectionAlreadyInUse,
-		/// Dmarket collection has not been set.
-		CollectionNotSet,
+		/// The collection is already enabled for Dmarket trades.
+		CollectionAlreadyEnabled,
+		/// The collection is not enabled for Dmarket trades.
+		CollectionNotEnabled,
 		/// The provided Dmarket collect was not found.
 		CollectionNotFound,
+		/// The quantity must be non-zero and within the maximum quantity, which can't exceed the
+		/// number of items offered.
+		InvalidQuantity,
+		/// The requested quantity exceeds the quantity left in the ask.
+		QuantityUnavailable,
+		/// The nonce of the ask doesn't match the current nonce of the seller.
+		InvalidAskNonce,
+		/// The nonce of the bid doesn't match the current nonce of the buyer.
+		InvalidBidNonce,
+		/// The signature provided doesn't match the order signed by the caller.
+		InvalidSignature,
+		/// The fees paid by the seller exceed the price of the trade.
+		FeesExceedPrice,
+		/// The asset of the trade doesn't exist.
+		UnknownAsset,
+		/// The trade has more fee recipients than allowed.
+		TooManyFeeRecipients,
+		/// The item can't be transferred, e.g. because it is locked.
+		ItemNotTransferable,
+		/// The unit price times the quantity of a batch trade overflows.
+		PriceOverflow,
+		/// The seller can't pay the fees out of the price without being reaped.
+		SellerBalanceTooLow,
+		/// A payment of the trade can't be received, e.g. because it is below the existential
+		/// deposit of a new account.
+		PaymentNotReceivable,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+
+			while meter.can_consume(<T as Config>::WeightInfo::prune_next_closed_order()) {
+				if !Self::prune_next_closed_order() {
+					break;
+				}
+				meter.consume(<T as Config>::WeightInfo::prune_next_closed_order());
+			}
+
+			meter.consumed()
+		}
+
+		fn integrity_test() {
+			assert!(
+				!T::ExpirationGranularity::get().is_zero(),
+				"ExpirationGranularity must be greater than zero."
+			);
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// Sets the Dmarket collection.
+		/// Enables a collection for Dmarket trades.
 		///
 		/// Only the root origin can execute this function.
 		///
@@ -153,15 +298,17 @@ pub mod pallet {
 		/// - The collection must already exist, otherwise the extrinsic will fail.
 		///
 		/// Parameters:
-		/// - `collection_id`: The collectionID of the NFT collection to be set as the Dmarket Collection.
+		/// - `collection_id`: The collectionID of the NFT collection to be added to the Dmarket Collections.
 		///
 		///
-		/// Emits CollectionUpdated when successful.
+		/// Emits CollectionEnabled when successful.
 		///
-		/// Weight: `WeightInfo::force_set_collection` (defined in the `Config` trait).
-		#[pallet::call_index(0)]
-		#[pallet::weight(<T as Config>::WeightInfo::force_set_collection())]
-		pub fn force_set_collection(
+		/// Weight: `WeightInfo::force_enable_collection` (defined in the `Config` trait).
+		///
+		/// The call index 0 of the retired `force_set_collection` call is not reused.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::force_enable_collection())]
+		pub fn force_enable_collection(
 			origin: OriginFor<T>,
 			collection_id: T::CollectionId,
 		) -> DispatchResult {
@@ -171,19 +318,56 @@ pub mod pallet {
 				.ok_or(Error::<T>::CollectionNotFound)?;
 
 			ensure!(
-				DmarketCollection::<T>::get().as_ref() != Some(&collection_id),
-				Error::<T>::CollectionAlreadyInUse
+				!DmarketCollections::<T>::contains_key(&collection_id),
+				Error::<T>::CollectionAlreadyEnabled
 			);
 
-			DmarketCollection::<T>::put(collection_id);
-			Self::deposit_event(Event::CollectionUpdated { collection_id });
+			DmarketCollections::<T>::insert(&collection_id, ());
+			Self::deposit_event(Event::CollectionEnabled { collection_id });
 			Ok(())
 		}
 
-		/// Execute a trade between a seller and a buyer for a specific NFT (item) in the configured DmarketCollection.
+		/// Disables a collection for Dmarket trades.
+		///
+		/// Only the root origin can execute this function.
+		///
+		/// Parameters:
+		/// - `collection_id`: The collectionID of the NFT collection to be removed from the Dmarket Collections.
+		///
+		/// Emits CollectionDisabled when successful.
+		///
+		/// Weight: `WeightInfo::force_disable_collection` (defined in the `Config` trait).
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::force_disable_collection())]
+		pub fn force_disable_collection(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(
+				DmarketCollections::<T>::take(&collection_id).is_some(),
+				Error::<T>::CollectionNotEnabled
+			);
+
+			Self::deposit_event(Event::CollectionDisabled { collection_id });
+			Ok(())
+		}
+
+		/// Execute a trade between a seller and a buyer for a specific NFT (item) in one of the enabled Dmarket Collections.
+		///
+		/// The seller pays `trade.fee` to `fee_address` and each of `trade.fee_recipients` out of
+		/// the price, while the buyer pays `trade.buyer_fee` to `fee_address` on top of it. All
+		/// the transfers are made atomically, in `trade.asset` or in the native currency if it is
+		/// `None`.
 		///
 		/// Preconditions:
 		/// - The seller and buyer must be different accounts.
+		/// - The ask and bid must carry the current nonces of the seller and buyer.
+		/// - The fees paid by the seller must not exceed the price, and be payable out of it
+		///   without reaping the seller.
+		/// - The asset of the trade, if any, must exist.
+		/// - The collection of the NFT item must be enabled.
 		/// - The seller must be the current owner of the NFT item.
 		/// - The trade must not be expired, and signatures provided must be valid.
 		///
@@ -195,9 +379,12 @@ pub mod pallet {
 		/// - `signatures`: The signatures from both the seller and buyer authorizing the trade.
 		/// - `fee_address`: The account ID where the transaction fee will be transferred.
 		///
-		/// Signed message schema:
-		/// - Ask: (domain, sender, fee_address, item, price, expiration).
-		/// - Bid: (domain, sender, fee_address, item, price, fee, expiration).
+		/// Signed message schema (v5):
+		/// - Ask: (version, domain, sender, fee_address, collection, item, price, asset, fee_recipients, nonce, expiration).
+		/// - Bid: (version, domain, sender, fee_address, collection, item, price, asset, fee, fee_recipients, buyer_fee, nonce, expiration).
+		///
+		/// Each message can be signed either over its SCALE encoding or as EIP-712 typed data,
+		/// see the `eip712` module.
 		///
 		/// Only callable if origin matches `sender` in both Ask and Bid signed messages.
 		///
@@ -205,7 +392,7 @@ pub mod pallet {
 		///
 		/// Weight: `WeightInfo::execute_trade` (defined in the `Config` trait).
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::execute_trade())]
+		#[pallet::weight(<T as Config>::WeightInfo::execute_trade(trade.fee_recipients.len() as u32))]
 		pub fn execute_trade(
 			origin: OriginFor<T>,
 			seller: T::AccountId,
@@ -216,61 +403,326 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			ensure!(seller != buyer, Error::<T>::BuyerIsSeller);
+			let (ask_hash, bid_hash) =
+				Self::do_validate_trade(&who, &seller, &buyer, &trade, signatures, &fee_address)
+					.map_err(Error::<T>::from)?;
 
-			let timestamp = pallet_timestamp::Pallet::<T>::get();
-			ensure!(trade.ask_expiration > timestamp, Error::<T>::AskExpired);
-			ensure!(trade.bid_expiration > timestamp, Error::<T>::BidExpired);
+			let order_data: OrderDataOf<T> =
+				OrderData { caller: who, fee_address: fee_address.clone() };
+
+			//Store closed trades
+			ClosedAsks::<T>::insert(ask_hash, order_data.clone());
+			ClosedBids::<T>::insert(bid_hash, order_data);
+			Self::index_closed_order(ClosedOrder::Ask(ask_hash), trade.ask_expiration);
+			Self::index_closed_order(ClosedOrder::Bid(bid_hash), trade.bid_expiration);
+
+			<pallet_nfts::Pallet<T> as Transfer<T::AccountId>>::transfer(
+				&trade.collection,
+				&trade.item,
+				&buyer,
+			)?;
+			let asset = &trade.asset;
+			Self::transfer_funds(asset, &buyer, &seller, trade.price, Preserve)
+				.map_err(|_| Error::<T>::BuyerBalanceTooLow)?;
+			if !trade.buyer_fee.is_zero() {
+				Self::transfer_funds(asset, &buyer, &fee_address, trade.buyer_fee, Preserve)
+					.map_err(|_| Error::<T>::BuyerBalanceTooLow)?;
+			}
+			Self::transfer_funds(asset, &seller, &fee_address, trade.fee, Preserve)?;
+			for recipient in trade.fee_recipients.iter().filter(|r| !r.amount.is_zero()) {
+				Self::transfer_funds(
+					asset,
+					&seller,
+					&recipient.account,
+					recipient.amount,
+					Preserve,
+				)?;
+			}
+
+			Self::deposit_event(Event::Trade {
+				seller,
+				buyer,
+				collection: trade.collection,
+				item: trade.item,
+				price: trade.price,
+				fee: trade.fee,
+				fee_recipients: trade.fee_recipients,
+				buyer_fee: trade.buyer_fee,
+				asset: trade.asset,
+			});
+
+			Ok(())
+		}
 
-			// Pay fees to FeeAddress
-			let collection = DmarketCollection::<T>::get().ok_or(Error::<T>::CollectionNotSet)?;
+		/// Execute a trade of several identical items between a seller and a buyer.
+		///
+		/// The seller signs a unit price and the maximum quantity to be sold out of the offered
+		/// items, while the buyer signs the quantity to buy. The ask can be filled by several
+		/// trades until its maximum quantity is sold, with the items being transferred in the
+		/// order they were offered.
+		///
+		/// Preconditions:
+		/// - The seller and buyer must be different accounts.
+		/// - The ask and bid must carry the current nonces of the seller and buyer.
+		/// - The collection of the items must be enabled.
+		/// - The seller must be the current owner of the transferred items, which must be
+		///   transferable.
+		/// - The quantity must not exceed the quantity left in the ask.
+		/// - The trade must not be expired, and signatures provided must be valid.
+		///
+		/// Parameters:
+		/// - `origin`: The origin of the call, which must be part of the signed message of both seller and buyer.
+		/// - `seller`: The account ID of the seller who owns the items.
+		/// - `buyer`: The account ID of the buyer who will purchase the items.
+		/// - `trade`: The parameters of the batch trade, including the items, unit price, quantities and expiration times.
+		/// - `signatures`: The signatures from both the seller and buyer authorizing the trade.
+		/// - `fee_address`: The account ID where the transaction fee will be transferred.
+		///
+		/// The seller pays the fee and, for every unit sold, the amount of each fee recipient,
+		/// while the buyer pays the buyer fee on top of the price, all in the asset of the trade.
+		///
+		/// Signed message schema (v5):
+		/// - Ask: (version, domain, sender, fee_address, collection, items, unit_price, asset, fee_recipients, max_quantity, nonce, expiration).
+		/// - Bid: (version, domain, sender, fee_address, collection, items, unit_price, asset, quantity, fee, fee_recipients, buyer_fee, nonce, expiration).
+		///
+		/// Only callable if origin matches `sender` in both Ask and Bid signed messages.
+		///
+		/// Emits `BatchTrade` event upon successful execution.
+		///
+		/// Weight: `WeightInfo::execute_batch_trade` (defined in the `Config` trait).
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::execute_batch_trade(
+			trade.items.len() as u32,
+			trade.fee_recipients.len() as u32,
+		))]
+		pub fn execute_batch_trade(
+			origin: OriginFor<T>,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			trade: BatchTradeParamsOf<T>,
+			signatures: TradeSignatures<<T as Config>::Signature>,
+			fee_address: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-			let item_owner = pallet_nfts::Pallet::<T>::owner(collection, trade.item)
-				.ok_or(Error::<T>::ItemNotFound)?;
-			ensure!(seller == item_owner, Error::<T>::SellerNotItemOwner);
+			ensure!(
+				trade.quantity > 0
+					&& trade.quantity <= trade.max_quantity
+					&& trade.max_quantity as usize <= trade.items.len(),
+				Error::<T>::InvalidQuantity
+			);
 
-			let (ask_hash, bid_hash) = Self::hash_ask_bid_data(&trade);
+			let (ask_hash, bid_hash) = Self::hash_batch_ask_bid_data(&seller, &buyer, &trade);
 			ensure!(!ClosedAsks::<T>::contains_key(ask_hash), Error::<T>::AskAlreadyExecuted);
 			ensure!(!ClosedBids::<T>::contains_key(bid_hash), Error::<T>::BidAlreadyExecuted);
 
+			let partially_filled = RemainingAskQuantity::<T>::get(ask_hash);
+			let remaining = partially_filled.unwrap_or(trade.max_quantity);
+			ensure!(trade.quantity <= remaining, Error::<T>::QuantityUnavailable);
+
+			let sold = (trade.max_quantity - remaining) as usize;
+			let items: BatchItemsOf<T> = BoundedVec::truncate_from(
+				trade.items[sold..sold + trade.quantity as usize].to_vec(),
+			);
+			let price = Self::ensure_trade_executable(&seller, &buyer, &trade, &items)
+				.map_err(Error::<T>::from)?;
+
 			Self::verify_signature(
 				&seller,
-				&Self::get_ask_message(&who, &fee_address, &trade),
+				&Self::get_batch_ask_message(&who, &fee_address, &trade),
 				signatures.ask_signature,
 			)
 			.map_err(|_| Error::<T>::InvalidSellerSignature)?;
 
 			Self::verify_signature(
 				&buyer,
-				&Self::get_bid_message(&who, &fee_address, &trade),
+				&Self::get_batch_bid_message(&who, &fee_address, &trade),
 				signatures.bid_signature,
 			)
 			.map_err(|_| Error::<T>::InvalidBuyerSignature)?;
 
+			Self::ensure_trade_funded(&seller, &buyer, &fee_address, &trade, price)
+				.map_err(Error::<T>::from)?;
+
 			let order_data: OrderDataOf<T> =
 				OrderData { caller: who, fee_address: fee_address.clone() };
 
-			//Store closed trades
-			ClosedAsks::<T>::insert(ask_hash, order_data.clone());
+			// Track the quantity left in the ask, closing it once everything is sold
+			let left = remaining - trade.quantity;
+			if left.is_zero() {
+				RemainingAskQuantity::<T>::remove(ask_hash);
+				ClosedAsks::<T>::insert(ask_hash, order_data.clone());
+			} else {
+				RemainingAskQuantity::<T>::insert(ask_hash, left);
+			}
+			if partially_filled.is_none() {
+				Self::index_closed_order(ClosedOrder::Ask(ask_hash), trade.ask_expiration);
+			}
 			ClosedBids::<T>::insert(bid_hash, order_data);
+			Self::index_closed_order(ClosedOrder::Bid(bid_hash), trade.bid_expiration);
 
-			<pallet_nfts::Pallet<T> as Transfer<T::AccountId>>::transfer(
-				&collection,
-				&trade.item,
-				&buyer,
-			)?;
-			<T as crate::Config>::Currency::transfer(&buyer, &seller, trade.price, Preserve)
+			let collection = trade.collection;
+			let quantity = BalanceOf::<T>::from(trade.quantity);
+			for item in items.iter() {
+				<pallet_nfts::Pallet<T> as Transfer<T::AccountId>>::transfer(
+					&collection,
+					item,
+					&buyer,
+				)?;
+			}
+
+			let asset = &trade.asset;
+			Self::transfer_funds(asset, &buyer, &seller, price, Preserve)
 				.map_err(|_| Error::<T>::BuyerBalanceTooLow)?;
-			<T as crate::Config>::Currency::transfer(&seller, &fee_address, trade.fee, Preserve)?;
+			if !trade.buyer_fee.is_zero() {
+				Self::transfer_funds(asset, &buyer, &fee_address, trade.buyer_fee, Preserve)
+					.map_err(|_| Error::<T>::BuyerBalanceTooLow)?;
+			}
+			Self::transfer_funds(asset, &seller, &fee_address, trade.fee, Preserve)?;
+			for recipient in trade.fee_recipients.iter().filter(|r| !r.amount.is_zero()) {
+				Self::transfer_funds(
+					asset,
+					&seller,
+					&recipient.account,
+					recipient.amount.saturating_mul(quantity),
+					Preserve,
+				)?;
+			}
 
-			Self::deposit_event(Event::Trade {
+			Self::deposit_event(Event::BatchTrade {
 				seller,
 				buyer,
-				item: trade.item,
-				price: trade.price,
+				collection,
+				items,
+				unit_price: trade.unit_price,
 				fee: trade.fee,
+				fee_recipients: trade.fee_recipients,
+				buyer_fee: trade.buyer_fee,
+				asset: trade.asset,
+			});
+
+			Ok(())
+		}
+
+		/// Cancels an ask signed by the caller before it gets executed.
+		///
+		/// Only callable by the owner of the item.
+		///
+		/// The ask hash is registered in `ClosedAsks` until the ask expires, so the signed
+		/// message can no longer be used in a trade.
+		///
+		/// Parameters:
+		/// - `sender`: The account authorized to submit the trade in the signed message.
+		/// - `fee_address`: The fee account in the signed message.
+		/// - `trade`: The parameters of the trade the ask was signed for.
+		/// - `signature`: The signature of the ask, which must have been created by the caller.
+		///
+		/// Emits `AskCancelled` event upon successful execution.
+		///
+		/// Weight: `WeightInfo::cancel_ask` (defined in the `Config` trait).
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_ask())]
+		pub fn cancel_ask(
+			origin: OriginFor<T>,
+			sender: T::AccountId,
+			fee_address: T::AccountId,
+			trade: TradeParamsOf<T>,
+			signature: <T as Config>::Signature,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				trade.ask_expiration > pallet_timestamp::Pallet::<T>::get(),
+				Error::<T>::AskExpired
+			);
+			ensure!(
+				pallet_nfts::Pallet::<T>::owner(trade.collection, trade.item).as_ref()
+					== Some(&who),
+				Error::<T>::SellerNotItemOwner
+			);
+
+			let ask_hash = Self::hash_ask_data(&who, &trade);
+			ensure!(!ClosedAsks::<T>::contains_key(ask_hash), Error::<T>::AskAlreadyExecuted);
+
+			Self::verify_message(
+				&who,
+				&Self::ask_message(&sender, &fee_address, &trade),
+				signature,
+			)
+			.map_err(|_| Error::<T>::InvalidSignature)?;
+
+			ClosedAsks::<T>::insert(ask_hash, OrderData { caller: sender, fee_address });
+			Self::index_closed_order(ClosedOrder::Ask(ask_hash), trade.ask_expiration);
+
+			Self::deposit_event(Event::AskCancelled { who, hash: ask_hash });
+			Ok(())
+		}
+
+		/// Cancels a bid signed by the caller before it gets executed.
+		///
+		/// The bid hash is registered in `ClosedBids` until the bid expires, so the signed
+		/// message can no longer be used in a trade.
+		///
+		/// Parameters:
+		/// - `sender`: The account authorized to submit the trade in the signed message.
+		/// - `fee_address`: The fee account in the signed message.
+		/// - `trade`: The parameters of the trade the bid was signed for.
+		/// - `signature`: The signature of the bid, which must have been created by the caller.
+		///
+		/// Emits `BidCancelled` event upon successful execution.
+		///
+		/// Weight: `WeightInfo::cancel_bid` (defined in the `Config` trait).
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_bid())]
+		pub fn cancel_bid(
+			origin: OriginFor<T>,
+			sender: T::AccountId,
+			fee_address: T::AccountId,
+			trade: TradeParamsOf<T>,
+			signature: <T as Config>::Signature,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				trade.bid_expiration > pallet_timestamp::Pallet::<T>::get(),
+				Error::<T>::BidExpired
+			);
+
+			let bid_hash = Self::hash_bid_data(&who, &trade);
+			ensure!(!ClosedBids::<T>::contains_key(bid_hash), Error::<T>::BidAlreadyExecuted);
+
+			Self::verify_message(
+				&who,
+				&Self::bid_message(&sender, &fee_address, &trade),
+				signature,
+			)
+			.map_err(|_| Error::<T>::InvalidSignature)?;
+
+			ClosedBids::<T>::insert(bid_hash, OrderData { caller: sender, fee_address });
+			Self::index_closed_order(ClosedOrder::Bid(bid_hash), trade.bid_expiration);
+
+			Self::deposit_event(Event::BidCancelled { who, hash: bid_hash });
+			Ok(())
+		}
+
+		/// Bumps the nonce of the caller, voiding all the asks and bids it signed so far.
+		///
+		/// Orders signed afterwards must carry the new nonce.
+		///
+		/// Emits `NonceBumped` event upon successful execution.
+		///
+		/// Weight: `WeightInfo::bump_nonce` (defined in the `Config` trait).
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::bump_nonce())]
+		pub fn bump_nonce(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let nonce = Nonces::<T>::mutate(&who, |nonce| {
+				*nonce = nonce.wrapping_add(1);
+				*nonce
 			});
 
+			Self::deposit_event(Event::NonceBumped { who, nonce });
 			Ok(())
 		}
 	}
@@ -288,44 +740,606 @@ pub mod pallet {
 			Ok(())
 		}
 
-		pub fn hash_ask_bid_data(trade: &TradeParamsOf<T>) -> (T::Hash, T::Hash) {
-			let ask_hash =
-				T::Hashing::hash(&(trade.item, trade.price, trade.ask_expiration).encode());
-			let bid_hash = T::Hashing::hash(
-				&(trade.item, trade.price, trade.fee, trade.bid_expiration).encode(),
+		/// Verifies a signature of `message` by `who`, made either over its SCALE encoding or
+		/// over its EIP-712 typed data.
+		fn verify_message<M: Encode + TypedData>(
+			who: &T::AccountId,
+			message: &M,
+			signature: T::Signature,
+		) -> Result<(), DispatchError> {
+			Self::verify_signature(who, &message.encode(), signature.clone())
+				.or_else(|_| Self::verify_signature(who, &message.signing_payload(), signature))
+		}
+
+		/// Runs the checks shared by `execute_trade` and `execute_batch_trade` that don't depend
+		/// on the signed messages, for the `items` of `trade` being sold. A single trade is
+		/// checked as a batch trade of one item.
+		///
+		/// Returns the price of the sold items.
+		pub(crate) fn ensure_trade_executable<Items>(
+			seller: &T::AccountId,
+			buyer: &T::AccountId,
+			trade: &BatchTradeParams<
+				T::CollectionId,
+				BalanceOf<T>,
+				Items,
+				T::Moment,
+				FeeRecipientsOf<T>,
+				AssetIdOf<T>,
+			>,
+			items: &[Item],
+		) -> Result<BalanceOf<T>, TradeError> {
+			ensure!(seller != buyer, TradeError::BuyerIsSeller);
+
+			let timestamp = pallet_timestamp::Pallet::<T>::get();
+			ensure!(trade.ask_expiration > timestamp, TradeError::AskExpired);
+			ensure!(trade.bid_expiration > timestamp, TradeError::BidExpired);
+			ensure!(trade.ask_nonce == Nonces::<T>::get(seller), TradeError::InvalidAskNonce);
+			ensure!(trade.bid_nonce == Nonces::<T>::get(buyer), TradeError::InvalidBidNonce);
+
+			let quantity = BalanceOf::<T>::from(trade.quantity);
+			let price = trade.unit_price.checked_mul(&quantity).ok_or(TradeError::PriceOverflow)?;
+			let seller_fees =
+				trade.fee_recipients.iter().try_fold(trade.fee, |total, recipient| {
+					recipient
+						.amount
+						.checked_mul(&quantity)
+						.and_then(|amount| total.checked_add(&amount))
+				});
+			ensure!(seller_fees.is_some_and(|fees| fees <= price), TradeError::FeesExceedPrice);
+			if let Some(ref asset) = trade.asset {
+				ensure!(T::Assets::asset_exists(asset.clone()), TradeError::UnknownAsset);
+			}
+
+			let collection = trade.collection;
+			ensure!(
+				DmarketCollections::<T>::contains_key(&collection),
+				TradeError::CollectionNotEnabled
 			);
 
-			(ask_hash, bid_hash)
+			for item in items {
+				let item_owner = pallet_nfts::Pallet::<T>::owner(collection, *item)
+					.ok_or(TradeError::ItemNotFound)?;
+				ensure!(*seller == item_owner, TradeError::SellerNotItemOwner);
+				ensure!(
+					<pallet_nfts::Pallet<T> as NftInspect<T::AccountId>>::can_transfer(
+						&collection,
+						item
+					),
+					TradeError::ItemNotTransferable
+				);
+			}
+
+			Ok(price)
 		}
 
-		pub fn get_ask_message(
-			caller: &T::AccountId,
+		/// Checks that every payment of a trade of `price` can be made, the buyer paying the
+		/// price and the buyer fee while the seller, once credited with the price, pays the fee
+		/// and the fee recipients out of it without being reaped.
+		///
+		/// Every payment must also be receivable, an account paid several times only needing to
+		/// be able to receive the first payment.
+		pub(crate) fn ensure_trade_funded<Items>(
+			seller: &T::AccountId,
+			buyer: &T::AccountId,
 			fee_address: &T::AccountId,
+			trade: &BatchTradeParams<
+				T::CollectionId,
+				BalanceOf<T>,
+				Items,
+				T::Moment,
+				FeeRecipientsOf<T>,
+				AssetIdOf<T>,
+			>,
+			price: BalanceOf<T>,
+		) -> Result<(), TradeError> {
+			let asset = &trade.asset;
+			let quantity = BalanceOf::<T>::from(trade.quantity);
+
+			let buyer_payment = price.checked_add(&trade.buyer_fee);
+			let buyer_funds = Self::reducible_funds(asset, buyer);
+			ensure!(
+				buyer_payment.is_some_and(|payment| payment <= buyer_funds),
+				TradeError::BuyerBalanceTooLow
+			);
+
+			let recipients = trade
+				.fee_recipients
+				.iter()
+				.map(|recipient| (&recipient.account, recipient.amount.saturating_mul(quantity)));
+			let seller_fees = recipients
+				.clone()
+				.fold(trade.fee, |total, (_, amount)| total.saturating_add(amount));
+			ensure!(
+				seller_fees <= Self::reducible_funds_after_deposit(asset, seller, price),
+				TradeError::SellerBalanceTooLow
+			);
+
+			let mut credited = Vec::new();
+			let payments =
+				[(seller, price), (fee_address, trade.buyer_fee), (fee_address, trade.fee)]
+					.into_iter()
+					.chain(recipients)
+					.filter(|(_, amount)| !amount.is_zero());
+			for (who, amount) in payments {
+				if !credited.contains(&who) {
+					ensure!(
+						Self::can_receive_funds(asset, who, amount),
+						TradeError::PaymentNotReceivable
+					);
+					credited.push(who);
+				}
+			}
+
+			Ok(())
+		}
+
+		/// Runs every check of `execute_trade` without mutating state, returning the hashes of
+		/// the ask and the bid if the trade can be executed.
+		pub(crate) fn do_validate_trade(
+			who: &T::AccountId,
+			seller: &T::AccountId,
+			buyer: &T::AccountId,
 			trade: &TradeParamsOf<T>,
+			signatures: TradeSignatures<<T as Config>::Signature>,
+			fee_address: &T::AccountId,
+		) -> Result<(T::Hash, T::Hash), TradeError> {
+			let terms = BatchTradeParams {
+				unit_price: trade.price,
+				fee: trade.fee,
+				collection: trade.collection,
+				items: (),
+				max_quantity: 1,
+				quantity: 1,
+				ask_expiration: trade.ask_expiration,
+				bid_expiration: trade.bid_expiration,
+				ask_nonce: trade.ask_nonce,
+				bid_nonce: trade.bid_nonce,
+				fee_recipients: trade.fee_recipients.clone(),
+				buyer_fee: trade.buyer_fee,
+				asset: trade.asset.clone(),
+			};
+			Self::ensure_trade_executable(seller, buyer, &terms, &[trade.item])?;
+
+			let (ask_hash, bid_hash) = Self::hash_ask_bid_data(seller, buyer, trade);
+			ensure!(!ClosedAsks::<T>::contains_key(ask_hash), TradeError::AskAlreadyExecuted);
+			ensure!(!ClosedBids::<T>::contains_key(bid_hash), TradeError::BidAlreadyExecuted);
+
+			Self::verify_message(
+				seller,
+				&Self::ask_message(who, fee_address, trade),
+				signatures.ask_signature,
+			)
+			.map_err(|_| TradeError::InvalidSellerSignature)?;
+
+			Self::verify_message(
+				buyer,
+				&Self::bid_message(who, fee_address, trade),
+				signatures.bid_signature,
+			)
+			.map_err(|_| TradeError::InvalidBuyerSignature)?;
+
+			Self::ensure_trade_funded(seller, buyer, fee_address, &terms, trade.price)?;
+
+			Ok((ask_hash, bid_hash))
+		}
+
+		/// Checks whether a trade can be executed, without executing it.
+		pub fn validate_trade(
+			caller: &T::AccountId,
+			seller: &T::AccountId,
+			buyer: &T::AccountId,
+			trade: TradeParams<
+				T::CollectionId,
+				BalanceOf<T>,
+				Item,
+				T::Moment,
+				Vec<FeeRecipientOf<T>>,
+				AssetIdOf<T>,
+			>,
+			signatures: TradeSignatures<<T as Config>::Signature>,
+			fee_address: &T::AccountId,
+		) -> Result<(), TradeError> {
+			let trade = TradeParams {
+				price: trade.price,
+				fee: trade.fee,
+				collection: trade.collection,
+				item: trade.item,
+				ask_expiration: trade.ask_expiration,
+				bid_expiration: trade.bid_expiration,
+				ask_nonce: trade.ask_nonce,
+				bid_nonce: trade.bid_nonce,
+				fee_recipients: trade
+					.fee_recipients
+					.try_into()
+					.map_err(|_| TradeError::TooManyFeeRecipients)?,
+				buyer_fee: trade.buyer_fee,
+				asset: trade.asset,
+			};
+
+			Self::do_validate_trade(caller, seller, buyer, &trade, signatures, fee_address)
+				.map(|_| ())
+		}
+
+		/// Transfers `amount` of `asset`, or of the native currency if `asset` is `None`.
+		fn transfer_funds(
+			asset: &Option<AssetIdOf<T>>,
+			source: &T::AccountId,
+			dest: &T::AccountId,
+			amount: BalanceOf<T>,
+			preservation: Preservation,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			match asset {
+				Some(asset) => <T::Assets as fungibles::Mutate<T::AccountId>>::transfer(
+					asset.clone(),
+					source,
+					dest,
+					amount,
+					preservation,
+				),
+				None => <T as Config>::Currency::transfer(source, dest, amount, preservation),
+			}
+		}
+
+		/// Returns the balance of `asset`, or of the native currency if `asset` is `None`, that
+		/// `who` can transfer without being reaped.
+		fn reducible_funds(asset: &Option<AssetIdOf<T>>, who: &T::AccountId) -> BalanceOf<T> {
+			match asset {
+				Some(asset) => <T::Assets as fungibles::Inspect<T::AccountId>>::reducible_balance(
+					asset.clone(),
+					who,
+					Preserve,
+					Polite,
+				),
+				None => <T as Config>::Currency::reducible_balance(who, Preserve, Polite),
+			}
+		}
+
+		/// Returns the balance of `asset`, or of the native currency if `asset` is `None`, that
+		/// `who` can transfer without being reaped once credited with `amount`.
+		fn reducible_funds_after_deposit(
+			asset: &Option<AssetIdOf<T>>,
+			who: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> BalanceOf<T> {
+			let (total, minimum) = match asset {
+				Some(asset) => (
+					<T::Assets as fungibles::Inspect<T::AccountId>>::total_balance(
+						asset.clone(),
+						who,
+					),
+					<T::Assets as fungibles::Inspect<T::AccountId>>::minimum_balance(asset.clone()),
+				),
+				None => (
+					<T as Config>::Currency::total_balance(who),
+					<T as Config>::Currency::minimum_balance(),
+				),
+			};
+			if total.is_zero() {
+				amount.saturating_sub(minimum)
+			} else {
+				Self::reducible_funds(asset, who).saturating_add(amount)
+			}
+		}
+
+		/// Returns whether `who` can receive `amount` of `asset`, or of the native currency if
+		/// `asset` is `None`.
+		fn can_receive_funds(
+			asset: &Option<AssetIdOf<T>>,
+			who: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> bool {
+			match asset {
+				Some(asset) => <T::Assets as fungibles::Inspect<T::AccountId>>::can_deposit(
+					asset.clone(),
+					who,
+					amount,
+					Extant,
+				),
+				None => <T as Config>::Currency::can_deposit(who, amount, Extant),
+			}
+			.into_result()
+			.is_ok()
+		}
+
+		/// Prunes the next closed order whose expiration has passed.
+		/// Returns `false` if there are no due closed orders left, `true` otherwise.
+		///
+		/// This function either prunes a single closed order or moves the sweep past an empty
+		/// expiration slot, to avoid blocking the runtime and to ease calculation of the consumed
+		/// weight. An expired order can't be executed, so it no longer needs replay protection.
+		pub fn prune_next_closed_order() -> bool {
+			let Some(slot) = NextExpirationSlot::<T>::get() else {
+				return false;
+			};
+
+			if slot > pallet_timestamp::Pallet::<T>::get() {
+				return false;
+			}
+
+			match ClosedOrderExpirations::<T>::iter_key_prefix(slot).next() {
+				Some(order) => {
+					ClosedOrderExpirations::<T>::remove(slot, &order);
+					match order {
+						ClosedOrder::Ask(hash) => {
+							ClosedAsks::<T>::remove(hash);
+							RemainingAskQuantity::<T>::remove(hash);
+						},
+						ClosedOrder::Bid(hash) => ClosedBids::<T>::remove(hash),
+					}
+				},
+				// Stop sweeping once the index is empty, it restarts with the next closed order.
+				None if ClosedOrderExpirations::<T>::iter_keys().next().is_none() => {
+					NextExpirationSlot::<T>::kill()
+				},
+				None => NextExpirationSlot::<T>::put(
+					slot.saturating_add(T::ExpirationGranularity::get()),
+				),
+			}
+
+			true
+		}
+
+		/// Returns the expiration slot of an order expiring at `expiration`.
+		pub fn expiration_slot(expiration: T::Moment) -> T::Moment {
+			let granularity = T::ExpirationGranularity::get();
+			let remainder = expiration % granularity;
+			if remainder.is_zero() {
+				expiration
+			} else {
+				expiration.saturating_sub(remainder).saturating_add(granularity)
+			}
+		}
+
+		/// Indexes a closed order by the expiration slot of `expiration`, moving the sweep back
+		/// to this slot if needed.
+		pub(crate) fn index_closed_order(order: ClosedOrder<T::Hash>, expiration: T::Moment) {
+			let slot = Self::expiration_slot(expiration);
+			ClosedOrderExpirations::<T>::insert(slot, order, ());
+			NextExpirationSlot::<T>::mutate(|next| match next {
+				Some(next) if *next <= slot => {},
+				_ => *next = Some(slot),
+			});
+		}
+
+		/// The hashes of an ask and a bid, which include their signer and nonce so that
+		/// identical orders of different accounts, or signed again after a nonce bump, are
+		/// tracked apart.
+		pub fn hash_ask_bid_data<Fees: Encode>(
+			seller: &T::AccountId,
+			buyer: &T::AccountId,
+			trade: &TradeParams<T::CollectionId, BalanceOf<T>, Item, T::Moment, Fees, AssetIdOf<T>>,
+		) -> (T::Hash, T::Hash) {
+			(Self::hash_ask_data(seller, trade), Self::hash_bid_data(buyer, trade))
+		}
+
+		pub(crate) fn hash_ask_data<Fees: Encode>(
+			seller: &T::AccountId,
+			trade: &TradeParams<T::CollectionId, BalanceOf<T>, Item, T::Moment, Fees, AssetIdOf<T>>,
+		) -> T::Hash {
+			T::Hashing::hash(
+				&(
+					seller,
+					trade.ask_nonce,
+					trade.collection,
+					trade.item,
+					trade.price,
+					&trade.asset,
+					&trade.fee_recipients,
+					trade.ask_expiration,
+				)
+					.encode(),
+			)
+		}
+
+		pub(crate) fn hash_bid_data<Fees: Encode>(
+			buyer: &T::AccountId,
+			trade: &TradeParams<T::CollectionId, BalanceOf<T>, Item, T::Moment, Fees, AssetIdOf<T>>,
+		) -> T::Hash {
+			T::Hashing::hash(
+				&(
+					buyer,
+					trade.bid_nonce,
+					trade.collection,
+					trade.item,
+					trade.price,
+					&trade.asset,
+					trade.fee,
+					&trade.fee_recipients,
+					trade.buyer_fee,
+					trade.bid_expiration,
+				)
+					.encode(),
+			)
+		}
+
+		pub fn get_ask_message<Fees: Clone + Into<Vec<FeeRecipientOf<T>>>>(
+			caller: &T::AccountId,
+			fee_address: &T::AccountId,
+			trade: &TradeParams<T::CollectionId, BalanceOf<T>, Item, T::Moment, Fees, AssetIdOf<T>>,
+		) -> Vec<u8> {
+			Self::ask_message(caller, fee_address, trade).encode()
+		}
+
+		pub fn get_bid_message<Fees: Clone + Into<Vec<FeeRecipientOf<T>>>>(
+			caller: &T::AccountId,
+			fee_address: &T::AccountId,
+			trade: &TradeParams<T::CollectionId, BalanceOf<T>, Item, T::Moment, Fees, AssetIdOf<T>>,
+		) -> Vec<u8> {
+			Self::bid_message(caller, fee_address, trade).encode()
+		}
+
+		/// Returns the EIP-712 typed data JSON of the ask, to be signed with
+		/// `eth_signTypedData_v4`.
+		pub fn get_ask_typed_data<Fees: Clone + Into<Vec<FeeRecipientOf<T>>>>(
+			caller: &T::AccountId,
+			fee_address: &T::AccountId,
+			trade: &TradeParams<T::CollectionId, BalanceOf<T>, Item, T::Moment, Fees, AssetIdOf<T>>,
 		) -> Vec<u8> {
+			Self::ask_message(caller, fee_address, trade).typed_data_json()
+		}
+
+		/// Returns the EIP-712 typed data JSON of the bid, to be signed with
+		/// `eth_signTypedData_v4`.
+		pub fn get_bid_typed_data<Fees: Clone + Into<Vec<FeeRecipientOf<T>>>>(
+			caller: &T::AccountId,
+			fee_address: &T::AccountId,
+			trade: &TradeParams<T::CollectionId, BalanceOf<T>, Item, T::Moment, Fees, AssetIdOf<T>>,
+		) -> Vec<u8> {
+			Self::bid_message(caller, fee_address, trade).typed_data_json()
+		}
+
+		pub(crate) fn ask_message<Fees: Clone + Into<Vec<FeeRecipientOf<T>>>>(
+			caller: &T::AccountId,
+			fee_address: &T::AccountId,
+			trade: &TradeParams<T::CollectionId, BalanceOf<T>, Item, T::Moment, Fees, AssetIdOf<T>>,
+		) -> AskMessageOf<T> {
 			AskMessage {
+				version: MESSAGE_VERSION,
 				domain: T::Domain::get(),
 				sender: caller.clone(),
 				fee_address: fee_address.clone(),
+				collection: trade.collection,
 				item: trade.item,
 				price: trade.price,
+				asset: trade.asset.clone(),
+				fee_recipients: trade.fee_recipients.clone().into(),
+				nonce: trade.ask_nonce,
 				expiration: trade.ask_expiration,
 			}
-			.encode()
 		}
 
-		pub fn get_bid_message(
+		pub(crate) fn bid_message<Fees: Clone + Into<Vec<FeeRecipientOf<T>>>>(
 			caller: &T::AccountId,
 			fee_address: &T::AccountId,
-			trade: &TradeParamsOf<T>,
-		) -> Vec<u8> {
+			trade: &TradeParams<T::CollectionId, BalanceOf<T>, Item, T::Moment, Fees, AssetIdOf<T>>,
+		) -> BidMessageOf<T> {
 			BidMessage {
+				version: MESSAGE_VERSION,
 				domain: T::Domain::get(),
 				sender: caller.clone(),
 				fee_address: fee_address.clone(),
+				collection: trade.collection,
 				item: trade.item,
 				price: trade.price,
+				asset: trade.asset.clone(),
+				fee: trade.fee,
+				fee_recipients: trade.fee_recipients.clone().into(),
+				buyer_fee: trade.buyer_fee,
+				nonce: trade.bid_nonce,
+				expiration: trade.bid_expiration,
+			}
+		}
+
+		/// The hashes of a batch ask and bid, which include their signer and nonce so that
+		/// identical orders of different accounts are tracked apart.
+		pub fn hash_batch_ask_bid_data<Items: Encode, Fees: Encode>(
+			seller: &T::AccountId,
+			buyer: &T::AccountId,
+			trade: &BatchTradeParams<
+				T::CollectionId,
+				BalanceOf<T>,
+				Items,
+				T::Moment,
+				Fees,
+				AssetIdOf<T>,
+			>,
+		) -> (T::Hash, T::Hash) {
+			let ask_hash = T::Hashing::hash(
+				&(
+					seller,
+					trade.ask_nonce,
+					trade.collection,
+					&trade.items,
+					trade.unit_price,
+					&trade.asset,
+					&trade.fee_recipients,
+					trade.max_quantity,
+					trade.ask_expiration,
+				)
+					.encode(),
+			);
+			let bid_hash = T::Hashing::hash(
+				&(
+					buyer,
+					trade.bid_nonce,
+					trade.collection,
+					&trade.items,
+					trade.unit_price,
+					&trade.asset,
+					trade.quantity,
+					trade.fee,
+					&trade.fee_recipients,
+					trade.buyer_fee,
+					trade.bid_expiration,
+				)
+					.encode(),
+			);
+
+			(ask_hash, bid_hash)
+		}
+
+		pub fn get_batch_ask_message<
+			Items: Encode + Clone,
+			Fees: Clone + Into<Vec<FeeRecipientOf<T>>>,
+		>(
+			caller: &T::AccountId,
+			fee_address: &T::AccountId,
+			trade: &BatchTradeParams<
+				T::CollectionId,
+				BalanceOf<T>,
+				Items,
+				T::Moment,
+				Fees,
+				AssetIdOf<T>,
+			>,
+		) -> Vec<u8> {
+			BatchAskMessage {
+				version: MESSAGE_VERSION,
+				domain: T::Domain::get(),
+				sender: caller.clone(),
+				fee_address: fee_address.clone(),
+				collection: trade.collection,
+				items: trade.items.clone(),
+				unit_price: trade.unit_price,
+				asset: trade.asset.clone(),
+				fee_recipients: trade.fee_recipients.clone().into(),
+				max_quantity: trade.max_quantity,
+				nonce: trade.ask_nonce,
+				expiration: trade.ask_expiration,
+			}
+			.encode()
+		}
+
+		pub fn get_batch_bid_message<
+			Items: Encode + Clone,
+			Fees: Clone + Into<Vec<FeeRecipientOf<T>>>,
+		>(
+			caller: &T::AccountId,
+			fee_address: &T::AccountId,
+			trade: &BatchTradeParams<
+				T::CollectionId,
+				BalanceOf<T>,
+				Items,
+				T::Moment,
+				Fees,
+				AssetIdOf<T>,
+			>,
+		) -> Vec<u8> {
+			BatchBidMessage {
+				version: MESSAGE_VERSION,
+				domain: T::Domain::get(),
+				sender: caller.clone(),
+				fee_address: fee_address.clone(),
+				collection: trade.collection,
+				items: trade.items.clone(),
+				unit_price: trade.unit_price,
+				asset: trade.asset.clone(),
+				quantity: trade.quantity,
 				fee: trade.fee,
+				fee_recipients: trade.fee_recipients.clone().into(),
+				buyer_fee: trade.buyer_fee,
+				nonce: trade.bid_nonce,
 				expiration: trade.bid_expiration,
 			}
 			.encode()
@@ -337,15 +1351,34 @@ sp_core::generate_feature_enabled_macro!(runtime_benchmarks_enabled, feature = "
 
 use sp_std::vec::Vec;
 sp_api::decl_runtime_apis! {
-	pub trait DmarketApi<AccountId, Balance, Moment, Hash>
+	/// Version 2 changed the trade parameters and signed messages, and added the batch trade
+	/// messages, the EIP-712 typed data and the validation of a trade without executing it.
+	#[api_version(2)]
+	pub trait DmarketApi<AccountId, Balance, Moment, Hash, CollectionId, AssetId, Signature>
 	where
 		AccountId: Codec,
 		Balance: Codec,
 		Moment: Codec,
 		Hash: Codec,
+		CollectionId: Codec,
+		AssetId: Codec,
+		Signature: Codec,
 	{
-		fn hash_ask_bid_data(trade: TradeParams<Balance, Item, Moment>)-> (Hash, Hash);
-		fn get_ask_message(caller: AccountId, fee_address: AccountId, trade: TradeParams<Balance, Item, Moment>) -> Vec<u8>;
-		fn get_bid_message(caller: AccountId, fee_address: AccountId, trade: TradeParams<Balance, Item, Moment>) -> Vec<u8>;
+		#[changed_in(2)]
+		fn hash_ask_bid_data(trade: TradeParamsV1<Balance, Item, Moment>) -> (Hash, Hash);
+		#[changed_in(2)]
+		fn get_ask_message(caller: AccountId, fee_address: AccountId, trade: TradeParamsV1<Balance, Item, Moment>) -> Vec<u8>;
+		#[changed_in(2)]
+		fn get_bid_message(caller: AccountId, fee_address: AccountId, trade: TradeParamsV1<Balance, Item, Moment>) -> Vec<u8>;
+		fn hash_ask_bid_data(seller: AccountId, buyer: AccountId, trade: TradeParams<CollectionId, Balance, Item, Moment, Vec<FeeRecipient<AccountId, Balance>>, AssetId>)-> (Hash, Hash);
+		fn get_ask_message(caller: AccountId, fee_address: AccountId, trade: TradeParams<CollectionId, Balance, Item, Moment, Vec<FeeRecipient<AccountId, Balance>>, AssetId>) -> Vec<u8>;
+		fn get_bid_message(caller: AccountId, fee_address: AccountId, trade: TradeParams<CollectionId, Balance, Item, Moment, Vec<FeeRecipient<AccountId, Balance>>, AssetId>) -> Vec<u8>;
+		fn get_ask_typed_data(caller: AccountId, fee_address: AccountId, trade: TradeParams<CollectionId, Balance, Item, Moment, Vec<FeeRecipient<AccountId, Balance>>, AssetId>) -> Vec<u8>;
+		fn get_bid_typed_data(caller: AccountId, fee_address: AccountId, trade: TradeParams<CollectionId, Balance, Item, Moment, Vec<FeeRecipient<AccountId, Balance>>, AssetId>) -> Vec<u8>;
+		fn hash_batch_ask_bid_data(seller: AccountId, buyer: AccountId, trade: BatchTradeParams<CollectionId, Balance, Vec<Item>, Moment, Vec<FeeRecipient<AccountId, Balance>>, AssetId>) -> (Hash, Hash);
+		fn get_batch_ask_message(caller: AccountId, fee_address: AccountId, trade: BatchTradeParams<CollectionId, Balance, Vec<Item>, Moment, Vec<FeeRecipient<AccountId, Balance>>, AssetId>) -> Vec<u8>;
+		fn get_batch_bid_message(caller: AccountId, fee_address: AccountId, trade: BatchTradeParams<CollectionId, Balance, Vec<Item>, Moment, Vec<FeeRecipient<AccountId, Balance>>, AssetId>) -> Vec<u8>;
+		/// Runs every check of `execute_trade` without executing the trade.
+		fn validate_trade(caller: AccountId, seller: AccountId, buyer: AccountId, trade: TradeParams<CollectionId, Balance, Item, Moment, Vec<FeeRecipient<AccountId, Balance>>, AssetId>, signatures: TradeSignatures<Signature>, fee_address: AccountId) -> Result<(), TradeError>;
 	}
 }
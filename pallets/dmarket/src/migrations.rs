@@ -0,0 +1,141 @@
+//! Storage migrations for the dmarket pallet.
+
+use crate::*;
+use frame_support::{
+	migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+};
+
+pub mod v1 {
+	use super::*;
+	use frame_support::{
+		migrations::{SteppedMigration, SteppedMigrationError},
+		weights::WeightMeter,
+	};
+	use sp_std::vec::Vec;
+
+	/// Bumps the storage version once the closed asks and bids are indexed by their expiration.
+	///
+	/// The closed orders stored before, without an expiration, are removed by
+	/// `LazyClearLegacyClosedOrders` over several blocks.
+	pub struct UncheckedMigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			Weight::zero()
+		}
+	}
+
+	/// Removes the closed asks and bids stored before the expiration index existed.
+	///
+	/// Their expiration is not stored, so they could never be pruned. Transactions are suspended
+	/// while the migration runs, so every closed order it visits was stored before the upgrade.
+	/// The upgrade must change the signed messages, as the `v2` signing schema does, so that the
+	/// orders signed before it can't be executed again once their hashes are removed.
+	///
+	/// Runs over several blocks, the cursor being the last removed order.
+	pub struct LazyClearLegacyClosedOrders<T>(PhantomData<T>);
+
+	impl<T: Config> SteppedMigration for LazyClearLegacyClosedOrders<T> {
+		type Cursor = ClosedOrder<T::Hash>;
+		type Identifier = [u8; 29];
+
+		fn id() -> Self::Identifier {
+			*b"dmarket::legacy-closed-orders"
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			let required = T::DbWeight::get().reads_writes(1, 1);
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+
+			while meter.try_consume(required).is_ok() {
+				let next = match cursor {
+					None => next_ask::<T>(None).or_else(|| next_bid::<T>(None)),
+					Some(ClosedOrder::Ask(hash)) => {
+						next_ask::<T>(Some(ClosedAsks::<T>::hashed_key_for(hash)))
+							.or_else(|| next_bid::<T>(None))
+					},
+					Some(ClosedOrder::Bid(hash)) => {
+						next_bid::<T>(Some(ClosedBids::<T>::hashed_key_for(hash)))
+					},
+				};
+
+				match next {
+					Some(ClosedOrder::Ask(hash)) => ClosedAsks::<T>::remove(hash),
+					Some(ClosedOrder::Bid(hash)) => ClosedBids::<T>::remove(hash),
+					None => {
+						log::info!(target: "runtime::dmarket", "Removed the legacy closed orders");
+						return Ok(None);
+					},
+				}
+				cursor = next;
+			}
+
+			Ok(cursor)
+		}
+	}
+
+	/// The closed ask stored after the raw key `from`, or the first one.
+	fn next_ask<T: Config>(from: Option<Vec<u8>>) -> Option<ClosedOrder<T::Hash>> {
+		let mut asks = match from {
+			Some(key) => ClosedAsks::<T>::iter_keys_from(key),
+			None => ClosedAsks::<T>::iter_keys(),
+		};
+		asks.next().map(ClosedOrder::Ask)
+	}
+
+	/// The closed bid stored after the raw key `from`, or the first one.
+	fn next_bid<T: Config>(from: Option<Vec<u8>>) -> Option<ClosedOrder<T::Hash>> {
+		let mut bids = match from {
+			Some(key) => ClosedBids::<T>::iter_keys_from(key),
+			None => ClosedBids::<T>::iter_keys(),
+		};
+		bids.next().map(ClosedOrder::Bid)
+	}
+
+	/// Migrates the dmarket storage from version 0 to version 1.
+	pub type MigrateToV1<T> = VersionedMigration<
+		0,
+		1,
+		UncheckedMigrateToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+pub mod v2 {
+	use super::*;
+
+	#[frame_support::storage_alias]
+	type DmarketCollection<T: Config> =
+		StorageValue<Pallet<T>, <T as pallet_nfts::Config>::CollectionId, OptionQuery>;
+
+	/// Moves the single Dmarket collection into the set of enabled collections.
+	pub struct UncheckedMigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			match DmarketCollection::<T>::take() {
+				Some(collection_id) => {
+					DmarketCollections::<T>::insert(collection_id, ());
+					log::info!(target: "runtime::dmarket", "Enabled the Dmarket collection in v2");
+					T::DbWeight::get().reads_writes(1, 2)
+				},
+				None => T::DbWeight::get().reads(1),
+			}
+		}
+	}
+
+	/// Migrates the dmarket storage from version 1 to version 2.
+	pub type MigrateToV2<T> = VersionedMigration<
+		1,
+		2,
+		UncheckedMigrateToV2<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
@@ -1,12 +1,15 @@
 use frame_support::{
 	derive_impl, parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, ConstU128},
+	traits::{
+		AsEnsureOriginWithArg, ConstU32, ConstU64, ConstU128, StorageMapShim, fungible, fungibles,
+		tokens::{DepositConsequence, Fortitude, Preservation, Provenance, WithdrawConsequence},
+	},
 };
 use frame_system as system;
 use sp_core::H256;
 use sp_keystore::{KeystoreExt, testing::MemoryKeystore};
 use sp_runtime::{
-	BuildStorage,
+	BuildStorage, DispatchError, TokenError,
 	traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
 };
 
@@ -19,6 +22,9 @@ type Signature = EthereumSignature;
 pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
 type Block = frame_system::mocking::MockBlock<Test>;
 
+/// The only asset besides the native currency trades can be settled in.
+pub const USDC: u32 = 1;
+
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
 	pub enum Test
@@ -26,6 +32,7 @@ frame_support::construct_runtime!(
 		System: frame_system,
 		Dmarket: pallet_dmarket,
 		Balances: pallet_balances,
+		AssetBalances: pallet_balances::<Instance2>,
 		Timestamp: pallet_timestamp,
 		Nfts: pallet_nfts,
 	}
@@ -113,6 +120,114 @@ impl pallet_balances::Config for Test {
 	type DoneSlashHandler = ();
 }
 
+impl pallet_balances::Config<pallet_balances::Instance2> for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u128;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = StorageMapShim<
+		pallet_balances::Account<Test, pallet_balances::Instance2>,
+		AccountId,
+		pallet_balances::AccountData<u128>,
+	>;
+	type WeightInfo = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type DoneSlashHandler = ();
+}
+
+/// Exposes `AssetBalances` as the `USDC` asset.
+pub struct TestAssets;
+
+impl fungibles::Inspect<AccountId> for TestAssets {
+	type AssetId = u32;
+	type Balance = u128;
+
+	fn total_issuance(asset: u32) -> u128 {
+		if asset == USDC { <AssetBalances as fungible::Inspect<_>>::total_issuance() } else { 0 }
+	}
+
+	fn minimum_balance(asset: u32) -> u128 {
+		if asset == USDC { <AssetBalances as fungible::Inspect<_>>::minimum_balance() } else { 0 }
+	}
+
+	fn total_balance(asset: u32, who: &AccountId) -> u128 {
+		if asset == USDC { <AssetBalances as fungible::Inspect<_>>::total_balance(who) } else { 0 }
+	}
+
+	fn balance(asset: u32, who: &AccountId) -> u128 {
+		if asset == USDC { <AssetBalances as fungible::Inspect<_>>::balance(who) } else { 0 }
+	}
+
+	fn reducible_balance(
+		asset: u32,
+		who: &AccountId,
+		preservation: Preservation,
+		force: Fortitude,
+	) -> u128 {
+		if asset == USDC {
+			<AssetBalances as fungible::Inspect<_>>::reducible_balance(who, preservation, force)
+		} else {
+			0
+		}
+	}
+
+	fn can_deposit(
+		asset: u32,
+		who: &AccountId,
+		amount: u128,
+		provenance: Provenance,
+	) -> DepositConsequence {
+		if asset == USDC {
+			<AssetBalances as fungible::Inspect<_>>::can_deposit(who, amount, provenance)
+		} else {
+			DepositConsequence::UnknownAsset
+		}
+	}
+
+	fn can_withdraw(asset: u32, who: &AccountId, amount: u128) -> WithdrawConsequence<u128> {
+		if asset == USDC {
+			<AssetBalances as fungible::Inspect<_>>::can_withdraw(who, amount)
+		} else {
+			WithdrawConsequence::UnknownAsset
+		}
+	}
+
+	fn asset_exists(asset: u32) -> bool {
+		asset == USDC
+	}
+}
+
+impl fungibles::Unbalanced<AccountId> for TestAssets {
+	fn handle_dust(dust: fungibles::Dust<AccountId, Self>) {
+		<AssetBalances as fungible::Unbalanced<_>>::handle_dust(fungible::Dust(dust.1))
+	}
+
+	fn write_balance(
+		asset: u32,
+		who: &AccountId,
+		amount: u128,
+	) -> Result<Option<u128>, DispatchError> {
+		if asset != USDC {
+			return Err(TokenError::UnknownAsset.into());
+		}
+		<AssetBalances as fungible::Unbalanced<_>>::write_balance(who, amount)
+	}
+
+	fn set_total_issuance(asset: u32, amount: u128) {
+		if asset == USDC {
+			<AssetBalances as fungible::Unbalanced<_>>::set_total_issuance(amount)
+		}
+	}
+}
+
+impl fungibles::Mutate<AccountId> for TestAssets {}
+
 impl pallet_timestamp::Config for Test {
 	type Moment = u64;
 	type OnTimestampSet = ();
@@ -128,9 +243,13 @@ impl crate::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type Currency = Balances;
+	type Assets = TestAssets;
 	type Signature = Signature;
 	type Signer = <Signature as Verify>::Signer;
 	type Domain = DOMAIN;
+	type ExpirationGranularity = ConstU64<10>;
+	type MaxBatchItems = ConstU32<10>;
+	type MaxFeeRecipients = ConstU32<5>;
 	type WeightInfo = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
@@ -3,7 +3,11 @@ use account::{AccountId20, EthereumSignature, EthereumSigner};
 use frame_support::{
 	assert_noop, assert_ok,
 	error::BadOrigin,
-	traits::fungible::{Inspect as InspectFungible, Mutate},
+	traits::{
+		Hooks,
+		fungible::{Inspect as InspectFungible, Mutate},
+	},
+	weights::Weight,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 use pallet_nfts::{CollectionConfig, CollectionSettings, MintSettings, NextCollectionId};
@@ -46,25 +50,26 @@ fn collection_config_with_all_settings_enabled()
 	}
 }
 
-mod force_set_collection {
+mod force_enable_collection {
 	use super::*;
 
 	#[test]
-	fn force_set_collection_works() {
+	fn force_enable_collection_works() {
 		new_test_ext().execute_with(|| {
 			let collection_id = create_collection(&account(0));
 
-			assert_ok!(Dmarket::force_set_collection(RuntimeOrigin::root(), collection_id));
-			assert!(DmarketCollection::<Test>::get() == Some(collection_id));
+			assert_ok!(Dmarket::force_enable_collection(RuntimeOrigin::root(), collection_id));
+			assert!(DmarketCollections::<Test>::contains_key(collection_id));
 
 			assert_noop!(
-				Dmarket::force_set_collection(RuntimeOrigin::root(), collection_id),
-				Error::<Test>::CollectionAlreadyInUse
+				Dmarket::force_enable_collection(RuntimeOrigin::root(), collection_id),
+				Error::<Test>::CollectionAlreadyEnabled
 			);
 
 			let other_collection = create_collection(&account(0));
-			assert_ok!(Dmarket::force_set_collection(RuntimeOrigin::root(), other_collection));
-			assert!(DmarketCollection::<Test>::get() == Some(other_collection));
+			assert_ok!(Dmarket::force_enable_collection(RuntimeOrigin::root(), other_collection));
+			assert!(DmarketCollections::<Test>::contains_key(collection_id));
+			assert!(DmarketCollections::<Test>::contains_key(other_collection));
 		})
 	}
 
@@ -74,7 +79,7 @@ mod force_set_collection {
 			let collection_id = create_collection(&account(0));
 
 			assert_noop!(
-				Dmarket::force_set_collection(RuntimeOrigin::signed(account(1)), collection_id),
+				Dmarket::force_enable_collection(RuntimeOrigin::signed(account(1)), collection_id),
 				BadOrigin
 			);
 		})
@@ -84,17 +89,54 @@ mod force_set_collection {
 	fn collection_not_found() {
 		new_test_ext().execute_with(|| {
 			assert_noop!(
-				Dmarket::force_set_collection(RuntimeOrigin::root(), 0),
+				Dmarket::force_enable_collection(RuntimeOrigin::root(), 0),
 				Error::<Test>::CollectionNotFound
 			);
 		})
 	}
 }
 
+mod force_disable_collection {
+	use super::*;
+
+	#[test]
+	fn force_disable_collection_works() {
+		new_test_ext().execute_with(|| {
+			let collection_id = create_collection(&account(0));
+			let other_collection = create_collection(&account(0));
+			assert_ok!(Dmarket::force_enable_collection(RuntimeOrigin::root(), collection_id));
+			assert_ok!(Dmarket::force_enable_collection(RuntimeOrigin::root(), other_collection));
+
+			assert_ok!(Dmarket::force_disable_collection(RuntimeOrigin::root(), collection_id));
+			assert!(!DmarketCollections::<Test>::contains_key(collection_id));
+			assert!(DmarketCollections::<Test>::contains_key(other_collection));
+			System::assert_last_event(Event::CollectionDisabled { collection_id }.into());
+
+			assert_noop!(
+				Dmarket::force_disable_collection(RuntimeOrigin::root(), collection_id),
+				Error::<Test>::CollectionNotEnabled
+			);
+		})
+	}
+
+	#[test]
+	fn fails_no_root() {
+		new_test_ext().execute_with(|| {
+			let collection_id = create_collection(&account(0));
+			assert_ok!(Dmarket::force_enable_collection(RuntimeOrigin::root(), collection_id));
+
+			assert_noop!(
+				Dmarket::force_disable_collection(RuntimeOrigin::signed(account(1)), collection_id),
+				BadOrigin
+			);
+		})
+	}
+}
+
 mod execute_trade {
 	use super::*;
 
-	fn get_trade_accounts() -> (AccountIdOf<Test>, AccountIdOf<Test>, KeyPair, KeyPair) {
+	pub(super) fn get_trade_accounts() -> (AccountIdOf<Test>, AccountIdOf<Test>, KeyPair, KeyPair) {
 		let sender = account(0);
 		let fee_address = account(1);
 
@@ -103,19 +145,25 @@ mod execute_trade {
 		(sender, fee_address, seller_pair, buyer_pair)
 	}
 
-	fn setup_nft(
+	pub(super) fn setup_nft(
 		sender: &AccountIdOf<Test>,
 		item_owner: &AccountIdOf<Test>,
 		item: u128,
 	) -> CollectionId<Test> {
 		let collection_id = create_collection(&sender);
-		assert_ok!(Dmarket::force_set_collection(RuntimeOrigin::root(), collection_id));
-
-		assert_ok!(Nfts::mint(RuntimeOrigin::signed(*sender), 0, Some(item), *item_owner, None));
+		assert_ok!(Dmarket::force_enable_collection(RuntimeOrigin::root(), collection_id));
+
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(*sender),
+			collection_id,
+			Some(item),
+			*item_owner,
+			None
+		));
 		collection_id
 	}
 
-	fn sign_trade(
+	pub(super) fn sign_trade(
 		caller: &AccountIdOf<Test>,
 		fee_address: &AccountIdOf<Test>,
 		trade: &TradeParamsOf<Test>,
@@ -157,9 +205,15 @@ mod execute_trade {
 			let trade = TradeParams {
 				price,
 				fee,
+				collection,
 				item,
 				ask_expiration: expiration,
 				bid_expiration: expiration,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: Default::default(),
+				buyer_fee: 0,
+				asset: None,
 			};
 			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
 
@@ -177,53 +231,89 @@ mod execute_trade {
 			assert_eq!(Balances::balance(&seller), seller_balance + price - fee);
 			assert_eq!(Balances::balance(&fee_address), fee_address_balance + fee);
 
-			let (ask_hash, bid_hash) = Dmarket::hash_ask_bid_data(&trade);
+			let (ask_hash, bid_hash) = Dmarket::hash_ask_bid_data(&seller, &buyer, &trade);
 			assert!(ClosedAsks::<Test>::contains_key(ask_hash));
 			assert!(ClosedBids::<Test>::contains_key(bid_hash));
 		})
 	}
 
 	#[test]
-	fn buyer_is_seller() {
+	fn execute_trade_pays_fee_recipients_and_buyer_fee() {
 		new_test_ext().execute_with(|| {
 			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
 
 			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
-			let buyer: AccountId20 = seller.clone();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+			let creator = account(2);
+			let referrer = account(3);
 
 			let item = 1;
-			let _ = setup_nft(&sender, &seller, item);
+			let collection = setup_nft(&sender, &seller, item);
 
 			let expiration = Timestamp::get() + 10;
-			let price = 10000000;
+			let price = 10000;
 			let fee = 100;
+			let buyer_fee = 50;
 			Balances::set_balance(&buyer, price * 2);
 
+			let buyer_balance = Balances::balance(&buyer);
+			let fee_address_balance = Balances::balance(&fee_address);
+			let seller_balance = Balances::balance(&seller);
+
 			let trade = TradeParams {
 				price,
 				fee,
+				collection,
 				item,
 				ask_expiration: expiration,
 				bid_expiration: expiration,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: vec![
+					FeeRecipient { account: creator, amount: 500 },
+					FeeRecipient { account: referrer, amount: 200 },
+				]
+				.try_into()
+				.unwrap(),
+				buyer_fee,
+				asset: None,
 			};
 			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
 
-			assert_noop!(
-				Dmarket::execute_trade(
-					RuntimeOrigin::signed(sender),
+			assert_ok!(Dmarket::execute_trade(
+				RuntimeOrigin::signed(sender),
+				seller,
+				buyer,
+				trade.clone(),
+				signatures,
+				fee_address
+			));
+
+			assert_eq!(Nfts::owner(collection, item).unwrap(), buyer);
+			assert_eq!(Balances::balance(&buyer), buyer_balance - price - buyer_fee);
+			assert_eq!(Balances::balance(&seller), seller_balance + price - fee - 500 - 200);
+			assert_eq!(Balances::balance(&fee_address), fee_address_balance + fee + buyer_fee);
+			assert_eq!(Balances::balance(&creator), 500);
+			assert_eq!(Balances::balance(&referrer), 200);
+			System::assert_last_event(
+				Event::Trade {
 					seller,
 					buyer,
-					trade.clone(),
-					signatures,
-					fee_address
-				),
-				Error::<Test>::BuyerIsSeller
+					collection,
+					item,
+					price,
+					fee,
+					fee_recipients: trade.fee_recipients,
+					buyer_fee,
+					asset: None,
+				}
+				.into(),
 			);
 		})
 	}
 
 	#[test]
-	fn ask_or_bid_expired() {
+	fn fee_recipients_are_signed() {
 		new_test_ext().execute_with(|| {
 			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
 
@@ -231,49 +321,62 @@ mod execute_trade {
 			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
 
 			let item = 1;
-			let _ = setup_nft(&sender, &seller, item);
+			let collection = setup_nft(&sender, &seller, item);
+			Balances::set_balance(&buyer, 100000);
 
 			let expiration = Timestamp::get() + 10;
-			let price = 10000000;
-			let fee = 100;
-			Balances::set_balance(&buyer, price * 2);
-
-			let mut trade =
-				TradeParams { price, fee, item, ask_expiration: 0, bid_expiration: expiration };
-			let signatures =
-				sign_trade(&sender, &fee_address, &trade, seller_pair.clone(), buyer_pair.clone());
+			let trade = TradeParams {
+				price: 10000,
+				fee: 100,
+				collection,
+				item,
+				ask_expiration: expiration,
+				bid_expiration: expiration,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: vec![FeeRecipient { account: account(2), amount: 500 }]
+					.try_into()
+					.unwrap(),
+				buyer_fee: 0,
+				asset: None,
+			};
+			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
 
+			let redirected_fees = TradeParams {
+				fee_recipients: vec![FeeRecipient { account: account(3), amount: 500 }]
+					.try_into()
+					.unwrap(),
+				..trade.clone()
+			};
 			assert_noop!(
 				Dmarket::execute_trade(
 					RuntimeOrigin::signed(sender),
 					seller,
 					buyer,
-					trade.clone(),
-					signatures,
+					redirected_fees,
+					signatures.clone(),
 					fee_address
 				),
-				Error::<Test>::AskExpired
+				Error::<Test>::InvalidSellerSignature
 			);
 
-			trade.ask_expiration = expiration;
-			trade.bid_expiration = 0;
-			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+			let buyer_fee = TradeParams { buyer_fee: 10, ..trade };
 			assert_noop!(
 				Dmarket::execute_trade(
-					RuntimeOrigin::signed(seller),
+					RuntimeOrigin::signed(sender),
 					seller,
 					buyer,
-					trade.clone(),
+					buyer_fee,
 					signatures,
 					fee_address
 				),
-				Error::<Test>::BidExpired
+				Error::<Test>::InvalidBuyerSignature
 			);
 		})
 	}
 
 	#[test]
-	fn collection_not_set() {
+	fn fees_exceed_price() {
 		new_test_ext().execute_with(|| {
 			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
 
@@ -281,18 +384,25 @@ mod execute_trade {
 			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
 
 			let item = 1;
+			let collection = setup_nft(&sender, &seller, item);
+			Balances::set_balance(&buyer, 100000);
+			Balances::set_balance(&seller, 100000);
 
 			let expiration = Timestamp::get() + 10;
-			let price = 10000000;
-			let fee = 100;
-			Balances::set_balance(&buyer, price * 2);
-
 			let trade = TradeParams {
-				price,
-				fee,
+				price: 1000,
+				fee: 100,
+				collection,
 				item,
 				ask_expiration: expiration,
 				bid_expiration: expiration,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: vec![FeeRecipient { account: account(2), amount: 901 }]
+					.try_into()
+					.unwrap(),
+				buyer_fee: 0,
+				asset: None,
 			};
 			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
 
@@ -301,17 +411,17 @@ mod execute_trade {
 					RuntimeOrigin::signed(sender),
 					seller,
 					buyer,
-					trade.clone(),
+					trade,
 					signatures,
 					fee_address
 				),
-				Error::<Test>::CollectionNotSet
+				Error::<Test>::FeesExceedPrice
 			);
 		})
 	}
 
 	#[test]
-	fn item_not_found() {
+	fn execute_trade_in_asset() {
 		new_test_ext().execute_with(|| {
 			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
 
@@ -319,19 +429,91 @@ mod execute_trade {
 			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
 
 			let item = 1;
-			let _ = setup_nft(&sender, &seller, item);
+			let collection = setup_nft(&sender, &seller, item);
 
 			let expiration = Timestamp::get() + 10;
-			let price = 10000000;
+			let price = 10000;
 			let fee = 100;
-			Balances::set_balance(&buyer, price * 2);
+			let buyer_fee = 50;
+			Balances::set_balance(&buyer, 1000);
+			AssetBalances::set_balance(&buyer, price * 2);
 
 			let trade = TradeParams {
 				price,
 				fee,
-				item: item + 1,
+				collection,
+				item,
+				ask_expiration: expiration,
+				bid_expiration: expiration,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: vec![FeeRecipient { account: account(2), amount: 500 }]
+					.try_into()
+					.unwrap(),
+				buyer_fee,
+				asset: Some(USDC),
+			};
+			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+
+			assert_ok!(Dmarket::execute_trade(
+				RuntimeOrigin::signed(sender),
+				seller,
+				buyer,
+				trade.clone(),
+				signatures,
+				fee_address
+			));
+
+			assert_eq!(Nfts::owner(collection, item).unwrap(), buyer);
+			assert_eq!(AssetBalances::balance(&buyer), price - buyer_fee);
+			assert_eq!(AssetBalances::balance(&seller), price - fee - 500);
+			assert_eq!(AssetBalances::balance(&fee_address), fee + buyer_fee);
+			assert_eq!(AssetBalances::balance(&account(2)), 500);
+			assert_eq!(Balances::balance(&buyer), 1000);
+			assert_eq!(Balances::balance(&seller), 0);
+			System::assert_last_event(
+				Event::Trade {
+					seller,
+					buyer,
+					collection,
+					item,
+					price,
+					fee,
+					fee_recipients: trade.fee_recipients,
+					buyer_fee,
+					asset: Some(USDC),
+				}
+				.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn asset_is_signed() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
+
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let item = 1;
+			let collection = setup_nft(&sender, &seller, item);
+			Balances::set_balance(&buyer, 100000);
+			AssetBalances::set_balance(&buyer, 100000);
+
+			let expiration = Timestamp::get() + 10;
+			let trade = TradeParams {
+				price: 10000,
+				fee: 100,
+				collection,
+				item,
 				ask_expiration: expiration,
 				bid_expiration: expiration,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: Default::default(),
+				buyer_fee: 0,
+				asset: None,
 			};
 			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
 
@@ -340,17 +522,28 @@ mod execute_trade {
 					RuntimeOrigin::signed(sender),
 					seller,
 					buyer,
-					trade.clone(),
+					TradeParams { asset: Some(USDC), ..trade.clone() },
+					signatures.clone(),
+					fee_address
+				),
+				Error::<Test>::InvalidSellerSignature
+			);
+			assert_noop!(
+				Dmarket::execute_trade(
+					RuntimeOrigin::signed(sender),
+					seller,
+					buyer,
+					TradeParams { asset: Some(USDC + 1), ..trade },
 					signatures,
 					fee_address
 				),
-				Error::<Test>::ItemNotFound
+				Error::<Test>::UnknownAsset
 			);
 		})
 	}
 
 	#[test]
-	fn already_executed() {
+	fn trades_in_multiple_collections() {
 		new_test_ext().execute_with(|| {
 			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
 
@@ -359,45 +552,127 @@ mod execute_trade {
 
 			let item = 1;
 			let collection = setup_nft(&sender, &seller, item);
+			let other_collection = setup_nft(&sender, &seller, item);
 
 			let expiration = Timestamp::get() + 10;
 			let price = 10000000;
 			let fee = 100;
-			Balances::set_balance(&buyer, price * 2);
+			Balances::set_balance(&buyer, price * 3);
 
 			let trade = TradeParams {
 				price,
 				fee,
+				collection,
 				item,
 				ask_expiration: expiration,
 				bid_expiration: expiration,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: Default::default(),
+				buyer_fee: 0,
+				asset: None,
 			};
-			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+			let signatures =
+				sign_trade(&sender, &fee_address, &trade, seller_pair.clone(), buyer_pair.clone());
+
+			// The signatures are bound to the collection of the trade
+			let other_trade = TradeParams { collection: other_collection, ..trade.clone() };
+			assert_noop!(
+				Dmarket::execute_trade(
+					RuntimeOrigin::signed(sender),
+					seller,
+					buyer,
+					other_trade.clone(),
+					signatures.clone(),
+					fee_address
+				),
+				Error::<Test>::InvalidSellerSignature
+			);
 
 			assert_ok!(Dmarket::execute_trade(
 				RuntimeOrigin::signed(sender),
 				seller,
 				buyer,
-				trade.clone(),
-				signatures.clone(),
+				trade,
+				signatures,
 				fee_address
 			));
+			System::assert_last_event(
+				Event::Trade {
+					seller,
+					buyer,
+					collection,
+					item,
+					price,
+					fee,
+					fee_recipients: Default::default(),
+					buyer_fee: 0,
+					asset: None,
+				}
+				.into(),
+			);
 
-			assert_ok!(Nfts::transfer(RuntimeOrigin::signed(buyer), collection, item, seller));
-			assert_noop!(
-				Dmarket::execute_trade(
-					RuntimeOrigin::signed(sender),
+			let signatures =
+				sign_trade(&sender, &fee_address, &other_trade, seller_pair, buyer_pair);
+			assert_ok!(Dmarket::execute_trade(
+				RuntimeOrigin::signed(sender),
+				seller,
+				buyer,
+				other_trade,
+				signatures,
+				fee_address
+			));
+			System::assert_last_event(
+				Event::Trade {
 					seller,
 					buyer,
-					trade.clone(),
-					signatures.clone(),
-					fee_address
-				),
-				Error::<Test>::AskAlreadyExecuted
+					collection: other_collection,
+					item,
+					price,
+					fee,
+					fee_recipients: Default::default(),
+					buyer_fee: 0,
+					asset: None,
+				}
+				.into(),
 			);
 
-			let (ask_hash, _) = Dmarket::hash_ask_bid_data(&trade);
-			ClosedAsks::<Test>::remove(ask_hash);
+			assert_eq!(Nfts::owner(collection, item).unwrap(), buyer);
+			assert_eq!(Nfts::owner(other_collection, item).unwrap(), buyer);
+		})
+	}
+
+	#[test]
+	fn buyer_is_seller() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
+
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = seller.clone();
+
+			let item = 1;
+			let collection = setup_nft(&sender, &seller, item);
+
+			let expiration = Timestamp::get() + 10;
+			let price = 10000000;
+			let fee = 100;
+			Balances::set_balance(&buyer, price * 2);
+
+			let trade = TradeParams {
+				price,
+				fee,
+				collection,
+				item,
+				ask_expiration: expiration,
+				bid_expiration: expiration,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: Default::default(),
+				buyer_fee: 0,
+				asset: None,
+			};
+			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+
 			assert_noop!(
 				Dmarket::execute_trade(
 					RuntimeOrigin::signed(sender),
@@ -407,13 +682,13 @@ mod execute_trade {
 					signatures,
 					fee_address
 				),
-				Error::<Test>::BidAlreadyExecuted
+				Error::<Test>::BuyerIsSeller
 			);
 		})
 	}
 
 	#[test]
-	fn invalid_signatures() {
+	fn ask_or_bid_expired() {
 		new_test_ext().execute_with(|| {
 			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
 
@@ -421,23 +696,28 @@ mod execute_trade {
 			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
 
 			let item = 1;
-			let _ = setup_nft(&sender, &seller, item);
+			let collection = setup_nft(&sender, &seller, item);
 
 			let expiration = Timestamp::get() + 10;
 			let price = 10000000;
 			let fee = 100;
 			Balances::set_balance(&buyer, price * 2);
 
-			let trade = TradeParams {
+			let mut trade = TradeParams {
 				price,
 				fee,
+				collection,
 				item,
-				ask_expiration: expiration,
+				ask_expiration: 0,
 				bid_expiration: expiration,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: Default::default(),
+				buyer_fee: 0,
+				asset: None,
 			};
-			let mut signatures =
+			let signatures =
 				sign_trade(&sender, &fee_address, &trade, seller_pair.clone(), buyer_pair.clone());
-			signatures.ask_signature = EthereumSignature::from(Signature::from_raw([0; 65]));
 
 			assert_noop!(
 				Dmarket::execute_trade(
@@ -448,28 +728,28 @@ mod execute_trade {
 					signatures,
 					fee_address
 				),
-				Error::<Test>::InvalidSellerSignature
+				Error::<Test>::AskExpired
 			);
 
-			let mut signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
-			signatures.bid_signature = EthereumSignature::from(Signature::from_raw([0; 65]));
-
+			trade.ask_expiration = expiration;
+			trade.bid_expiration = 0;
+			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
 			assert_noop!(
 				Dmarket::execute_trade(
-					RuntimeOrigin::signed(sender),
+					RuntimeOrigin::signed(seller),
 					seller,
 					buyer,
 					trade.clone(),
 					signatures,
 					fee_address
 				),
-				Error::<Test>::InvalidBuyerSignature
+				Error::<Test>::BidExpired
 			);
 		})
 	}
 
 	#[test]
-	fn seller_not_owner() {
+	fn collection_not_enabled() {
 		new_test_ext().execute_with(|| {
 			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
 
@@ -477,7 +757,8 @@ mod execute_trade {
 			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
 
 			let item = 1;
-			let _ = setup_nft(&sender, &account(1), item);
+			let collection = setup_nft(&sender, &seller, item);
+			assert_ok!(Dmarket::force_disable_collection(RuntimeOrigin::root(), collection));
 
 			let expiration = Timestamp::get() + 10;
 			let price = 10000000;
@@ -487,9 +768,15 @@ mod execute_trade {
 			let trade = TradeParams {
 				price,
 				fee,
+				collection,
 				item,
 				ask_expiration: expiration,
 				bid_expiration: expiration,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: Default::default(),
+				buyer_fee: 0,
+				asset: None,
 			};
 			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
 
@@ -502,13 +789,13 @@ mod execute_trade {
 					signatures,
 					fee_address
 				),
-				Error::<Test>::SellerNotItemOwner
+				Error::<Test>::CollectionNotEnabled
 			);
 		})
 	}
 
 	#[test]
-	fn buyer_not_enough_funds() {
+	fn item_not_found() {
 		new_test_ext().execute_with(|| {
 			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
 
@@ -516,19 +803,25 @@ mod execute_trade {
 			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
 
 			let item = 1;
-			let _ = setup_nft(&sender, &seller, item);
+			let collection = setup_nft(&sender, &seller, item);
 
 			let expiration = Timestamp::get() + 10;
 			let price = 10000000;
 			let fee = 100;
-			Balances::set_balance(&buyer, price - 10);
+			Balances::set_balance(&buyer, price * 2);
 
 			let trade = TradeParams {
 				price,
 				fee,
-				item,
+				collection,
+				item: item + 1,
 				ask_expiration: expiration,
 				bid_expiration: expiration,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: Default::default(),
+				buyer_fee: 0,
+				asset: None,
 			};
 			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
 
@@ -541,7 +834,1647 @@ mod execute_trade {
 					signatures,
 					fee_address
 				),
-				Error::<Test>::BuyerBalanceTooLow
+				Error::<Test>::ItemNotFound
+			);
+		})
+	}
+
+	#[test]
+	fn already_executed() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
+
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let item = 1;
+			let collection = setup_nft(&sender, &seller, item);
+
+			let expiration = Timestamp::get() + 10;
+			let price = 10000000;
+			let fee = 100;
+			Balances::set_balance(&buyer, price * 2);
+
+			let trade = TradeParams {
+				price,
+				fee,
+				collection,
+				item,
+				ask_expiration: expiration,
+				bid_expiration: expiration,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: Default::default(),
+				buyer_fee: 0,
+				asset: None,
+			};
+			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+
+			assert_ok!(Dmarket::execute_trade(
+				RuntimeOrigin::signed(sender),
+				seller,
+				buyer,
+				trade.clone(),
+				signatures.clone(),
+				fee_address
+			));
+
+			assert_ok!(Nfts::transfer(RuntimeOrigin::signed(buyer), collection, item, seller));
+			assert_noop!(
+				Dmarket::execute_trade(
+					RuntimeOrigin::signed(sender),
+					seller,
+					buyer,
+					trade.clone(),
+					signatures.clone(),
+					fee_address
+				),
+				Error::<Test>::AskAlreadyExecuted
+			);
+
+			let (ask_hash, _) = Dmarket::hash_ask_bid_data(&seller, &buyer, &trade);
+			ClosedAsks::<Test>::remove(ask_hash);
+			assert_noop!(
+				Dmarket::execute_trade(
+					RuntimeOrigin::signed(sender),
+					seller,
+					buyer,
+					trade.clone(),
+					signatures,
+					fee_address
+				),
+				Error::<Test>::BidAlreadyExecuted
+			);
+		})
+	}
+
+	#[test]
+	fn invalid_signatures() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
+
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let item = 1;
+			let collection = setup_nft(&sender, &seller, item);
+
+			let expiration = Timestamp::get() + 10;
+			let price = 10000000;
+			let fee = 100;
+			Balances::set_balance(&buyer, price * 2);
+
+			let trade = TradeParams {
+				price,
+				fee,
+				collection,
+				item,
+				ask_expiration: expiration,
+				bid_expiration: expiration,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: Default::default(),
+				buyer_fee: 0,
+				asset: None,
+			};
+			let mut signatures =
+				sign_trade(&sender, &fee_address, &trade, seller_pair.clone(), buyer_pair.clone());
+			signatures.ask_signature = EthereumSignature::from(Signature::from_raw([0; 65]));
+
+			assert_noop!(
+				Dmarket::execute_trade(
+					RuntimeOrigin::signed(sender),
+					seller,
+					buyer,
+					trade.clone(),
+					signatures,
+					fee_address
+				),
+				Error::<Test>::InvalidSellerSignature
+			);
+
+			let mut signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+			signatures.bid_signature = EthereumSignature::from(Signature::from_raw([0; 65]));
+
+			assert_noop!(
+				Dmarket::execute_trade(
+					RuntimeOrigin::signed(sender),
+					seller,
+					buyer,
+					trade.clone(),
+					signatures,
+					fee_address
+				),
+				Error::<Test>::InvalidBuyerSignature
+			);
+		})
+	}
+
+	#[test]
+	fn seller_not_owner() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
+
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let item = 1;
+			let collection = setup_nft(&sender, &account(1), item);
+
+			let expiration = Timestamp::get() + 10;
+			let price = 10000000;
+			let fee = 100;
+			Balances::set_balance(&buyer, price * 2);
+
+			let trade = TradeParams {
+				price,
+				fee,
+				collection,
+				item,
+				ask_expiration: expiration,
+				bid_expiration: expiration,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: Default::default(),
+				buyer_fee: 0,
+				asset: None,
+			};
+			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+
+			assert_noop!(
+				Dmarket::execute_trade(
+					RuntimeOrigin::signed(sender),
+					seller,
+					buyer,
+					trade.clone(),
+					signatures,
+					fee_address
+				),
+				Error::<Test>::SellerNotItemOwner
+			);
+		})
+	}
+
+	#[test]
+	fn buyer_not_enough_funds() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
+
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let item = 1;
+			let collection = setup_nft(&sender, &seller, item);
+
+			let expiration = Timestamp::get() + 10;
+			let price = 10000000;
+			let fee = 100;
+			Balances::set_balance(&buyer, price - 10);
+
+			let trade = TradeParams {
+				price,
+				fee,
+				collection,
+				item,
+				ask_expiration: expiration,
+				bid_expiration: expiration,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: Default::default(),
+				buyer_fee: 0,
+				asset: None,
+			};
+			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+
+			assert_noop!(
+				Dmarket::execute_trade(
+					RuntimeOrigin::signed(sender),
+					seller,
+					buyer,
+					trade.clone(),
+					signatures,
+					fee_address
+				),
+				Error::<Test>::BuyerBalanceTooLow
+			);
+		})
+	}
+}
+
+mod execute_batch_trade {
+	use super::{
+		execute_trade::{get_trade_accounts, setup_nft},
+		*,
+	};
+
+	fn setup_items(
+		sender: &AccountIdOf<Test>,
+		item_owner: &AccountIdOf<Test>,
+		count: u128,
+	) -> (CollectionId<Test>, BatchItemsOf<Test>) {
+		let collection = setup_nft(sender, item_owner, 1);
+		for item in 2..=count {
+			assert_ok!(Nfts::mint(
+				RuntimeOrigin::signed(*sender),
+				collection,
+				Some(item),
+				*item_owner,
+				None
+			));
+		}
+		(collection, (1..=count).collect::<Vec<_>>().try_into().unwrap())
+	}
+
+	fn sign_batch_trade(
+		caller: &AccountIdOf<Test>,
+		fee_address: &AccountIdOf<Test>,
+		trade: &BatchTradeParamsOf<Test>,
+		seller_pair: KeyPair,
+		buyer_pair: KeyPair,
+	) -> TradeSignaturesOf<Test> {
+		let ask_message: Vec<u8> = Dmarket::get_batch_ask_message(caller, fee_address, trade);
+		let hashed_ask = keccak_256(&ask_message);
+
+		let bid_message: Vec<u8> = Dmarket::get_batch_bid_message(caller, fee_address, trade);
+		let hashed_bid = keccak_256(&bid_message);
+
+		TradeSignatures {
+			ask_signature: EthereumSignature::from(seller_pair.sign_prehashed(&hashed_ask)),
+			bid_signature: EthereumSignature::from(buyer_pair.sign_prehashed(&hashed_bid)),
+		}
+	}
+
+	#[test]
+	fn execute_batch_trade_works() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
+
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let (collection, items) = setup_items(&sender, &seller, 5);
+
+			let expiration = Timestamp::get() + 10;
+			let unit_price = 10000;
+			let fee = 100;
+			Balances::set_balance(&buyer, unit_price * 10);
+
+			let buyer_balance = Balances::balance(&buyer);
+			let fee_address_balance = Balances::balance(&fee_address);
+			let seller_balance = Balances::balance(&seller);
+
+			let trade = BatchTradeParams {
+				unit_price,
+				fee,
+				collection,
+				items,
+				max_quantity: 4,
+				quantity: 3,
+				ask_expiration: expiration,
+				bid_expiration: expiration,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: Default::default(),
+				buyer_fee: 0,
+				asset: None,
+			};
+			let signatures = sign_batch_trade(
+				&sender,
+				&fee_address,
+				&trade,
+				seller_pair.clone(),
+				buyer_pair.clone(),
+			);
+
+			assert_ok!(Dmarket::execute_batch_trade(
+				RuntimeOrigin::signed(sender),
+				seller,
+				buyer,
+				trade.clone(),
+				signatures,
+				fee_address
+			));
+			System::assert_last_event(
+				Event::BatchTrade {
+					seller,
+					buyer,
+					collection,
+					items: vec![1, 2, 3].try_into().unwrap(),
+					unit_price,
+					fee,
+					fee_recipients: Default::default(),
+					buyer_fee: 0,
+					asset: None,
+				}
+				.into(),
+			);
+
+			for item in 1..=3 {
+				assert_eq!(Nfts::owner(collection, item).unwrap(), buyer);
+			}
+			assert_eq!(Nfts::owner(collection, 4).unwrap(), seller);
+			assert_eq!(Balances::balance(&buyer), buyer_balance - unit_price * 3);
+			assert_eq!(Balances::balance(&seller), seller_balance + unit_price * 3 - fee);
+			assert_eq!(Balances::balance(&fee_address), fee_address_balance + fee);
+
+			let (ask_hash, bid_hash) = Dmarket::hash_batch_ask_bid_data(&seller, &buyer, &trade);
+			assert_eq!(RemainingAskQuantity::<Test>::get(ask_hash), Some(1));
+			assert!(!ClosedAsks::<Test>::contains_key(ask_hash));
+			assert!(ClosedBids::<Test>::contains_key(bid_hash));
+
+			// The ask can be filled by another bid until its maximum quantity is sold
+			let trade = BatchTradeParams { quantity: 2, ..trade };
+			let signatures = sign_batch_trade(
+				&sender,
+				&fee_address,
+				&trade,
+				seller_pair.clone(),
+				buyer_pair.clone(),
+			);
+			assert_noop!(
+				Dmarket::execute_batch_trade(
+					RuntimeOrigin::signed(sender),
+					seller,
+					buyer,
+					trade.clone(),
+					signatures,
+					fee_address
+				),
+				Error::<Test>::QuantityUnavailable
+			);
+
+			let trade = BatchTradeParams { quantity: 1, ..trade };
+			let signatures = sign_batch_trade(
+				&sender,
+				&fee_address,
+				&trade,
+				seller_pair.clone(),
+				buyer_pair.clone(),
+			);
+			assert_ok!(Dmarket::execute_batch_trade(
+				RuntimeOrigin::signed(sender),
+				seller,
+				buyer,
+				trade.clone(),
+				signatures,
+				fee_address
+			));
+
+			assert_eq!(Nfts::owner(collection, 4).unwrap(), buyer);
+			assert_eq!(Nfts::owner(collection, 5).unwrap(), seller);
+			assert_eq!(RemainingAskQuantity::<Test>::get(ask_hash), None);
+			assert!(ClosedAsks::<Test>::contains_key(ask_hash));
+
+			let trade = BatchTradeParams { bid_expiration: expiration + 1, ..trade };
+			let signatures =
+				sign_batch_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+			assert_noop!(
+				Dmarket::execute_batch_trade(
+					RuntimeOrigin::signed(sender),
+					seller,
+					buyer,
+					trade,
+					signatures,
+					fee_address
+				),
+				Error::<Test>::AskAlreadyExecuted
+			);
+		})
+	}
+
+	#[test]
+	fn bid_already_executed() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
+
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let (collection, items) = setup_items(&sender, &seller, 4);
+			Balances::set_balance(&buyer, 1000000);
+
+			let expiration = Timestamp::get() + 10;
+			let trade = BatchTradeParams {
+				unit_price: 10000,
+				fee: 100,
+				collection,
+				items,
+				max_quantity: 4,
+				quantity: 2,
+				ask_expiration: expiration,
+				bid_expiration: expiration,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: Default::default(),
+				buyer_fee: 0,
+				asset: None,
+			};
+			let signatures =
+				sign_batch_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+
+			assert_ok!(Dmarket::execute_batch_trade(
+				RuntimeOrigin::signed(sender),
+				seller,
+				buyer,
+				trade.clone(),
+				signatures.clone(),
+				fee_address
+			));
+			assert_noop!(
+				Dmarket::execute_batch_trade(
+					RuntimeOrigin::signed(sender),
+					seller,
+					buyer,
+					trade,
+					signatures,
+					fee_address
+				),
+				Error::<Test>::BidAlreadyExecuted
+			);
+		})
+	}
+
+	#[test]
+	fn identical_bids_of_different_buyers() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
+			let other_buyer_pair = Pair::from_string("//OtherBuyer", None).unwrap();
+
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+			let other_buyer: AccountId20 =
+				EthereumSigner::from(other_buyer_pair.public()).into_account();
+
+			let (collection, items) = setup_items(&sender, &seller, 4);
+			Balances::set_balance(&buyer, 1000000);
+			Balances::set_balance(&other_buyer, 1000000);
+
+			let expiration = Timestamp::get() + 10;
+			let trade = BatchTradeParams {
+				unit_price: 10000,
+				fee: 100,
+				collection,
+				items,
+				max_quantity: 4,
+				quantity: 2,
+				ask_expiration: expiration,
+				bid_expiration: expiration,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: Default::default(),
+				buyer_fee: 0,
+				asset: None,
+			};
+			let signatures =
+				sign_batch_trade(&sender, &fee_address, &trade, seller_pair.clone(), buyer_pair);
+			assert_ok!(Dmarket::execute_batch_trade(
+				RuntimeOrigin::signed(sender),
+				seller,
+				buyer,
+				trade.clone(),
+				signatures,
+				fee_address
+			));
+
+			// The same bid signed by another buyer is not considered executed
+			let signatures =
+				sign_batch_trade(&sender, &fee_address, &trade, seller_pair, other_buyer_pair);
+			assert_ok!(Dmarket::execute_batch_trade(
+				RuntimeOrigin::signed(sender),
+				seller,
+				other_buyer,
+				trade.clone(),
+				signatures,
+				fee_address
+			));
+
+			assert_eq!(Nfts::owner(collection, 2).unwrap(), buyer);
+			assert_eq!(Nfts::owner(collection, 4).unwrap(), other_buyer);
+			let (ask_hash, _) = Dmarket::hash_batch_ask_bid_data(&seller, &buyer, &trade);
+			assert!(ClosedAsks::<Test>::contains_key(ask_hash));
+		})
+	}
+
+	#[test]
+	fn invalid_quantity() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
+
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let (collection, items) = setup_items(&sender, &seller, 2);
+			Balances::set_balance(&buyer, 1000000);
+
+			let expiration = Timestamp::get() + 10;
+			let trade = BatchTradeParams {
+				unit_price: 10000,
+				fee: 100,
+				collection,
+				items,
+				max_quantity: 2,
+				quantity: 1,
+				ask_expiration: expiration,
+				bid_expiration: expiration,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: Default::default(),
+				buyer_fee: 0,
+				asset: None,
+			};
+
+			for (max_quantity, quantity) in [(2, 0), (1, 2), (3, 1)] {
+				let trade = BatchTradeParams { max_quantity, quantity, ..trade.clone() };
+				let signatures = sign_batch_trade(
+					&sender,
+					&fee_address,
+					&trade,
+					seller_pair.clone(),
+					buyer_pair.clone(),
+				);
+				assert_noop!(
+					Dmarket::execute_batch_trade(
+						RuntimeOrigin::signed(sender),
+						seller,
+						buyer,
+						trade,
+						signatures,
+						fee_address
+					),
+					Error::<Test>::InvalidQuantity
+				);
+			}
+		})
+	}
+
+	#[test]
+	fn execute_batch_trade_in_asset() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
+
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let (collection, items) = setup_items(&sender, &seller, 3);
+
+			let expiration = Timestamp::get() + 10;
+			let unit_price = 10000;
+			let fee = 100;
+			let buyer_fee = 50;
+			Balances::set_balance(&buyer, 1000);
+			AssetBalances::set_balance(&buyer, unit_price * 4);
+
+			let trade = BatchTradeParams {
+				unit_price,
+				fee,
+				collection,
+				items,
+				max_quantity: 3,
+				quantity: 2,
+				ask_expiration: expiration,
+				bid_expiration: expiration,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: vec![FeeRecipient { account: account(2), amount: 500 }]
+					.try_into()
+					.unwrap(),
+				buyer_fee,
+				asset: Some(USDC),
+			};
+			let signatures =
+				sign_batch_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+
+			assert_ok!(Dmarket::execute_batch_trade(
+				RuntimeOrigin::signed(sender),
+				seller,
+				buyer,
+				trade.clone(),
+				signatures,
+				fee_address
+			));
+
+			for item in 1..=2 {
+				assert_eq!(Nfts::owner(collection, item).unwrap(), buyer);
+			}
+			assert_eq!(AssetBalances::balance(&buyer), unit_price * 2 - buyer_fee);
+			assert_eq!(AssetBalances::balance(&seller), unit_price * 2 - fee - 500 * 2);
+			assert_eq!(AssetBalances::balance(&fee_address), fee + buyer_fee);
+			assert_eq!(AssetBalances::balance(&account(2)), 500 * 2);
+			assert_eq!(Balances::balance(&buyer), 1000);
+			System::assert_last_event(
+				Event::BatchTrade {
+					seller,
+					buyer,
+					collection,
+					items: vec![1, 2].try_into().unwrap(),
+					unit_price,
+					fee,
+					fee_recipients: trade.fee_recipients,
+					buyer_fee,
+					asset: Some(USDC),
+				}
+				.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn fees_exceed_price() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
+
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let (collection, items) = setup_items(&sender, &seller, 2);
+			Balances::set_balance(&buyer, 1000000);
+
+			let expiration = Timestamp::get() + 10;
+			let trade = BatchTradeParams {
+				unit_price: 1000,
+				fee: 100,
+				collection,
+				items,
+				max_quantity: 2,
+				quantity: 2,
+				ask_expiration: expiration,
+				bid_expiration: expiration,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: vec![FeeRecipient { account: account(2), amount: 951 }]
+					.try_into()
+					.unwrap(),
+				buyer_fee: 0,
+				asset: None,
+			};
+			let signatures =
+				sign_batch_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+
+			// The fee recipients are paid for every unit sold
+			assert_noop!(
+				Dmarket::execute_batch_trade(
+					RuntimeOrigin::signed(sender),
+					seller,
+					buyer,
+					trade,
+					signatures,
+					fee_address
+				),
+				Error::<Test>::FeesExceedPrice
+			);
+		})
+	}
+
+	#[test]
+	fn price_overflow() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
+
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let (collection, items) = setup_items(&sender, &seller, 2);
+			Balances::set_balance(&buyer, 1000000);
+
+			let expiration = Timestamp::get() + 10;
+			let trade = BatchTradeParams {
+				unit_price: u128::MAX / 2 + 1,
+				fee: 0,
+				collection,
+				items,
+				max_quantity: 2,
+				quantity: 2,
+				ask_expiration: expiration,
+				bid_expiration: expiration,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: Default::default(),
+				buyer_fee: 0,
+				asset: None,
+			};
+			let signatures =
+				sign_batch_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+
+			assert_noop!(
+				Dmarket::execute_batch_trade(
+					RuntimeOrigin::signed(sender),
+					seller,
+					buyer,
+					trade,
+					signatures,
+					fee_address
+				),
+				Error::<Test>::PriceOverflow
+			);
+		})
+	}
+
+	#[test]
+	fn item_not_transferable() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
+
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let (collection, items) = setup_items(&sender, &seller, 3);
+			Balances::set_balance(&buyer, 1000000);
+
+			// Only the last of the sold items is locked
+			assert_ok!(Nfts::lock_item_transfer(RuntimeOrigin::signed(sender), collection, 2));
+
+			let expiration = Timestamp::get() + 10;
+			let trade = BatchTradeParams {
+				unit_price: 1000,
+				fee: 100,
+				collection,
+				items,
+				max_quantity: 3,
+				quantity: 2,
+				ask_expiration: expiration,
+				bid_expiration: expiration,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: Default::default(),
+				buyer_fee: 0,
+				asset: None,
+			};
+			let signatures =
+				sign_batch_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+
+			assert_noop!(
+				Dmarket::execute_batch_trade(
+					RuntimeOrigin::signed(sender),
+					seller,
+					buyer,
+					trade,
+					signatures,
+					fee_address
+				),
+				Error::<Test>::ItemNotTransferable
+			);
+		})
+	}
+}
+
+mod cancel_orders {
+	use super::{
+		execute_trade::{get_trade_accounts, setup_nft, sign_trade},
+		*,
+	};
+
+	fn setup_trade() -> (AccountIdOf<Test>, AccountIdOf<Test>, KeyPair, KeyPair, TradeParamsOf<Test>)
+	{
+		let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
+
+		let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+		let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+		let item = 1;
+		let collection = setup_nft(&sender, &seller, item);
+		Balances::set_balance(&buyer, 1000000);
+
+		let expiration = Timestamp::get() + 10;
+		let trade = TradeParams {
+			price: 10000,
+			fee: 100,
+			collection,
+			item,
+			ask_expiration: expiration,
+			bid_expiration: expiration,
+			ask_nonce: 0,
+			bid_nonce: 0,
+			fee_recipients: Default::default(),
+			buyer_fee: 0,
+			asset: None,
+		};
+		(sender, fee_address, seller_pair, buyer_pair, trade)
+	}
+
+	#[test]
+	fn cancel_ask_works() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair, trade) = setup_trade();
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+
+			// Only the owner of the item can cancel an ask
+			assert_noop!(
+				Dmarket::cancel_ask(
+					RuntimeOrigin::signed(buyer),
+					sender,
+					fee_address,
+					trade.clone(),
+					signatures.ask_signature.clone()
+				),
+				Error::<Test>::SellerNotItemOwner
+			);
+
+			assert_ok!(Dmarket::cancel_ask(
+				RuntimeOrigin::signed(seller),
+				sender,
+				fee_address,
+				trade.clone(),
+				signatures.ask_signature.clone()
+			));
+			let (ask_hash, _) = Dmarket::hash_ask_bid_data(&seller, &buyer, &trade);
+			System::assert_last_event(Event::AskCancelled { who: seller, hash: ask_hash }.into());
+			assert!(ClosedAsks::<Test>::contains_key(ask_hash));
+			assert!(ClosedOrderExpirations::<Test>::contains_key(
+				Dmarket::expiration_slot(trade.ask_expiration),
+				ClosedOrder::Ask(ask_hash)
+			));
+
+			assert_noop!(
+				Dmarket::cancel_ask(
+					RuntimeOrigin::signed(seller),
+					sender,
+					fee_address,
+					trade.clone(),
+					signatures.ask_signature.clone()
+				),
+				Error::<Test>::AskAlreadyExecuted
+			);
+			assert_noop!(
+				Dmarket::execute_trade(
+					RuntimeOrigin::signed(sender),
+					seller,
+					buyer,
+					trade,
+					signatures,
+					fee_address
+				),
+				Error::<Test>::AskAlreadyExecuted
+			);
+		})
+	}
+
+	#[test]
+	fn cancel_bid_works() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair, trade) = setup_trade();
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+
+			assert_noop!(
+				Dmarket::cancel_bid(
+					RuntimeOrigin::signed(seller),
+					sender,
+					fee_address,
+					trade.clone(),
+					signatures.bid_signature.clone()
+				),
+				Error::<Test>::InvalidSignature
+			);
+
+			assert_ok!(Dmarket::cancel_bid(
+				RuntimeOrigin::signed(buyer),
+				sender,
+				fee_address,
+				trade.clone(),
+				signatures.bid_signature.clone()
+			));
+			let (_, bid_hash) = Dmarket::hash_ask_bid_data(&seller, &buyer, &trade);
+			System::assert_last_event(Event::BidCancelled { who: buyer, hash: bid_hash }.into());
+			assert!(ClosedBids::<Test>::contains_key(bid_hash));
+
+			assert_noop!(
+				Dmarket::execute_trade(
+					RuntimeOrigin::signed(sender),
+					seller,
+					buyer,
+					trade,
+					signatures,
+					fee_address
+				),
+				Error::<Test>::BidAlreadyExecuted
+			);
+		})
+	}
+
+	#[test]
+	fn identical_bid_of_another_account_not_cancelled() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair, trade) = setup_trade();
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let griefer_pair = Pair::from_string("//Griefer", None).unwrap();
+			let griefer: AccountId20 = EthereumSigner::from(griefer_pair.public()).into_account();
+
+			// The griefer signs a bid on the same terms and cancels it first
+			let griefer_signatures =
+				sign_trade(&sender, &fee_address, &trade, seller_pair.clone(), griefer_pair);
+			assert_ok!(Dmarket::cancel_bid(
+				RuntimeOrigin::signed(griefer),
+				sender,
+				fee_address,
+				trade.clone(),
+				griefer_signatures.bid_signature
+			));
+
+			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+			assert_ok!(Dmarket::execute_trade(
+				RuntimeOrigin::signed(sender),
+				seller,
+				buyer,
+				trade,
+				signatures,
+				fee_address
+			));
+		})
+	}
+
+	#[test]
+	fn cancel_expired_order_fails() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair, trade) = setup_trade();
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+			Timestamp::set_timestamp(trade.ask_expiration);
+
+			assert_noop!(
+				Dmarket::cancel_ask(
+					RuntimeOrigin::signed(seller),
+					sender,
+					fee_address,
+					trade.clone(),
+					signatures.ask_signature
+				),
+				Error::<Test>::AskExpired
+			);
+			assert_noop!(
+				Dmarket::cancel_bid(
+					RuntimeOrigin::signed(buyer),
+					sender,
+					fee_address,
+					trade,
+					signatures.bid_signature
+				),
+				Error::<Test>::BidExpired
+			);
+		})
+	}
+
+	#[test]
+	fn bump_nonce_voids_signed_orders() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair, trade) = setup_trade();
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let signatures =
+				sign_trade(&sender, &fee_address, &trade, seller_pair.clone(), buyer_pair.clone());
+
+			assert_ok!(Dmarket::bump_nonce(RuntimeOrigin::signed(seller)));
+			System::assert_last_event(Event::NonceBumped { who: seller, nonce: 1 }.into());
+			assert_eq!(Nonces::<Test>::get(seller), 1);
+
+			assert_noop!(
+				Dmarket::execute_trade(
+					RuntimeOrigin::signed(sender),
+					seller,
+					buyer,
+					trade.clone(),
+					signatures,
+					fee_address
+				),
+				Error::<Test>::InvalidAskNonce
+			);
+
+			assert_ok!(Dmarket::bump_nonce(RuntimeOrigin::signed(buyer)));
+			let trade = TradeParams { ask_nonce: 1, ..trade };
+			let signatures =
+				sign_trade(&sender, &fee_address, &trade, seller_pair.clone(), buyer_pair.clone());
+			assert_noop!(
+				Dmarket::execute_trade(
+					RuntimeOrigin::signed(sender),
+					seller,
+					buyer,
+					trade.clone(),
+					signatures,
+					fee_address
+				),
+				Error::<Test>::InvalidBidNonce
+			);
+
+			// Orders signed with the new nonces are valid
+			let trade = TradeParams { bid_nonce: 1, ..trade };
+			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+			assert_ok!(Dmarket::execute_trade(
+				RuntimeOrigin::signed(sender),
+				seller,
+				buyer,
+				trade,
+				signatures,
+				fee_address
+			));
+		})
+	}
+}
+
+mod validate_trade {
+	use super::{
+		execute_trade::{get_trade_accounts, setup_nft, sign_trade},
+		*,
+	};
+
+	fn setup_trade() -> (AccountIdOf<Test>, AccountIdOf<Test>, KeyPair, KeyPair, TradeParamsOf<Test>)
+	{
+		let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
+
+		let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+		let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+		let item = 1;
+		let collection = setup_nft(&sender, &seller, item);
+		Balances::set_balance(&buyer, 1000000);
+
+		let expiration = Timestamp::get() + 10;
+		let trade = TradeParams {
+			price: 10000,
+			fee: 100,
+			collection,
+			item,
+			ask_expiration: expiration,
+			bid_expiration: expiration,
+			ask_nonce: 0,
+			bid_nonce: 0,
+			fee_recipients: Default::default(),
+			buyer_fee: 0,
+			asset: None,
+		};
+		(sender, fee_address, seller_pair, buyer_pair, trade)
+	}
+
+	fn unbounded(
+		trade: &TradeParamsOf<Test>,
+	) -> TradeParams<CollectionId<Test>, Balance<Test>, Item, u64, Vec<FeeRecipientOf<Test>>, u32>
+	{
+		TradeParams {
+			price: trade.price,
+			fee: trade.fee,
+			collection: trade.collection,
+			item: trade.item,
+			ask_expiration: trade.ask_expiration,
+			bid_expiration: trade.bid_expiration,
+			ask_nonce: trade.ask_nonce,
+			bid_nonce: trade.bid_nonce,
+			fee_recipients: trade.fee_recipients.to_vec(),
+			buyer_fee: trade.buyer_fee,
+			asset: trade.asset,
+		}
+	}
+
+	fn validate(
+		sender: &AccountIdOf<Test>,
+		seller_pair: &KeyPair,
+		buyer_pair: &KeyPair,
+		trade: &TradeParamsOf<Test>,
+		fee_address: &AccountIdOf<Test>,
+	) -> Result<(), TradeError> {
+		let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+		let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+		let signatures =
+			sign_trade(sender, fee_address, trade, seller_pair.clone(), buyer_pair.clone());
+
+		Dmarket::validate_trade(sender, &seller, &buyer, unbounded(trade), signatures, fee_address)
+	}
+
+	#[test]
+	fn validate_trade_works() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair, trade) = setup_trade();
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+			let buyer_balance = Balances::balance(&buyer);
+
+			assert_eq!(validate(&sender, &seller_pair, &buyer_pair, &trade, &fee_address), Ok(()));
+
+			assert_eq!(Nfts::owner(trade.collection, trade.item).unwrap(), seller);
+			assert_eq!(Balances::balance(&buyer), buyer_balance);
+			let (ask_hash, bid_hash) = Dmarket::hash_ask_bid_data(&seller, &buyer, &trade);
+			assert!(!ClosedAsks::<Test>::contains_key(ask_hash));
+			assert!(!ClosedBids::<Test>::contains_key(bid_hash));
+
+			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+			assert_ok!(Dmarket::execute_trade(
+				RuntimeOrigin::signed(sender),
+				seller,
+				buyer,
+				trade,
+				signatures,
+				fee_address
+			));
+		})
+	}
+
+	#[test]
+	fn expired_orders() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair, mut trade) = setup_trade();
+
+			trade.ask_expiration = 0;
+			assert_eq!(
+				validate(&sender, &seller_pair, &buyer_pair, &trade, &fee_address),
+				Err(TradeError::AskExpired)
+			);
+
+			trade.ask_expiration = trade.bid_expiration;
+			trade.bid_expiration = 0;
+			assert_eq!(
+				validate(&sender, &seller_pair, &buyer_pair, &trade, &fee_address),
+				Err(TradeError::BidExpired)
+			);
+		})
+	}
+
+	#[test]
+	fn seller_not_owner() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair, trade) = setup_trade();
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+
+			assert_ok!(Nfts::transfer(
+				RuntimeOrigin::signed(seller),
+				trade.collection,
+				trade.item,
+				account(2)
+			));
+
+			assert_eq!(
+				validate(&sender, &seller_pair, &buyer_pair, &trade, &fee_address),
+				Err(TradeError::SellerNotItemOwner)
+			);
+		})
+	}
+
+	#[test]
+	fn item_not_transferable() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair, trade) = setup_trade();
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			assert_ok!(Nfts::lock_item_transfer(
+				RuntimeOrigin::signed(sender),
+				trade.collection,
+				trade.item
+			));
+
+			assert_eq!(
+				validate(&sender, &seller_pair, &buyer_pair, &trade, &fee_address),
+				Err(TradeError::ItemNotTransferable)
+			);
+
+			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+			assert_noop!(
+				Dmarket::execute_trade(
+					RuntimeOrigin::signed(sender),
+					seller,
+					buyer,
+					trade,
+					signatures,
+					fee_address
+				),
+				Error::<Test>::ItemNotTransferable
+			);
+		})
+	}
+
+	#[test]
+	fn seller_balance_too_low() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair, trade) = setup_trade();
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			// The buyer can pay, but the seller, with no funds of their own, can't pay the whole
+			// price as fee without being reaped.
+			assert_eq!(Balances::balance(&seller), 0);
+			let trade = TradeParams { fee: trade.price, ..trade };
+
+			assert_eq!(
+				validate(&sender, &seller_pair, &buyer_pair, &trade, &fee_address),
+				Err(TradeError::SellerBalanceTooLow)
+			);
+
+			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+			assert_noop!(
+				Dmarket::execute_trade(
+					RuntimeOrigin::signed(sender),
+					seller,
+					buyer,
+					trade,
+					signatures,
+					fee_address
+				),
+				Error::<Test>::SellerBalanceTooLow
+			);
+		})
+	}
+
+	#[test]
+	fn already_executed() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair, trade) = setup_trade();
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let signatures =
+				sign_trade(&sender, &fee_address, &trade, seller_pair.clone(), buyer_pair.clone());
+			assert_ok!(Dmarket::execute_trade(
+				RuntimeOrigin::signed(sender),
+				seller,
+				buyer,
+				trade.clone(),
+				signatures,
+				fee_address
+			));
+			assert_ok!(Nfts::transfer(
+				RuntimeOrigin::signed(buyer),
+				trade.collection,
+				trade.item,
+				seller
+			));
+
+			assert_eq!(
+				validate(&sender, &seller_pair, &buyer_pair, &trade, &fee_address),
+				Err(TradeError::AskAlreadyExecuted)
+			);
+		})
+	}
+
+	#[test]
+	fn invalid_signatures() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair, trade) = setup_trade();
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let signatures =
+				sign_trade(&sender, &fee_address, &trade, buyer_pair.clone(), seller_pair.clone());
+			assert_eq!(
+				Dmarket::validate_trade(
+					&sender,
+					&seller,
+					&buyer,
+					unbounded(&trade),
+					signatures,
+					&fee_address
+				),
+				Err(TradeError::InvalidSellerSignature)
+			);
+		})
+	}
+
+	#[test]
+	fn buyer_balance_too_low() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair, mut trade) = setup_trade();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			Balances::set_balance(&buyer, trade.price + Balances::minimum_balance());
+			assert_eq!(validate(&sender, &seller_pair, &buyer_pair, &trade, &fee_address), Ok(()));
+
+			trade.buyer_fee = 1;
+			assert_eq!(
+				validate(&sender, &seller_pair, &buyer_pair, &trade, &fee_address),
+				Err(TradeError::BuyerBalanceTooLow)
+			);
+		})
+	}
+
+	#[test]
+	fn too_many_fee_recipients() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair, trade) = setup_trade();
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+			let mut params = unbounded(&trade);
+			// One more than the `MaxFeeRecipients` of the mock.
+			params.fee_recipients =
+				(10..16).map(|id| FeeRecipient { account: account(id), amount: 1 }).collect();
+			assert_eq!(
+				Dmarket::validate_trade(&sender, &seller, &buyer, params, signatures, &fee_address),
+				Err(TradeError::TooManyFeeRecipients)
+			);
+		})
+	}
+}
+
+mod closed_order_expiration {
+	use super::{
+		execute_trade::{get_trade_accounts, setup_nft, sign_trade},
+		*,
+	};
+	use sp_runtime::traits::Hash;
+
+	#[test]
+	fn closed_orders_pruned_after_expiration() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
+
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let item = 1;
+			let collection = setup_nft(&sender, &seller, item);
+			Balances::set_balance(&buyer, 1000);
+
+			let now = Timestamp::get();
+			let trade = TradeParams {
+				price: 100,
+				fee: 1,
+				collection,
+				item,
+				ask_expiration: now + 10,
+				bid_expiration: now + 25,
+				ask_nonce: 0,
+				bid_nonce: 0,
+				fee_recipients: Default::default(),
+				buyer_fee: 0,
+				asset: None,
+			};
+			let signatures =
+				sign_trade(&sender, &fee_address, &trade, seller_pair.clone(), buyer_pair.clone());
+
+			assert_ok!(Dmarket::execute_trade(
+				RuntimeOrigin::signed(sender),
+				seller,
+				buyer,
+				trade.clone(),
+				signatures,
+				fee_address
+			));
+
+			let (ask_hash, bid_hash) = Dmarket::hash_ask_bid_data(&seller, &buyer, &trade);
+			let ask_slot = Dmarket::expiration_slot(trade.ask_expiration);
+			let bid_slot = Dmarket::expiration_slot(trade.bid_expiration);
+			assert!(ClosedOrderExpirations::<Test>::contains_key(
+				ask_slot,
+				ClosedOrder::Ask(ask_hash)
+			));
+			assert!(ClosedOrderExpirations::<Test>::contains_key(
+				bid_slot,
+				ClosedOrder::Bid(bid_hash)
+			));
+			assert_eq!(NextExpirationSlot::<Test>::get(), Some(ask_slot));
+
+			// Nothing is pruned before the expiration
+			Dmarket::on_idle(System::block_number(), Weight::MAX);
+			assert!(ClosedAsks::<Test>::contains_key(ask_hash));
+
+			Timestamp::set_timestamp(ask_slot);
+			Dmarket::on_idle(System::block_number(), Weight::MAX);
+			assert!(!ClosedAsks::<Test>::contains_key(ask_hash));
+			assert!(ClosedBids::<Test>::contains_key(bid_hash));
+
+			// The pruned ask can't be replayed, it is expired
+			assert_ok!(Nfts::transfer(RuntimeOrigin::signed(buyer), collection, item, seller));
+			let signatures = sign_trade(&sender, &fee_address, &trade, seller_pair, buyer_pair);
+			assert_noop!(
+				Dmarket::execute_trade(
+					RuntimeOrigin::signed(sender),
+					seller,
+					buyer,
+					trade.clone(),
+					signatures,
+					fee_address
+				),
+				Error::<Test>::AskExpired
+			);
+
+			Timestamp::set_timestamp(bid_slot);
+			Dmarket::on_idle(System::block_number(), Weight::MAX);
+			assert!(!ClosedBids::<Test>::contains_key(bid_hash));
+			assert_eq!(ClosedOrderExpirations::<Test>::iter().count(), 0);
+			assert_eq!(NextExpirationSlot::<Test>::get(), None);
+		})
+	}
+
+	#[test]
+	fn on_idle_respects_weight_limit() {
+		new_test_ext().execute_with(|| {
+			let weight = <() as WeightInfo>::prune_next_closed_order();
+			for i in 0..3u8 {
+				let hash = <Test as frame_system::Config>::Hashing::hash(&[i]);
+				ClosedAsks::<Test>::insert(
+					hash,
+					OrderData { caller: account(0), fee_address: account(1) },
+				);
+				Dmarket::index_closed_order(ClosedOrder::Ask(hash), 10);
+			}
+			Timestamp::set_timestamp(10);
+
+			assert_eq!(Dmarket::on_idle(System::block_number(), weight * 2), weight * 2);
+			assert_eq!(ClosedAsks::<Test>::iter().count(), 1);
+		})
+	}
+}
+
+mod eip712 {
+	use super::{
+		execute_trade::{get_trade_accounts, setup_nft},
+		*,
+	};
+	use crate::eip712::{TypedData, Value};
+
+	fn sign_typed_data(pair: &KeyPair, message: &impl TypedData) -> EthereumSignature {
+		EthereumSignature::from(pair.sign_prehashed(&keccak_256(&message.signing_payload())))
+	}
+
+	fn trade(collection: CollectionId<Test>, price: Balance<Test>) -> TradeParamsOf<Test> {
+		let expiration = Timestamp::get() + 10;
+		TradeParams {
+			price,
+			fee: 100,
+			collection,
+			item: 1,
+			ask_expiration: expiration,
+			bid_expiration: expiration,
+			ask_nonce: 0,
+			bid_nonce: 0,
+			fee_recipients: Default::default(),
+			buyer_fee: 0,
+			asset: None,
+		}
+	}
+
+	#[test]
+	fn execute_trade_with_typed_data_signatures() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
+
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let collection = setup_nft(&sender, &seller, 1);
+			let trade = trade(collection, 10000);
+			Balances::set_balance(&buyer, 1000000);
+
+			let signatures = TradeSignatures {
+				ask_signature: sign_typed_data(
+					&seller_pair,
+					&Dmarket::ask_message(&sender, &fee_address, &trade),
+				),
+				bid_signature: sign_typed_data(
+					&buyer_pair,
+					&Dmarket::bid_message(&sender, &fee_address, &trade),
+				),
+			};
+
+			assert_ok!(Dmarket::execute_trade(
+				RuntimeOrigin::signed(sender),
+				seller,
+				buyer,
+				trade.clone(),
+				signatures,
+				fee_address
+			));
+			assert_eq!(Nfts::owner(collection, trade.item).unwrap(), buyer);
+		})
+	}
+
+	#[test]
+	fn typed_data_signature_of_another_message_fails() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, seller_pair, buyer_pair) = get_trade_accounts();
+
+			let seller: AccountId20 = EthereumSigner::from(seller_pair.public()).into_account();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let collection = setup_nft(&sender, &seller, 1);
+			let trade = trade(collection, 10000);
+			let cheaper_trade = TradeParams { price: 1000, ..trade.clone() };
+			Balances::set_balance(&buyer, 1000000);
+
+			let signatures = TradeSignatures {
+				ask_signature: sign_typed_data(
+					&seller_pair,
+					&Dmarket::ask_message(&sender, &fee_address, &cheaper_trade),
+				),
+				bid_signature: sign_typed_data(
+					&buyer_pair,
+					&Dmarket::bid_message(&sender, &fee_address, &trade),
+				),
+			};
+
+			assert_noop!(
+				Dmarket::execute_trade(
+					RuntimeOrigin::signed(sender),
+					seller,
+					buyer,
+					trade,
+					signatures,
+					fee_address
+				),
+				Error::<Test>::InvalidSellerSignature
+			);
+		})
+	}
+
+	#[test]
+	fn cancel_bid_with_typed_data_signature() {
+		new_test_ext().execute_with(|| {
+			let (sender, fee_address, _, buyer_pair) = get_trade_accounts();
+			let buyer: AccountId20 = EthereumSigner::from(buyer_pair.public()).into_account();
+
+			let trade = trade(0, 10000);
+			let signature =
+				sign_typed_data(&buyer_pair, &Dmarket::bid_message(&sender, &fee_address, &trade));
+
+			assert_ok!(Dmarket::cancel_bid(
+				RuntimeOrigin::signed(buyer),
+				sender,
+				fee_address,
+				trade.clone(),
+				signature
+			));
+			let bid_hash = Dmarket::hash_bid_data(&buyer, &trade);
+			assert!(ClosedBids::<Test>::contains_key(bid_hash));
+		})
+	}
+
+	#[test]
+	fn values_are_encoded_as_words() {
+		let mut word = [0u8; 32];
+		word[30..].copy_from_slice(&[0x01, 0x02]);
+		assert_eq!(Value::uint(64, &0x0102u64).encode_data(), word);
+
+		let mut word = [0u8; 32];
+		word[12..].copy_from_slice(&[7; 20]);
+		assert_eq!(Value::address(&account(7)).encode_data(), word);
+
+		let mut word = [0u8; 32];
+		word[..8].copy_from_slice(b"MYTH_NET");
+		assert_eq!(Value::Bytes8(*b"MYTH_NET").encode_data(), word);
+
+		assert_eq!(Value::uint(128, &0u128).to_json(), "\"0\"");
+		assert_eq!(
+			Value::uint(128, &u128::MAX).to_json(),
+			"\"340282366920938463463374607431768211455\""
+		);
+	}
+
+	#[test]
+	fn ask_typed_data_json() {
+		new_test_ext().execute_with(|| {
+			let trade = TradeParams {
+				ask_nonce: 2,
+				ask_expiration: 1000,
+				fee_recipients: vec![FeeRecipient { account: account(2), amount: 25 }]
+					.try_into()
+					.unwrap(),
+				asset: Some(USDC),
+				..trade(5, u128::MAX)
+			};
+			let message = Dmarket::ask_message(&account(0), &account(1), &trade);
+
+			assert_eq!(
+				message.type_string(),
+				"Ask(bytes8 domain,address sender,address feeAddress,uint256 collection,\
+				 uint128 item,uint256 price,bytes asset,FeeRecipient[] feeRecipients,\
+				 uint32 nonce,uint64 expiration)FeeRecipient(address account,uint256 amount)"
+			);
+			assert_eq!(
+				String::from_utf8(Dmarket::get_ask_typed_data(&account(0), &account(1), &trade))
+					.unwrap(),
+				concat!(
+					"{\"types\":{\"EIP712Domain\":[{\"name\":\"name\",\"type\":\"string\"},",
+					"{\"name\":\"version\",\"type\":\"string\"}],\"Ask\":[",
+					"{\"name\":\"domain\",\"type\":\"bytes8\"},",
+					"{\"name\":\"sender\",\"type\":\"address\"},",
+					"{\"name\":\"feeAddress\",\"type\":\"address\"},",
+					"{\"name\":\"collection\",\"type\":\"uint256\"},",
+					"{\"name\":\"item\",\"type\":\"uint128\"},",
+					"{\"name\":\"price\",\"type\":\"uint256\"},",
+					"{\"name\":\"asset\",\"type\":\"bytes\"},",
+					"{\"name\":\"feeRecipients\",\"type\":\"FeeRecipient[]\"},",
+					"{\"name\":\"nonce\",\"type\":\"uint32\"},",
+					"{\"name\":\"expiration\",\"type\":\"uint64\"}],",
+					"\"FeeRecipient\":[{\"name\":\"account\",\"type\":\"address\"},",
+					"{\"name\":\"amount\",\"type\":\"uint256\"}]},",
+					"\"primaryType\":\"Ask\",",
+					"\"domain\":{\"name\":\"Dmarket\",\"version\":\"5\"},",
+					"\"message\":{\"domain\":\"0x4d5954485f4e4554\",",
+					"\"sender\":\"0x0000000000000000000000000000000000000000\",",
+					"\"feeAddress\":\"0x0101010101010101010101010101010101010101\",",
+					"\"collection\":\"5\",\"item\":\"1\",",
+					"\"price\":\"340282366920938463463374607431768211455\",",
+					"\"asset\":\"0x0101000000\",",
+					"\"feeRecipients\":[{\"account\":",
+					"\"0x0202020202020202020202020202020202020202\",\"amount\":\"25\"}],",
+					"\"nonce\":\"2\",\"expiration\":\"1000\"}}"
+				)
 			);
 		})
 	}
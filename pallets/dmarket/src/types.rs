@@ -1,27 +1,113 @@
-use crate::Config;
-use frame_support::traits::fungible::Inspect;
+use crate::{Config, Error};
+use frame_support::{
+	BoundedVec,
+	traits::{fungible::Inspect, fungibles},
+};
 use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+use sp_std::vec::Vec;
 
 pub type BalanceOf<T> =
 	<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 
+pub type AssetIdOf<T> =
+	<<T as Config>::Assets as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
+
 pub type Item = u128;
 pub type Domain = [u8; 8];
 
+/// The version of the signed ask and bid message schema.
+///
+/// Version 1 messages carried neither a version nor a collection, version 2 adds both and
+/// version 3 adds the nonce of the signer, version 4 adds the fee recipients and the buyer fee
+/// and version 5 adds the asset the trade is settled in, along with the fee recipients and the
+/// buyer fee of batch trades.
+pub const MESSAGE_VERSION: u8 = 5;
+
+/// An account paid out of the price of a trade, e.g. for a creator royalty or a referral.
+#[derive(
+	Clone, Encode, Decode, DecodeWithMemTracking, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen,
+)]
+pub struct FeeRecipient<Account, Amount> {
+	pub account: Account,
+	pub amount: Amount,
+}
+
+pub type FeeRecipientOf<T> = FeeRecipient<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+pub type FeeRecipientsOf<T> = BoundedVec<FeeRecipientOf<T>, <T as Config>::MaxFeeRecipients>;
+
+/// The parameters of a trade.
+///
+/// The seller pays `fee` to the fee address and each of the `fee_recipients` out of the price,
+/// while the buyer pays `buyer_fee` to the fee address on top of the price. All the amounts are
+/// in `asset`, or in the native currency if `None`.
 #[derive(Clone, Encode, Decode, DecodeWithMemTracking, Debug, Eq, PartialEq, TypeInfo)]
-pub struct TradeParams<Amount, ItemId, Expiration> {
+pub struct TradeParams<CollectionId, Amount, ItemId, Expiration, Fees, AssetId> {
 	pub price: Amount,
 	pub fee: Amount,
+	pub collection: CollectionId,
 	pub item: ItemId,
 	pub ask_expiration: Expiration,
 	pub bid_expiration: Expiration,
+	pub ask_nonce: u32,
+	pub bid_nonce: u32,
+	pub fee_recipients: Fees,
+	pub buyer_fee: Amount,
+	pub asset: Option<AssetId>,
 }
 
 pub type TradeParamsOf<T> = TradeParams<
+	<T as pallet_nfts::Config>::CollectionId,
 	<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance,
 	Item,
 	<T as pallet_timestamp::Config>::Moment,
+	FeeRecipientsOf<T>,
+	AssetIdOf<T>,
+>;
+
+/// The parameters of a trade in version 1 of `DmarketApi`, to call older runtimes.
+#[derive(Clone, Encode, Decode, DecodeWithMemTracking, Debug, Eq, PartialEq, TypeInfo)]
+pub struct TradeParamsV1<Amount, ItemId, Expiration> {
+	pub price: Amount,
+	pub fee: Amount,
+	pub item: ItemId,
+	pub ask_expiration: Expiration,
+	pub bid_expiration: Expiration,
+}
+
+/// The parameters of a trade of several identical items, signed by the seller for up to
+/// `max_quantity` of `items` and by the buyer for `quantity` of them.
+///
+/// The seller pays `fee` to the fee address and each of the `fee_recipients` their amount for
+/// every unit sold, while the buyer pays `buyer_fee` to the fee address on top of the price.
+/// All the amounts are in `asset`, or in the native currency if `None`.
+#[derive(Clone, Encode, Decode, DecodeWithMemTracking, Debug, Eq, PartialEq, TypeInfo)]
+pub struct BatchTradeParams<CollectionId, Amount, Items, Expiration, Fees, AssetId> {
+	pub unit_price: Amount,
+	pub fee: Amount,
+	pub collection: CollectionId,
+	pub items: Items,
+	pub max_quantity: u32,
+	pub quantity: u32,
+	pub ask_expiration: Expiration,
+	pub bid_expiration: Expiration,
+	pub ask_nonce: u32,
+	pub bid_nonce: u32,
+	pub fee_recipients: Fees,
+	pub buyer_fee: Amount,
+	pub asset: Option<AssetId>,
+}
+
+pub type BatchItemsOf<T> = BoundedVec<Item, <T as Config>::MaxBatchItems>;
+
+pub type BatchTradeParamsOf<T> = BatchTradeParams<
+	<T as pallet_nfts::Config>::CollectionId,
+	BalanceOf<T>,
+	BatchItemsOf<T>,
+	<T as pallet_timestamp::Config>::Moment,
+	FeeRecipientsOf<T>,
+	AssetIdOf<T>,
 >;
 
 #[derive(Clone, Encode, Decode, DecodeWithMemTracking, Debug, Eq, PartialEq, TypeInfo)]
@@ -32,21 +118,82 @@ pub struct TradeSignatures<OffchainSignature> {
 
 pub type TradeSignaturesOf<T> = TradeSignatures<<T as pallet_nfts::Config>::OffchainSignature>;
 
+/// The reason a trade can't be executed, as reported by the `validate_trade` runtime API.
+///
+/// Each variant matches the pallet error `execute_trade` fails with.
 #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo)]
-pub struct AskMessage<Account, Amount, ItemId, Expiration> {
+pub enum TradeError {
+	BuyerIsSeller,
+	AskExpired,
+	BidExpired,
+	InvalidAskNonce,
+	InvalidBidNonce,
+	TooManyFeeRecipients,
+	FeesExceedPrice,
+	UnknownAsset,
+	CollectionNotEnabled,
+	ItemNotFound,
+	SellerNotItemOwner,
+	ItemNotTransferable,
+	AskAlreadyExecuted,
+	BidAlreadyExecuted,
+	InvalidSellerSignature,
+	InvalidBuyerSignature,
+	BuyerBalanceTooLow,
+	PriceOverflow,
+	SellerBalanceTooLow,
+	PaymentNotReceivable,
+}
+
+impl<T: Config> From<TradeError> for Error<T> {
+	fn from(error: TradeError) -> Self {
+		match error {
+			TradeError::BuyerIsSeller => Error::BuyerIsSeller,
+			TradeError::AskExpired => Error::AskExpired,
+			TradeError::BidExpired => Error::BidExpired,
+			TradeError::InvalidAskNonce => Error::InvalidAskNonce,
+			TradeError::InvalidBidNonce => Error::InvalidBidNonce,
+			TradeError::TooManyFeeRecipients => Error::TooManyFeeRecipients,
+			TradeError::FeesExceedPrice => Error::FeesExceedPrice,
+			TradeError::UnknownAsset => Error::UnknownAsset,
+			TradeError::CollectionNotEnabled => Error::CollectionNotEnabled,
+			TradeError::ItemNotFound => Error::ItemNotFound,
+			TradeError::SellerNotItemOwner => Error::SellerNotItemOwner,
+			TradeError::ItemNotTransferable => Error::ItemNotTransferable,
+			TradeError::AskAlreadyExecuted => Error::AskAlreadyExecuted,
+			TradeError::BidAlreadyExecuted => Error::BidAlreadyExecuted,
+			TradeError::InvalidSellerSignature => Error::InvalidSellerSignature,
+			TradeError::InvalidBuyerSignature => Error::InvalidBuyerSignature,
+			TradeError::BuyerBalanceTooLow => Error::BuyerBalanceTooLow,
+			TradeError::PriceOverflow => Error::PriceOverflow,
+			TradeError::SellerBalanceTooLow => Error::SellerBalanceTooLow,
+			TradeError::PaymentNotReceivable => Error::PaymentNotReceivable,
+		}
+	}
+}
+
+#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo)]
+pub struct AskMessage<Account, Amount, CollectionId, ItemId, Expiration, AssetId> {
+	pub version: u8,
 	pub domain: Domain,
 	pub sender: Account,
 	pub fee_address: Account,
+	pub collection: CollectionId,
 	pub item: ItemId,
 	pub price: Amount,
+	pub asset: Option<AssetId>,
+	pub fee_recipients: Vec<FeeRecipient<Account, Amount>>,
+	pub nonce: u32,
 	pub expiration: Expiration,
 }
 
 pub type AskMessageOf<T> = AskMessage<
 	<T as frame_system::Config>::AccountId,
 	<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance,
+	<T as pallet_nfts::Config>::CollectionId,
 	Item,
 	<T as pallet_timestamp::Config>::Moment,
+	AssetIdOf<T>,
 >;
 
 #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
@@ -57,24 +204,73 @@ pub struct OrderData<Account> {
 
 pub type OrderDataOf<T> = OrderData<<T as frame_system::Config>::AccountId>;
 
+/// Identifies a closed ask or bid by the hash of its trade data.
+#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub enum ClosedOrder<Hash> {
+	Ask(Hash),
+	Bid(Hash),
+}
+
 #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo)]
-pub struct BidMessage<Account, Amount, ItemId, Expiration> {
+pub struct BidMessage<Account, Amount, CollectionId, ItemId, Expiration, AssetId> {
+	pub version: u8,
 	pub domain: Domain,
 	pub sender: Account,
 	pub fee_address: Account,
+	pub collection: CollectionId,
 	pub item: ItemId,
 	pub price: Amount,
+	pub asset: Option<AssetId>,
 	pub fee: Amount,
+	pub fee_recipients: Vec<FeeRecipient<Account, Amount>>,
+	pub buyer_fee: Amount,
+	pub nonce: u32,
 	pub expiration: Expiration,
 }
 
 pub type BidMessageOf<T> = BidMessage<
 	<T as frame_system::Config>::AccountId,
 	<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance,
+	<T as pallet_nfts::Config>::CollectionId,
 	Item,
 	<T as pallet_timestamp::Config>::Moment,
+	AssetIdOf<T>,
 >;
 
+#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo)]
+pub struct BatchAskMessage<Account, Amount, CollectionId, Items, Expiration, AssetId> {
+	pub version: u8,
+	pub domain: Domain,
+	pub sender: Account,
+	pub fee_address: Account,
+	pub collection: CollectionId,
+	pub items: Items,
+	pub unit_price: Amount,
+	pub asset: Option<AssetId>,
+	pub fee_recipients: Vec<FeeRecipient<Account, Amount>>,
+	pub max_quantity: u32,
+	pub nonce: u32,
+	pub expiration: Expiration,
+}
+
+#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo)]
+pub struct BatchBidMessage<Account, Amount, CollectionId, Items, Expiration, AssetId> {
+	pub version: u8,
+	pub domain: Domain,
+	pub sender: Account,
+	pub fee_address: Account,
+	pub collection: CollectionId,
+	pub items: Items,
+	pub unit_price: Amount,
+	pub asset: Option<AssetId>,
+	pub quantity: u32,
+	pub fee: Amount,
+	pub fee_recipients: Vec<FeeRecipient<Account, Amount>>,
+	pub buyer_fee: Amount,
+	pub nonce: u32,
+	pub expiration: Expiration,
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 pub trait BenchmarkHelper<CollectionId, Moment> {
 	/// Returns a collection id from a given integer.
@@ -38,8 +38,14 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for `pallet_dmarket`.
 pub trait WeightInfo {
-	fn force_set_collection() -> Weight;
-	fn execute_trade() -> Weight;
+	fn force_enable_collection() -> Weight;
+	fn force_disable_collection() -> Weight;
+	fn execute_trade(n: u32, ) -> Weight;
+	fn prune_next_closed_order() -> Weight;
+	fn execute_batch_trade(n: u32, f: u32, ) -> Weight;
+	fn cancel_ask() -> Weight;
+	fn cancel_bid() -> Weight;
+	fn bump_nonce() -> Weight;
 }
 
 /// Weights for `pallet_dmarket` using the Substrate node and recommended hardware.
@@ -47,9 +53,9 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 	impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Nfts::Collection` (r:1 w:0)
 	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
-	/// Storage: `Dmarket::DmarketCollection` (r:1 w:1)
-	/// Proof: `Dmarket::DmarketCollection` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
-	fn force_set_collection() -> Weight {
+	/// Storage: `Dmarket::DmarketCollections` (r:1 w:1)
+	/// Proof: `Dmarket::DmarketCollections` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn force_enable_collection() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `420`
 		//  Estimated: `3610`
@@ -58,6 +64,17 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Dmarket::DmarketCollections` (r:1 w:1)
+	/// Proof: `Dmarket::DmarketCollections` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn force_disable_collection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `182`
+		//  Estimated: `3513`
+		// Minimum execution time: 12_430_000 picoseconds.
+		Weight::from_parts(12_890_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	
 	/// Storage: `Dmarket::BalanceManager` (r:1 w:0)
 	/// Proof: `Dmarket::BalanceManager` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
@@ -65,8 +82,10 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Dmarket::FeeAddress` (r:1 w:0)
 	/// Proof: `Dmarket::FeeAddress` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
-	/// Storage: `Dmarket::DmarketCollection` (r:1 w:0)
-	/// Proof: `Dmarket::DmarketCollection` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::Nonces` (r:2 w:0)
+	/// Proof: `Dmarket::Nonces` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::DmarketCollections` (r:1 w:0)
+	/// Proof: `Dmarket::DmarketCollections` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Item` (r:1 w:1)
 	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(637), added: 3112, mode: `MaxEncodedLen`)
 	/// Storage: `Dmarket::ClosedAsks` (r:1 w:1)
@@ -87,14 +106,147 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
 	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(151), added: 2626, mode: `MaxEncodedLen`)
-	fn execute_trade() -> Weight {
+	/// Storage: `Dmarket::NextExpirationSlot` (r:1 w:1)
+	/// Proof: `Dmarket::NextExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedOrderExpirations` (r:0 w:2)
+	/// Proof: `Dmarket::ClosedOrderExpirations` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:5 w:5)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 5]`.
+	fn execute_trade(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1228`
-		//  Estimated: `4102`
-		// Minimum execution time: 172_460_000 picoseconds.
-		Weight::from_parts(174_840_000, 4102)
-			.saturating_add(T::DbWeight::get().reads(11_u64))
-			.saturating_add(T::DbWeight::get().writes(7_u64))
+		//  Measured:  `1228 + n * (20 ±0)`
+		//  Estimated: `4102 + n * (2591 ±0)`
+		// Minimum execution time: 279_110_000 picoseconds.
+		Weight::from_parts(281_530_000, 4102)
+			// Standard Error: 18_000
+			.saturating_add(Weight::from_parts(41_372_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(n.into()))
+	}
+	/// Storage: `Dmarket::NextExpirationSlot` (r:1 w:1)
+	/// Proof: `Dmarket::NextExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedOrderExpirations` (r:2 w:1)
+	/// Proof: `Dmarket::ClosedOrderExpirations` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedAsks` (r:0 w:1)
+	/// Proof: `Dmarket::ClosedAsks` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::RemainingAskQuantity` (r:0 w:1)
+	/// Proof: `Dmarket::RemainingAskQuantity` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn prune_next_closed_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `3522`
+		// Minimum execution time: 10_652_000 picoseconds.
+		Weight::from_parts(11_009_000, 3522)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::Nonces` (r:2 w:0)
+	/// Proof: `Dmarket::Nonces` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::DmarketCollections` (r:1 w:0)
+	/// Proof: `Dmarket::DmarketCollections` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedAsks` (r:1 w:1)
+	/// Proof: `Dmarket::ClosedAsks` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedBids` (r:1 w:1)
+	/// Proof: `Dmarket::ClosedBids` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::RemainingAskQuantity` (r:1 w:1)
+	/// Proof: `Dmarket::RemainingAskQuantity` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::NextExpirationSlot` (r:1 w:1)
+	/// Proof: `Dmarket::NextExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:10 w:10)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(637), added: 3112, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Collection` (r:1 w:0)
+	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Attribute` (r:10 w:0)
+	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(495), added: 2970, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(142), added: 2617, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemConfigOf` (r:10 w:0)
+	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Account` (r:0 w:20)
+	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:10)
+	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:10)
+	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(151), added: 2626, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedOrderExpirations` (r:0 w:2)
+	/// Proof: `Dmarket::ClosedOrderExpirations` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:8 w:8)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 10]`.
+	/// The range of component `f` is `[0, 5]`.
+	fn execute_batch_trade(n: u32, f: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1094 + f * (20 ±0) + n * (178 ±0)`
+		//  Estimated: `3610 + f * (2591 ±0) + n * (3336 ±0)`
+		// Minimum execution time: 161_340_000 picoseconds.
+		Weight::from_parts(129_816_000, 3610)
+			// Standard Error: 41_000
+			.saturating_add(Weight::from_parts(38_262_000, 0).saturating_mul(n.into()))
+			// Standard Error: 24_000
+			.saturating_add(Weight::from_parts(41_372_000, 0).saturating_mul(f.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(f.into())))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(f.into())))
+			.saturating_add(Weight::from_parts(0, 3336).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(f.into()))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:1 w:0)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(637), added: 3112, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedAsks` (r:1 w:1)
+	/// Proof: `Dmarket::ClosedAsks` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::NextExpirationSlot` (r:1 w:1)
+	/// Proof: `Dmarket::NextExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedOrderExpirations` (r:0 w:1)
+	/// Proof: `Dmarket::ClosedOrderExpirations` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	fn cancel_ask() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146`
+		//  Estimated: `4112`
+		// Minimum execution time: 108_872_000 picoseconds.
+		Weight::from_parts(109_570_000, 4112)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedBids` (r:1 w:1)
+	/// Proof: `Dmarket::ClosedBids` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::NextExpirationSlot` (r:1 w:1)
+	/// Proof: `Dmarket::NextExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedOrderExpirations` (r:0 w:1)
+	/// Proof: `Dmarket::ClosedOrderExpirations` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	fn cancel_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146`
+		//  Estimated: `3524`
+		// Minimum execution time: 109_194_000 picoseconds.
+		Weight::from_parts(110_011_000, 3524)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Dmarket::Nonces` (r:1 w:1)
+	/// Proof: `Dmarket::Nonces` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn bump_nonce() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3505`
+		// Minimum execution time: 7_341_000 picoseconds.
+		Weight::from_parts(7_620_000, 3505)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 }
 
@@ -102,9 +254,9 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl WeightInfo for () {
 	/// Storage: `Nfts::Collection` (r:1 w:0)
 	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
-	/// Storage: `Dmarket::DmarketCollection` (r:1 w:1)
-	/// Proof: `Dmarket::DmarketCollection` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
-	fn force_set_collection() -> Weight {
+	/// Storage: `Dmarket::DmarketCollections` (r:1 w:1)
+	/// Proof: `Dmarket::DmarketCollections` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn force_enable_collection() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `420`
 		//  Estimated: `3610`
@@ -113,6 +265,17 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Dmarket::DmarketCollections` (r:1 w:1)
+	/// Proof: `Dmarket::DmarketCollections` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn force_disable_collection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `182`
+		//  Estimated: `3513`
+		// Minimum execution time: 12_430_000 picoseconds.
+		Weight::from_parts(12_890_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 
 	/// Storage: `Dmarket::BalanceManager` (r:1 w:0)
 	/// Proof: `Dmarket::BalanceManager` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
@@ -120,8 +283,10 @@ impl WeightInfo for () {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Dmarket::FeeAddress` (r:1 w:0)
 	/// Proof: `Dmarket::FeeAddress` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
-	/// Storage: `Dmarket::DmarketCollection` (r:1 w:0)
-	/// Proof: `Dmarket::DmarketCollection` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::Nonces` (r:2 w:0)
+	/// Proof: `Dmarket::Nonces` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::DmarketCollections` (r:1 w:0)
+	/// Proof: `Dmarket::DmarketCollections` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Item` (r:1 w:1)
 	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(637), added: 3112, mode: `MaxEncodedLen`)
 	/// Storage: `Dmarket::ClosedAsks` (r:1 w:1)
@@ -142,13 +307,146 @@ impl WeightInfo for () {
 	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
 	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(151), added: 2626, mode: `MaxEncodedLen`)
-	fn execute_trade() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1228`
-		//  Estimated: `4102`
-		// Minimum execution time: 172_460_000 picoseconds.
-		Weight::from_parts(174_840_000, 4102)
-			.saturating_add(RocksDbWeight::get().reads(11_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	/// Storage: `Dmarket::NextExpirationSlot` (r:1 w:1)
+	/// Proof: `Dmarket::NextExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedOrderExpirations` (r:0 w:2)
+	/// Proof: `Dmarket::ClosedOrderExpirations` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:5 w:5)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 5]`.
+	fn execute_trade(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1228 + n * (20 ±0)`
+		//  Estimated: `4102 + n * (2591 ±0)`
+		// Minimum execution time: 279_110_000 picoseconds.
+		Weight::from_parts(281_530_000, 4102)
+			// Standard Error: 18_000
+			.saturating_add(Weight::from_parts(41_372_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(n.into()))
+	}
+	/// Storage: `Dmarket::NextExpirationSlot` (r:1 w:1)
+	/// Proof: `Dmarket::NextExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedOrderExpirations` (r:2 w:1)
+	/// Proof: `Dmarket::ClosedOrderExpirations` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedAsks` (r:0 w:1)
+	/// Proof: `Dmarket::ClosedAsks` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::RemainingAskQuantity` (r:0 w:1)
+	/// Proof: `Dmarket::RemainingAskQuantity` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn prune_next_closed_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `3522`
+		// Minimum execution time: 10_652_000 picoseconds.
+		Weight::from_parts(11_009_000, 3522)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::Nonces` (r:2 w:0)
+	/// Proof: `Dmarket::Nonces` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::DmarketCollections` (r:1 w:0)
+	/// Proof: `Dmarket::DmarketCollections` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedAsks` (r:1 w:1)
+	/// Proof: `Dmarket::ClosedAsks` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedBids` (r:1 w:1)
+	/// Proof: `Dmarket::ClosedBids` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::RemainingAskQuantity` (r:1 w:1)
+	/// Proof: `Dmarket::RemainingAskQuantity` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::NextExpirationSlot` (r:1 w:1)
+	/// Proof: `Dmarket::NextExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:10 w:10)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(637), added: 3112, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Collection` (r:1 w:0)
+	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Attribute` (r:10 w:0)
+	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(495), added: 2970, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(142), added: 2617, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemConfigOf` (r:10 w:0)
+	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Account` (r:0 w:20)
+	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:10)
+	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:10)
+	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(151), added: 2626, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedOrderExpirations` (r:0 w:2)
+	/// Proof: `Dmarket::ClosedOrderExpirations` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:8 w:8)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 10]`.
+	/// The range of component `f` is `[0, 5]`.
+	fn execute_batch_trade(n: u32, f: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1094 + f * (20 ±0) + n * (178 ±0)`
+		//  Estimated: `3610 + f * (2591 ±0) + n * (3336 ±0)`
+		// Minimum execution time: 161_340_000 picoseconds.
+		Weight::from_parts(129_816_000, 3610)
+			// Standard Error: 41_000
+			.saturating_add(Weight::from_parts(38_262_000, 0).saturating_mul(n.into()))
+			// Standard Error: 24_000
+			.saturating_add(Weight::from_parts(41_372_000, 0).saturating_mul(f.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(f.into())))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(f.into())))
+			.saturating_add(Weight::from_parts(0, 3336).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(f.into()))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:1 w:0)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(637), added: 3112, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedAsks` (r:1 w:1)
+	/// Proof: `Dmarket::ClosedAsks` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::NextExpirationSlot` (r:1 w:1)
+	/// Proof: `Dmarket::NextExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedOrderExpirations` (r:0 w:1)
+	/// Proof: `Dmarket::ClosedOrderExpirations` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	fn cancel_ask() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146`
+		//  Estimated: `4112`
+		// Minimum execution time: 108_872_000 picoseconds.
+		Weight::from_parts(109_570_000, 4112)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedBids` (r:1 w:1)
+	/// Proof: `Dmarket::ClosedBids` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::NextExpirationSlot` (r:1 w:1)
+	/// Proof: `Dmarket::NextExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedOrderExpirations` (r:0 w:1)
+	/// Proof: `Dmarket::ClosedOrderExpirations` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	fn cancel_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146`
+		//  Estimated: `3524`
+		// Minimum execution time: 109_194_000 picoseconds.
+		Weight::from_parts(110_011_000, 3524)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Dmarket::Nonces` (r:1 w:1)
+	/// Proof: `Dmarket::Nonces` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn bump_nonce() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3505`
+		// Minimum execution time: 7_341_000 picoseconds.
+		Weight::from_parts(7_620_000, 3505)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 }
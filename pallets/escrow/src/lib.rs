@@ -181,27 +181,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let revoker = ensure_signed(origin)?;
 
-			Deposits::<T>::mutate_exists(&address, &revoker, |maybe_deposit| -> DispatchResult {
-				if let Some(deposit) = maybe_deposit.as_mut() {
-					T::Currency::release(&HoldReason::Escrow.into(), &address, *deposit, Exact)?;
-
-					T::Currency::transfer(&address, &destination, *deposit, Expendable)?;
-
-					Self::deposit_event(Event::Revoked {
-						account: address.clone(),
-						destination: destination.clone(),
-						agent: revoker.clone(),
-						value: *deposit,
-						reason,
-					});
-
-					*maybe_deposit = None;
-
-					Ok(())
-				} else {
-					Err(Error::<T>::NoSuchDeposit.into())
-				}
-			})
+			Self::do_revoke(&address, &revoker, &destination, reason)
 		}
 
 		/// Forcefully revokes a deposit under special conditions, overriding typical checks.
@@ -300,6 +280,35 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	pub fn do_revoke(
+		address: &T::AccountId,
+		agent: &T::AccountId,
+		destination: &T::AccountId,
+		reason: Vec<u8>,
+	) -> DispatchResult {
+		Deposits::<T>::mutate_exists(address, agent, |maybe_deposit| -> DispatchResult {
+			if let Some(deposit) = maybe_deposit.as_mut() {
+				T::Currency::release(&HoldReason::Escrow.into(), address, *deposit, Exact)?;
+
+				T::Currency::transfer(address, destination, *deposit, Expendable)?;
+
+				Self::deposit_event(Event::Revoked {
+					account: address.clone(),
+					destination: destination.clone(),
+					agent: agent.clone(),
+					value: *deposit,
+					reason,
+				});
+
+				*maybe_deposit = None;
+
+				Ok(())
+			} else {
+				Err(Error::<T>::NoSuchDeposit.into())
+			}
+		})
+	}
+
 	pub fn get_deposit(address: &T::AccountId, agent: &T::AccountId) -> BalanceOf<T> {
 		Deposits::<T>::get(address, agent)
 	}
@@ -0,0 +1,186 @@
+//! RPC interface for the marketplace pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+use pallet_marketplace::{Ask, Bid, CollectionStats, OrderKey, Sale};
+use pallet_nfts::ItemId;
+use parity_scale_codec::Codec;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_marketplace::MarketplaceApi as MarketplaceRuntimeApi;
+
+#[rpc(client, server)]
+pub trait MarketplaceApi<BlockHash, AccountId, Balance, Moment, CollectionId, AssetId> {
+	/// The active Ask on an item, if any.
+	#[method(name = "marketplace_askFor")]
+	fn ask_for(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Ask<AccountId, Balance, Moment, AccountId, AssetId>>>;
+
+	/// The active Bids on an item with their price, from the highest to the lowest price.
+	#[method(name = "marketplace_bidsFor")]
+	fn bids_for(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(Balance, Bid<AccountId, Balance, Moment, AssetId>)>>;
+
+	/// The active Bid with the highest price on an item, if any.
+	#[method(name = "marketplace_bestBid")]
+	fn best_bid(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<(Balance, Bid<AccountId, Balance, Moment, AssetId>)>>;
+
+	/// The keys of all the orders and auctions created by an account.
+	#[method(name = "marketplace_ordersByAccount")]
+	fn orders_by_account(
+		&self,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<OrderKey<CollectionId, ItemId, Balance, AccountId>>>;
+
+	/// The amount held from a bidder for a Bid of `price` paying `fee`.
+	#[method(name = "marketplace_bidPayment")]
+	fn bid_payment(
+		&self,
+		price: Balance,
+		fee: Balance,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Balance>>;
+
+	/// The trading statistics of a collection in an asset, the native currency if `None`.
+	#[method(name = "marketplace_collectionStats")]
+	fn collection_stats(
+		&self,
+		collection: CollectionId,
+		asset: Option<AssetId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<CollectionStats<Balance>>>;
+
+	/// The most recent sales of a collection, from the oldest to the newest.
+	#[method(name = "marketplace_recentSales")]
+	fn recent_sales(
+		&self,
+		collection: CollectionId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Sale<AccountId, ItemId, Balance, Moment, AssetId>>>;
+}
+
+/// Provides the `marketplace_*` RPC methods.
+pub struct Marketplace<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Marketplace<C, Block> {
+	/// Creates a new instance of the marketplace RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error code of a failed runtime API call.
+const RUNTIME_ERROR: i32 = 1;
+
+fn runtime_error(error: impl ToString) -> ErrorObjectOwned {
+	ErrorObject::owned(RUNTIME_ERROR, "Unable to query the marketplace", Some(error.to_string()))
+}
+
+impl<C, Block, AccountId, Balance, Moment, CollectionId, AssetId>
+	MarketplaceApiServer<<Block as BlockT>::Hash, AccountId, Balance, Moment, CollectionId, AssetId>
+	for Marketplace<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: MarketplaceRuntimeApi<Block, AccountId, Balance, Moment, CollectionId, AssetId>,
+	AccountId: Codec,
+	Balance: Codec,
+	Moment: Codec,
+	CollectionId: Codec,
+	AssetId: Codec,
+{
+	fn ask_for(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Ask<AccountId, Balance, Moment, AccountId, AssetId>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().ask_for(at, collection, item).map_err(runtime_error)
+	}
+
+	fn bids_for(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(Balance, Bid<AccountId, Balance, Moment, AssetId>)>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().bids_for(at, collection, item).map_err(runtime_error)
+	}
+
+	fn best_bid(
+		&self,
+		collection: CollectionId,
+		item: ItemId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<(Balance, Bid<AccountId, Balance, Moment, AssetId>)>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().best_bid(at, collection, item).map_err(runtime_error)
+	}
+
+	fn orders_by_account(
+		&self,
+		who: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<OrderKey<CollectionId, ItemId, Balance, AccountId>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().orders_by_account(at, who).map_err(runtime_error)
+	}
+
+	fn bid_payment(
+		&self,
+		price: Balance,
+		fee: Balance,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Balance>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().bid_payment(at, price, fee).map_err(runtime_error)
+	}
+
+	fn collection_stats(
+		&self,
+		collection: CollectionId,
+		asset: Option<AssetId>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<CollectionStats<Balance>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.collection_stats(at, collection, asset)
+			.map_err(runtime_error)
+	}
+
+	fn recent_sales(
+		&self,
+		collection: CollectionId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<Sale<AccountId, ItemId, Balance, Moment, AssetId>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().recent_sales(at, collection).map_err(runtime_error)
+	}
+}
@@ -0,0 +1,246 @@
+use core::marker::PhantomData;
+use frame_support::traits::{
+	fungibles::{Dust, Inspect, InspectHold, Mutate, MutateHold, Unbalanced, UnbalancedHold},
+	tokens::{
+		DepositConsequence, Fortitude, Precision, Preservation, Provenance, WithdrawConsequence,
+	},
+};
+use sp_runtime::{DispatchError, DispatchResult};
+
+/// Adds `fungibles::Mutate` to an implementation of the fungibles traits with holds that lacks
+/// it, such as `pallet-assets-holder`, every other call being forwarded to `Assets`.
+///
+/// The `fungibles::Mutate` hooks are forwarded to `Events`, the assets `Assets` holds funds of
+/// such as `pallet-assets`, so that transfers, mints and burns still emit their events.
+pub struct AssetsWithHolds<Assets, Events>(PhantomData<(Assets, Events)>);
+
+impl<AccountId, Assets: Inspect<AccountId>, Events> Inspect<AccountId>
+	for AssetsWithHolds<Assets, Events>
+{
+	type AssetId = Assets::AssetId;
+	type Balance = Assets::Balance;
+
+	fn total_issuance(asset: Self::AssetId) -> Self::Balance {
+		Assets::total_issuance(asset)
+	}
+
+	fn active_issuance(asset: Self::AssetId) -> Self::Balance {
+		Assets::active_issuance(asset)
+	}
+
+	fn minimum_balance(asset: Self::AssetId) -> Self::Balance {
+		Assets::minimum_balance(asset)
+	}
+
+	fn total_balance(asset: Self::AssetId, who: &AccountId) -> Self::Balance {
+		Assets::total_balance(asset, who)
+	}
+
+	fn balance(asset: Self::AssetId, who: &AccountId) -> Self::Balance {
+		Assets::balance(asset, who)
+	}
+
+	fn reducible_balance(
+		asset: Self::AssetId,
+		who: &AccountId,
+		preservation: Preservation,
+		force: Fortitude,
+	) -> Self::Balance {
+		Assets::reducible_balance(asset, who, preservation, force)
+	}
+
+	fn can_deposit(
+		asset: Self::AssetId,
+		who: &AccountId,
+		amount: Self::Balance,
+		provenance: Provenance,
+	) -> DepositConsequence {
+		Assets::can_deposit(asset, who, amount, provenance)
+	}
+
+	fn can_withdraw(
+		asset: Self::AssetId,
+		who: &AccountId,
+		amount: Self::Balance,
+	) -> WithdrawConsequence<Self::Balance> {
+		Assets::can_withdraw(asset, who, amount)
+	}
+
+	fn asset_exists(asset: Self::AssetId) -> bool {
+		Assets::asset_exists(asset)
+	}
+}
+
+impl<AccountId, Assets: Unbalanced<AccountId>, Events> Unbalanced<AccountId>
+	for AssetsWithHolds<Assets, Events>
+{
+	fn handle_dust(dust: Dust<AccountId, Self>) {
+		Assets::handle_dust(Dust(dust.0, dust.1))
+	}
+
+	fn write_balance(
+		asset: Self::AssetId,
+		who: &AccountId,
+		amount: Self::Balance,
+	) -> Result<Option<Self::Balance>, DispatchError> {
+		Assets::write_balance(asset, who, amount)
+	}
+
+	fn set_total_issuance(asset: Self::AssetId, amount: Self::Balance) {
+		Assets::set_total_issuance(asset, amount)
+	}
+
+	fn decrease_balance(
+		asset: Self::AssetId,
+		who: &AccountId,
+		amount: Self::Balance,
+		precision: Precision,
+		preservation: Preservation,
+		force: Fortitude,
+	) -> Result<Self::Balance, DispatchError> {
+		Assets::decrease_balance(asset, who, amount, precision, preservation, force)
+	}
+
+	fn increase_balance(
+		asset: Self::AssetId,
+		who: &AccountId,
+		amount: Self::Balance,
+		precision: Precision,
+	) -> Result<Self::Balance, DispatchError> {
+		Assets::increase_balance(asset, who, amount, precision)
+	}
+
+	fn deactivate(asset: Self::AssetId, amount: Self::Balance) {
+		Assets::deactivate(asset, amount)
+	}
+
+	fn reactivate(asset: Self::AssetId, amount: Self::Balance) {
+		Assets::reactivate(asset, amount)
+	}
+}
+
+impl<AccountId, Assets, Events> Mutate<AccountId> for AssetsWithHolds<Assets, Events>
+where
+	AccountId: Eq,
+	Assets: Unbalanced<AccountId>,
+	Events: Mutate<AccountId, AssetId = Assets::AssetId, Balance = Assets::Balance>,
+{
+	fn done_mint_into(asset: Self::AssetId, who: &AccountId, amount: Self::Balance) {
+		Events::done_mint_into(asset, who, amount)
+	}
+
+	fn done_burn_from(asset: Self::AssetId, who: &AccountId, amount: Self::Balance) {
+		Events::done_burn_from(asset, who, amount)
+	}
+
+	fn done_shelve(asset: Self::AssetId, who: &AccountId, amount: Self::Balance) {
+		Events::done_shelve(asset, who, amount)
+	}
+
+	fn done_restore(asset: Self::AssetId, who: &AccountId, amount: Self::Balance) {
+		Events::done_restore(asset, who, amount)
+	}
+
+	fn done_transfer(
+		asset: Self::AssetId,
+		source: &AccountId,
+		dest: &AccountId,
+		amount: Self::Balance,
+	) {
+		Events::done_transfer(asset, source, dest, amount)
+	}
+}
+
+impl<AccountId, Assets: InspectHold<AccountId>, Events> InspectHold<AccountId>
+	for AssetsWithHolds<Assets, Events>
+{
+	type Reason = Assets::Reason;
+
+	fn total_balance_on_hold(asset: Self::AssetId, who: &AccountId) -> Self::Balance {
+		Assets::total_balance_on_hold(asset, who)
+	}
+
+	fn reducible_total_balance_on_hold(
+		asset: Self::AssetId,
+		who: &AccountId,
+		force: Fortitude,
+	) -> Self::Balance {
+		Assets::reducible_total_balance_on_hold(asset, who, force)
+	}
+
+	fn balance_on_hold(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &AccountId,
+	) -> Self::Balance {
+		Assets::balance_on_hold(asset, reason, who)
+	}
+
+	fn hold_available(asset: Self::AssetId, reason: &Self::Reason, who: &AccountId) -> bool {
+		Assets::hold_available(asset, reason, who)
+	}
+}
+
+impl<AccountId, Assets: InspectHold<AccountId> + UnbalancedHold<AccountId>, Events>
+	UnbalancedHold<AccountId> for AssetsWithHolds<Assets, Events>
+{
+	fn set_balance_on_hold(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		Assets::set_balance_on_hold(asset, reason, who, amount)
+	}
+}
+
+impl<AccountId, Assets: MutateHold<AccountId>, Events> MutateHold<AccountId>
+	for AssetsWithHolds<Assets, Events>
+{
+	fn done_hold(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &AccountId,
+		amount: Self::Balance,
+	) {
+		Assets::done_hold(asset, reason, who, amount)
+	}
+
+	fn done_release(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &AccountId,
+		amount: Self::Balance,
+	) {
+		Assets::done_release(asset, reason, who, amount)
+	}
+
+	fn done_burn_held(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &AccountId,
+		amount: Self::Balance,
+	) {
+		Assets::done_burn_held(asset, reason, who, amount)
+	}
+
+	fn done_transfer_on_hold(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		source: &AccountId,
+		dest: &AccountId,
+		amount: Self::Balance,
+	) {
+		Assets::done_transfer_on_hold(asset, reason, source, dest, amount)
+	}
+
+	fn done_transfer_and_hold(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		source: &AccountId,
+		dest: &AccountId,
+		transferred: Self::Balance,
+	) {
+		Assets::done_transfer_and_hold(asset, reason, source, dest, transferred)
+	}
+}
@@ -16,7 +16,8 @@ use sp_io::{
 	crypto::{ecdsa_generate, ecdsa_sign_prehashed},
 	hashing::keccak_256,
 };
-use sp_std::vec;
+use sp_runtime::Perbill;
+use sp_std::{vec, vec::Vec};
 
 use sp_core::ecdsa::Signature;
 
@@ -81,6 +82,20 @@ fn mint_nft<T: Config>(nft_id: ItemId) -> T::AccountId {
 	caller
 }
 
+fn mint_bundle<T: Config>(n: u32) -> (T::AccountId, BundleItemsOf<T>) {
+	let seller = mint_nft::<T>(T::BenchmarkHelper::item(1));
+	let collection = T::BenchmarkHelper::collection(0);
+
+	let mut items = vec![(collection, T::BenchmarkHelper::item(1))];
+	for i in 2..=n {
+		let item = T::BenchmarkHelper::item(i as u16);
+		assert_ok!(Nfts::<T>::mint_into(&collection, &item, &seller, &ItemConfig::default(), true));
+		items.push((collection, item));
+	}
+
+	(seller, items.try_into().unwrap())
+}
+
 #[benchmarks(where T::AccountId: From<AccountId20>, T::Signature: From<EthereumSignature>)]
 pub mod benchmarks {
 	use super::*;
@@ -96,6 +111,7 @@ pub mod benchmarks {
 		price: BalanceOf<T>,
 		fee_signer: Public,
 		escrow_agent: Option<T::AccountId>,
+		escrow_settlement: EscrowSettlement,
 	) where
 		T::Signature: From<EthereumSignature>,
 	{
@@ -107,10 +123,14 @@ pub mod benchmarks {
 			price,
 			fee: BalanceOf::<T>::from(0u8),
 			escrow_agent,
+			escrow_settlement,
+			asset: None,
 			signature_data: SignatureData {
 				signature: EthereumSignature::from(Signature::from_raw([0; 65])).into(),
 				nonce: vec![0],
+				deadline: nonce_deadline::<T>(),
 			},
+			buyer: None,
 		};
 		append_valid_signature::<T>(fee_signer, &mut order);
 
@@ -121,13 +141,117 @@ pub mod benchmarks {
 		));
 	}
 
+	fn create_bids<T: Config>(bidder: T::AccountId, n: u32, fee_signer: Public)
+	where
+		T::Signature: From<EthereumSignature>,
+	{
+		for i in 0..n {
+			let mut order = Order {
+				order_type: OrderType::Bid,
+				collection: T::BenchmarkHelper::collection(0),
+				item: T::BenchmarkHelper::item(1),
+				expires_at: Timestamp::<T>::get() + T::BenchmarkHelper::timestamp(100000),
+				price: BalanceOf::<T>::from(1000u32 + i),
+				fee: BalanceOf::<T>::from(0u8),
+				escrow_agent: None,
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
+				signature_data: SignatureData {
+					signature: EthereumSignature::from(Signature::from_raw([0; 65])).into(),
+					nonce: i.encode(),
+					deadline: nonce_deadline::<T>(),
+				},
+				buyer: None,
+			};
+			append_valid_signature::<T>(fee_signer.clone(), &mut order);
+
+			assert_ok!(Marketplace::<T>::create_order(
+				RawOrigin::Signed(bidder.clone()).into(),
+				order,
+				Execution::AllowCreation
+			));
+		}
+	}
+
+	/// Creates a bundle Ask of `b` items minted to `seller`, if `b` isn't zero. Returns the number
+	/// of created orders.
+	fn create_bundle_ask<T: Config>(seller: T::AccountId, b: u32, fee_signer: Public) -> u32
+	where
+		T::Signature: From<EthereumSignature>,
+	{
+		if b == 0 {
+			return 0;
+		}
+
+		let collection = T::BenchmarkHelper::collection(0);
+		let mut items = Vec::new();
+		for i in 0..b {
+			let item = T::BenchmarkHelper::item((i + 2) as u16);
+			assert_ok!(Nfts::<T>::mint_into(
+				&collection,
+				&item,
+				&seller,
+				&ItemConfig::default(),
+				true
+			));
+			items.push((collection, item));
+		}
+
+		let ask = bundle_order::<T>(
+			OrderType::Ask,
+			items.try_into().unwrap(),
+			BalanceOf::<T>::from(10000u32),
+			fee_signer,
+			vec![0xff],
+		);
+		assert_ok!(Marketplace::<T>::create_bundle_order(
+			RawOrigin::Signed(seller).into(),
+			ask,
+			Execution::AllowCreation
+		));
+		1
+	}
+
+	fn bundle_order<T: Config>(
+		order_type: OrderType,
+		items: BundleItemsOf<T>,
+		price: BalanceOf<T>,
+		fee_signer: Public,
+		nonce: Vec<u8>,
+	) -> BundleOrderOf<T>
+	where
+		T::Signature: From<EthereumSignature>,
+	{
+		let mut order = BundleOrder {
+			order_type,
+			items,
+			price,
+			expires_at: Timestamp::<T>::get() + T::BenchmarkHelper::timestamp(100000),
+			fee: BalanceOf::<T>::from(0u8),
+			asset: None,
+			signature_data: SignatureData {
+				signature: EthereumSignature::from(Signature::from_raw([0; 65])).into(),
+				nonce,
+				deadline: nonce_deadline::<T>(),
+			},
+		};
+		let message: BundleOrderMessageOf<T> = order.clone().into();
+		order.signature_data.signature = sign::<T>(fee_signer, &message.encode());
+		order
+	}
+
 	fn append_valid_signature<T: Config>(fee_signer: Public, order: &mut OrderOf<T>)
 	where
 		T::Signature: From<EthereumSignature>,
 	{
 		let message: OrderMessageOf<T> = order.clone().into();
+		let mut payload = message.encode();
+		if order.escrow_settlement == EscrowSettlement::Marketplace {
+			order.escrow_settlement.encode_to(&mut payload);
+		}
+		order.signature_data.deadline.encode_to(&mut payload);
 
-		let hashed = keccak_256(&message.encode());
+		let hashed = keccak_256(&payload);
 
 		let signature =
 			EthereumSignature::from(ecdsa_sign_prehashed(0.into(), &fee_signer, &hashed).unwrap());
@@ -149,10 +273,12 @@ pub mod benchmarks {
 
 		assert_ok!(Marketplace::<T>::force_set_authority(RawOrigin::Root.into(), admin.clone()));
 
-		assert_ok!(Marketplace::<T>::set_fee_signer_address(
-			RawOrigin::Signed(admin.clone()).into(),
-			admin.clone(),
-		));
+		// The admin is the last fee signer, so that the signature is checked against every signer.
+		let mut fee_signers: Vec<_> = (1..T::MaxFeeSigners::get())
+			.map(|i| (account::<T::AccountId>("feeSigner", i, SEED), FeeSignerInfo::default()))
+			.collect();
+		fee_signers.push((admin.clone(), FeeSignerInfo::default()));
+		FeeSigners::<T>::put(FeeSignersOf::<T>::truncate_from(fee_signers));
 		assert_ok!(Marketplace::<T>::set_payout_address(
 			RawOrigin::Signed(admin.clone()).into(),
 			admin.clone(),
@@ -161,6 +287,121 @@ pub mod benchmarks {
 		(admin, admin_public)
 	}
 
+	fn fee_signers<T: Config>(count: u32) -> FeeSignersOf<T> {
+		FeeSignersOf::<T>::truncate_from(
+			(0..count)
+				.map(|i| (account("feeSigner", i, SEED), FeeSignerInfo::default()))
+				.collect(),
+		)
+	}
+
+	fn auction_order<T: Config>(
+		kind: AuctionKind<BalanceOf<T>>,
+		fee_signer: Public,
+	) -> AuctionOrderOf<T>
+	where
+		T::Signature: From<EthereumSignature>,
+	{
+		let now = Timestamp::<T>::get();
+		let mut auction = AuctionOrder {
+			collection: T::BenchmarkHelper::collection(0),
+			item: T::BenchmarkHelper::item(1),
+			kind,
+			start_price: BalanceOf::<T>::from(10000u16),
+			starts_at: now,
+			ends_at: now + T::BenchmarkHelper::timestamp(100000),
+			fee: BalanceOf::<T>::from(0u8),
+			asset: None,
+			signature_data: SignatureData {
+				signature: EthereumSignature::from(Signature::from_raw([0; 65])).into(),
+				nonce: vec![0],
+				deadline: nonce_deadline::<T>(),
+			},
+		};
+		let message: AuctionMessageOf<T> = auction.clone().into();
+		auction.signature_data.signature = sign::<T>(fee_signer, &message.encode());
+		auction
+	}
+
+	fn auction_bid_signature<T: Config>(
+		amount: BalanceOf<T>,
+		fee: BalanceOf<T>,
+		nonce: Vec<u8>,
+		fee_signer: Public,
+	) -> SignatureData<T::Signature, Vec<u8>, T::Moment>
+	where
+		T::Signature: From<EthereumSignature>,
+	{
+		let message: AuctionBidMessageOf<T> = AuctionBidMessage {
+			collection: T::BenchmarkHelper::collection(0),
+			item: T::BenchmarkHelper::item(1),
+			amount,
+			fee,
+			nonce: nonce.clone(),
+			asset: None,
+		};
+		SignatureData {
+			signature: sign::<T>(fee_signer, &message.encode()),
+			nonce,
+			deadline: nonce_deadline::<T>(),
+		}
+	}
+
+	fn english_auction_with_bid<T: Config>() -> (T::AccountId, T::AccountId, AuctionOrderOf<T>)
+	where
+		T::AccountId: From<AccountId20>,
+		T::Signature: From<EthereumSignature>,
+	{
+		let seller = mint_nft::<T>(T::BenchmarkHelper::item(1));
+		let (_, fee_signer) = admin_accounts_setup::<T>();
+		set_royalty::<T>();
+
+		let kind = AuctionKind::English { min_increment: BalanceOf::<T>::from(100u16) };
+		let auction = auction_order::<T>(kind, fee_signer);
+		assert_ok!(Marketplace::<T>::create_auction(
+			RawOrigin::Signed(seller.clone()).into(),
+			auction.clone()
+		));
+
+		let bidder: T::AccountId = funded_and_whitelisted_account::<T>("bidder", 0);
+		let fee = BalanceOf::<T>::from(0u8);
+		let signature_data =
+			auction_bid_signature::<T>(auction.start_price, fee, vec![1], fee_signer);
+		assert_ok!(Marketplace::<T>::bid_on_auction(
+			RawOrigin::Signed(bidder.clone()).into(),
+			auction.collection,
+			auction.item,
+			auction.start_price,
+			fee,
+			signature_data
+		));
+
+		(seller, bidder, auction)
+	}
+
+	fn nonce_deadline<T: Config>() -> T::Moment {
+		Timestamp::<T>::get() + T::BenchmarkHelper::timestamp(100000)
+	}
+
+	fn set_royalty<T: Config>() {
+		let creator: T::AccountId = funded_and_whitelisted_account::<T>("creator", 0);
+		Royalties::<T>::insert(
+			T::BenchmarkHelper::collection(0),
+			Royalty { recipient: creator, rate: T::MaxRoyalty::get() },
+		);
+	}
+
+	fn sign<T: Config>(fee_signer: Public, payload: &[u8]) -> T::Signature
+	where
+		T::Signature: From<EthereumSignature>,
+	{
+		let mut payload = payload.to_vec();
+		nonce_deadline::<T>().encode_to(&mut payload);
+		let hashed = keccak_256(&payload);
+		EthereumSignature::from(ecdsa_sign_prehashed(0.into(), &fee_signer, &hashed).unwrap())
+			.into()
+	}
+
 	#[benchmark]
 	fn force_set_authority() {
 		let authority: T::AccountId = account("authority", 0, SEED);
@@ -193,8 +434,153 @@ pub mod benchmarks {
 		assert_last_event::<T>(Event::PayoutAddressUpdated { payout_address }.into());
 	}
 
+	#[benchmark]
+	fn add_fee_signer() {
+		let admin: T::AccountId = get_admin::<T>();
+		FeeSigners::<T>::put(fee_signers::<T>(T::MaxFeeSigners::get() - 1));
+		let fee_signer: T::AccountId = account("newFeeSigner", 0, SEED);
+		let info = FeeSignerInfo {
+			valid_from: Some(T::BenchmarkHelper::timestamp(1000)),
+			valid_until: Some(T::BenchmarkHelper::timestamp(100000)),
+			max_fee: Some(Perbill::from_percent(10)),
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), fee_signer.clone(), info.clone());
+
+		assert_last_event::<T>(Event::FeeSignerAdded { fee_signer, info }.into());
+	}
+
+	#[benchmark]
+	fn remove_fee_signer() {
+		let admin: T::AccountId = get_admin::<T>();
+		FeeSigners::<T>::put(fee_signers::<T>(T::MaxFeeSigners::get()));
+		let fee_signer: T::AccountId = account("feeSigner", 0, SEED);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), fee_signer.clone());
+
+		assert_last_event::<T>(Event::FeeSignerRemoved { fee_signer }.into());
+	}
+
+	#[benchmark]
+	fn set_fee_policy() {
+		let admin: T::AccountId = get_admin::<T>();
+		let collection = Some(T::BenchmarkHelper::collection(0));
+		let policy = Some(FeePolicy { min_fee_bps: 100, max_fee_bps: 1000 });
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), collection, policy.clone());
+
+		assert_last_event::<T>(Event::FeePolicySet { collection, policy }.into());
+	}
+
+	#[benchmark]
+	fn set_trading_paused() {
+		let admin: T::AccountId = get_admin::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), true);
+
+		assert_last_event::<T>(Event::TradingPauseSet { paused: true }.into());
+	}
+
+	#[benchmark]
+	fn set_collection_halted() {
+		let admin: T::AccountId = get_admin::<T>();
+		let collection = T::BenchmarkHelper::collection(0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), collection, true);
+
+		assert_last_event::<T>(Event::CollectionHaltSet { collection, halted: true }.into());
+	}
+
+	fn escrowed_trade<T: Config>(disputed: bool) -> TradeEscrowOf<T> {
+		let seller: T::AccountId = funded_and_whitelisted_account::<T>("seller", 0);
+		let buyer: T::AccountId = funded_and_whitelisted_account::<T>("buyer", 0);
+		let agent: T::AccountId = funded_and_whitelisted_account::<T>("escrow", 0);
+		let amount = <T as Config>::Currency::minimum_balance() * BalanceOf::<T>::from(100u16);
+		assert_ok!(T::Escrow::make_deposit(
+			&buyer,
+			&seller,
+			amount,
+			&Marketplace::<T>::escrow_account(0)
+		));
+
+		let escrow = TradeEscrow {
+			collection: T::BenchmarkHelper::collection(0),
+			item: T::BenchmarkHelper::item(1),
+			seller,
+			buyer,
+			agent,
+			amount,
+			release_at: Timestamp::<T>::get() + T::BenchmarkHelper::timestamp(100),
+			disputed,
+		};
+		Escrows::<T>::insert(0, escrow.clone());
+		escrow
+	}
+
+	#[benchmark]
+	fn confirm_receipt() {
+		let escrow = escrowed_trade::<T>(false);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(escrow.buyer), 0);
+
+		assert!(Escrows::<T>::get(0).is_none());
+		assert_last_event::<T>(
+			Event::EscrowReleased { escrow_id: 0, seller: escrow.seller, amount: escrow.amount }
+				.into(),
+		);
+	}
+
+	#[benchmark]
+	fn release_escrow() {
+		let escrow = escrowed_trade::<T>(false);
+		let caller: T::AccountId = funded_and_whitelisted_account::<T>("caller", 0);
+		Timestamp::<T>::set_timestamp(escrow.release_at);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), 0);
+
+		assert!(Escrows::<T>::get(0).is_none());
+		assert_last_event::<T>(
+			Event::EscrowReleased { escrow_id: 0, seller: escrow.seller, amount: escrow.amount }
+				.into(),
+		);
+	}
+
+	#[benchmark]
+	fn dispute_escrow() {
+		let escrow = escrowed_trade::<T>(false);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(escrow.buyer), 0);
+
+		assert!(Escrows::<T>::get(0).unwrap().disputed);
+		assert_last_event::<T>(Event::EscrowDisputed { escrow_id: 0 }.into());
+	}
+
+	// Refunding the buyer is heavier than releasing the proceeds to the seller.
+	#[benchmark]
+	fn resolve_escrow_dispute() {
+		let escrow = escrowed_trade::<T>(true);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(escrow.agent), 0, true);
+
+		assert!(Escrows::<T>::get(0).is_none());
+		assert_last_event::<T>(
+			Event::EscrowRefunded { escrow_id: 0, buyer: escrow.buyer, amount: escrow.amount }
+				.into(),
+		);
+	}
+
 	// Benchmark `create_order` wxtrinsic with the worst possible conditions:
 	// Ask already exists
+	// Collection has a royalty
 	// Matching Bid is created and executed
 	#[benchmark]
 	fn create_order() {
@@ -214,6 +600,15 @@ pub mod benchmarks {
 			price,
 			fee_signer,
 			Some(escrow.clone()),
+			EscrowSettlement::Marketplace,
+		);
+
+		// Setup royalty
+		let creator: T::AccountId = funded_and_whitelisted_account::<T>("creator", 0);
+		let rate = T::MaxRoyalty::get();
+		Royalties::<T>::insert(
+			T::BenchmarkHelper::collection(0),
+			Royalty { recipient: creator.clone(), rate },
 		);
 
 		// Setup buyer
@@ -226,10 +621,14 @@ pub mod benchmarks {
 			price,
 			fee: ed,
 			escrow_agent: Some(escrow),
+			escrow_settlement: EscrowSettlement::Marketplace,
+			asset: None,
 			signature_data: SignatureData {
 				signature: EthereumSignature::from(Signature::from_raw([0; 65])).into(),
 				nonce: vec![1],
+				deadline: nonce_deadline::<T>(),
 			},
+			buyer: None,
 		};
 		append_valid_signature::<T>(fee_signer, &mut order);
 
@@ -245,6 +644,10 @@ pub mod benchmarks {
 				price: order.price,
 				seller_fee: BalanceOf::<T>::from(0u8),
 				buyer_fee: order.fee,
+				royalty_recipient: Some(creator),
+				royalty: rate * order.price,
+				asset: None,
+				escrow_id: Some(0),
 			}
 			.into(),
 		);
@@ -265,7 +668,14 @@ pub mod benchmarks {
 		let bidder: T::AccountId = funded_and_whitelisted_account::<T>("bidder", 0);
 
 		let (_, fee_signer_public) = admin_accounts_setup::<T>();
-		create_valid_order::<T>(OrderType::Bid, bidder.clone(), price, fee_signer_public, None);
+		create_valid_order::<T>(
+			OrderType::Bid,
+			bidder.clone(),
+			price,
+			fee_signer_public,
+			None,
+			EscrowSettlement::Agent,
+		);
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(bidder.clone()), OrderType::Bid, collection, item, price);
@@ -273,5 +683,326 @@ pub mod benchmarks {
 		assert_last_event::<T>(Event::OrderCanceled { collection, item, who: bidder }.into());
 	}
 
+	// Benchmark `cancel_all_orders` extrinsic with the worst possible conditions:
+	// Cancel `n` bids and a bundle Ask of `b` items, unlocking all of them
+	#[benchmark]
+	fn cancel_all_orders(
+		n: Linear<0, { T::MaxOrdersPerAccount::get() - 1 }>,
+		b: Linear<0, { T::MaxBundleItems::get() }>,
+	) {
+		let _ = mint_nft::<T>(T::BenchmarkHelper::item(1));
+		let bidder: T::AccountId = funded_and_whitelisted_account::<T>("bidder", 0);
+		let (_, fee_signer_public) = admin_accounts_setup::<T>();
+		create_bids::<T>(bidder.clone(), n, fee_signer_public.clone());
+		let count = n + create_bundle_ask::<T>(bidder.clone(), b, fee_signer_public);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(bidder.clone()));
+
+		assert!(AccountOrders::<T>::get(&bidder).is_empty());
+		assert_last_event::<T>(Event::AllOrdersCanceled { who: bidder, count }.into());
+	}
+
+	#[benchmark]
+	fn force_cancel_all_orders(
+		n: Linear<0, { T::MaxOrdersPerAccount::get() - 1 }>,
+		b: Linear<0, { T::MaxBundleItems::get() }>,
+	) {
+		let _ = mint_nft::<T>(T::BenchmarkHelper::item(1));
+		let bidder: T::AccountId = funded_and_whitelisted_account::<T>("bidder", 0);
+		let (admin, fee_signer_public) = admin_accounts_setup::<T>();
+		create_bids::<T>(bidder.clone(), n, fee_signer_public.clone());
+		let count = n + create_bundle_ask::<T>(bidder.clone(), b, fee_signer_public);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), bidder.clone());
+
+		assert!(AccountOrders::<T>::get(&bidder).is_empty());
+		assert_last_event::<T>(Event::AllOrdersCanceled { who: bidder, count }.into());
+	}
+
+	// Benchmark `update_order` extrinsic with the worst possible conditions:
+	// Collection has a royalty
+	// Bid price is raised to match an existing Ask, which is executed
+	#[benchmark]
+	fn update_order() {
+		let collection = T::BenchmarkHelper::collection(0);
+		let item = T::BenchmarkHelper::item(1);
+		let seller = mint_nft::<T>(item);
+		let (_, fee_signer) = admin_accounts_setup::<T>();
+		set_royalty::<T>();
+
+		let price = BalanceOf::<T>::from(10000u16);
+		create_valid_order::<T>(
+			OrderType::Ask,
+			seller.clone(),
+			price,
+			fee_signer,
+			None,
+			EscrowSettlement::Agent,
+		);
+
+		let buyer: T::AccountId = funded_and_whitelisted_account::<T>("buyer", 0);
+		let current_price = BalanceOf::<T>::from(9000u16);
+		let mut bid = Order {
+			order_type: OrderType::Bid,
+			collection,
+			item,
+			expires_at: Timestamp::<T>::get() + T::BenchmarkHelper::timestamp(100000),
+			price: current_price,
+			fee: BalanceOf::<T>::from(0u8),
+			escrow_agent: None,
+			escrow_settlement: EscrowSettlement::Agent,
+			asset: None,
+			signature_data: SignatureData {
+				signature: EthereumSignature::from(Signature::from_raw([0; 65])).into(),
+				nonce: vec![1],
+				deadline: nonce_deadline::<T>(),
+			},
+			buyer: None,
+		};
+		append_valid_signature::<T>(fee_signer, &mut bid);
+		assert_ok!(Marketplace::<T>::create_order(
+			RawOrigin::Signed(buyer.clone()).into(),
+			bid,
+			Execution::AllowCreation
+		));
+
+		let mut update = OrderUpdate {
+			order_type: OrderType::Bid,
+			collection,
+			item,
+			current_price,
+			price,
+			expires_at: Timestamp::<T>::get() + T::BenchmarkHelper::timestamp(100000),
+			fee: BalanceOf::<T>::from(0u8),
+			signature_data: SignatureData {
+				signature: EthereumSignature::from(Signature::from_raw([0; 65])).into(),
+				nonce: vec![2],
+				deadline: nonce_deadline::<T>(),
+			},
+		};
+		let message: OrderUpdateMessageOf<T> = update.clone().into();
+		update.signature_data.signature = sign::<T>(fee_signer, &message.encode());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(buyer.clone()), update);
+
+		assert_eq!(pallet_nfts::Pallet::<T>::owner(collection, item), Some(buyer));
+	}
+
+	// Benchmark `create_bundle_order` extrinsic with the worst possible conditions:
+	// Bundle Ask already exists
+	// Collection has a royalty
+	// Matching bundle Bid is created and executed
+	#[benchmark]
+	fn create_bundle_order(n: Linear<1, { T::MaxBundleItems::get() }>) {
+		let (seller, items) = mint_bundle::<T>(n);
+		let (_, fee_signer) = admin_accounts_setup::<T>();
+		set_royalty::<T>();
+
+		let price = BalanceOf::<T>::from(10000u16);
+		let ask = bundle_order::<T>(OrderType::Ask, items.clone(), price, fee_signer, vec![0]);
+		assert_ok!(Marketplace::<T>::create_bundle_order(
+			RawOrigin::Signed(seller.clone()).into(),
+			ask,
+			Execution::AllowCreation
+		));
+
+		let buyer: T::AccountId = funded_and_whitelisted_account::<T>("buyer", 0);
+		let bid = bundle_order::<T>(OrderType::Bid, items.clone(), price, fee_signer, vec![1]);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(buyer.clone()), bid, Execution::Force);
+
+		assert_last_event::<T>(
+			Event::BundleOrderExecuted {
+				bundle: Marketplace::<T>::bundle_id(&items),
+				items,
+				seller,
+				buyer,
+				price,
+				seller_fee: BalanceOf::<T>::from(0u8),
+				buyer_fee: BalanceOf::<T>::from(0u8),
+				royalty: T::MaxRoyalty::get()
+					* (price / BalanceOf::<T>::from(n))
+					* BalanceOf::<T>::from(n),
+				asset: None,
+			}
+			.into(),
+		);
+	}
+
+	// Benchmark `cancel_bundle_order` extrinsic with the worst possible conditions:
+	// Cancel an Ask, unlocking all the items of the bundle
+	#[benchmark]
+	fn cancel_bundle_order(n: Linear<1, { T::MaxBundleItems::get() }>) {
+		let (seller, items) = mint_bundle::<T>(n);
+		let (_, fee_signer) = admin_accounts_setup::<T>();
+
+		let price = BalanceOf::<T>::from(10000u16);
+		let ask = bundle_order::<T>(OrderType::Ask, items.clone(), price, fee_signer, vec![0]);
+		assert_ok!(Marketplace::<T>::create_bundle_order(
+			RawOrigin::Signed(seller.clone()).into(),
+			ask,
+			Execution::AllowCreation
+		));
+		let bundle = Marketplace::<T>::bundle_id(&items);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(seller.clone()), OrderType::Ask, bundle, price);
+
+		assert_last_event::<T>(Event::BundleOrderCanceled { bundle, who: seller }.into());
+	}
+
+	#[benchmark]
+	fn set_collection_royalty() {
+		let collection = T::BenchmarkHelper::collection(0);
+		let _ = mint_nft::<T>(T::BenchmarkHelper::item(1));
+		let admin = get_admin::<T>();
+		let creator: T::AccountId = account("creator", 0, SEED);
+		let royalty = Some(Royalty { recipient: creator, rate: T::MaxRoyalty::get() });
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), collection, royalty.clone());
+
+		assert_last_event::<T>(Event::RoyaltySet { collection, royalty }.into());
+	}
+
+	#[benchmark]
+	fn create_auction() {
+		let collection = T::BenchmarkHelper::collection(0);
+		let item = T::BenchmarkHelper::item(1);
+		let seller = mint_nft::<T>(item);
+		let (_, fee_signer) = admin_accounts_setup::<T>();
+
+		let kind = AuctionKind::English { min_increment: BalanceOf::<T>::from(100u16) };
+		let auction = auction_order::<T>(kind.clone(), fee_signer);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(seller.clone()), auction.clone());
+
+		assert_last_event::<T>(
+			Event::AuctionCreated {
+				collection,
+				item,
+				seller,
+				kind,
+				start_price: auction.start_price,
+				starts_at: auction.starts_at,
+				ends_at: auction.ends_at,
+				fee: auction.fee,
+				asset: None,
+			}
+			.into(),
+		);
+	}
+
+	// Benchmark `bid_on_auction` extrinsic with the worst possible conditions:
+	// Collection has a royalty
+	// Dutch auction is bought and settled
+	#[benchmark]
+	fn bid_on_auction() {
+		let collection = T::BenchmarkHelper::collection(0);
+		let item = T::BenchmarkHelper::item(1);
+		let seller = mint_nft::<T>(item);
+		let (_, fee_signer) = admin_accounts_setup::<T>();
+		set_royalty::<T>();
+
+		let kind = AuctionKind::Dutch { end_price: BalanceOf::<T>::from(1000u16) };
+		let auction = auction_order::<T>(kind, fee_signer);
+		assert_ok!(Marketplace::<T>::create_auction(
+			RawOrigin::Signed(seller.clone()).into(),
+			auction.clone()
+		));
+
+		let bidder: T::AccountId = funded_and_whitelisted_account::<T>("bidder", 0);
+		let amount = auction.start_price;
+		let fee = BalanceOf::<T>::from(0u8);
+		let signature_data = auction_bid_signature::<T>(amount, fee, vec![1], fee_signer);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(bidder.clone()), collection, item, amount, fee, signature_data);
+
+		assert_last_event::<T>(
+			Event::AuctionSettled {
+				collection,
+				item,
+				seller,
+				winner: Some(bidder),
+				price: Some(amount),
+			}
+			.into(),
+		);
+	}
+
+	// Benchmark `settle_auction` extrinsic with the worst possible conditions:
+	// Collection has a royalty
+	// English auction has a winning bid
+	#[benchmark]
+	fn settle_auction() {
+		let collection = T::BenchmarkHelper::collection(0);
+		let item = T::BenchmarkHelper::item(1);
+		let (seller, bidder, auction) = english_auction_with_bid::<T>();
+		Timestamp::<T>::set_timestamp(auction.ends_at);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(bidder.clone()), collection, item);
+
+		assert_last_event::<T>(
+			Event::AuctionSettled {
+				collection,
+				item,
+				seller,
+				winner: Some(bidder),
+				price: Some(auction.start_price),
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn prune_next_nonce() {
+		let _ = mint_nft::<T>(T::BenchmarkHelper::item(1));
+		let bidder: T::AccountId = funded_and_whitelisted_account::<T>("bidder", 0);
+		let (_, fee_signer_public) = admin_accounts_setup::<T>();
+		create_bids::<T>(bidder, 1, fee_signer_public);
+		Timestamp::<T>::set_timestamp(Marketplace::<T>::expiration_slot(nonce_deadline::<T>()));
+
+		#[block]
+		{
+			assert!(Marketplace::<T>::prune_next_nonce());
+		}
+
+		assert!(Nonces::<T>::iter_keys().next().is_none());
+	}
+
+	// Benchmark `expire_next_order` with the worst possible conditions:
+	// An ended English auction with a winning bid is settled
+	#[benchmark]
+	fn expire_next_order() {
+		let collection = T::BenchmarkHelper::collection(0);
+		let item = T::BenchmarkHelper::item(1);
+		let (seller, bidder, auction) = english_auction_with_bid::<T>();
+		Timestamp::<T>::set_timestamp(Marketplace::<T>::expiration_slot(auction.ends_at));
+
+		#[block]
+		{
+			assert!(Marketplace::<T>::expire_next_order());
+		}
+
+		assert!(Auctions::<T>::get(collection, item).is_none());
+		assert_last_event::<T>(
+			Event::AuctionSettled {
+				collection,
+				item,
+				seller,
+				winner: Some(bidder),
+				price: Some(auction.start_price),
+			}
+			.into(),
+		);
+	}
+
 	impl_benchmark_test_suite!(Marketplace, crate::mock::new_test_ext(), crate::mock::Test);
 }
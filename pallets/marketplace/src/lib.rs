@@ -18,6 +18,11 @@ mod benchmarking;
 mod types;
 pub use types::*;
 
+mod assets;
+pub use assets::*;
+
+pub mod migrations;
+
 pub mod weights;
 pub use weights::*;
 
@@ -27,13 +32,19 @@ pub use pallet::*;
 pub mod pallet {
 	use super::*;
 	use frame_support::{
-		ensure,
+		PalletId, ensure,
 		pallet_prelude::*,
+		storage::{self, TransactionOutcome},
 		traits::{
 			fungible::{Inspect, Mutate, MutateHold},
+			fungibles,
 			nonfungibles_v2::Transfer,
-			tokens::{Precision::Exact, Preservation::Preserve},
+			tokens::{
+				Precision::Exact,
+				Preservation::{self, Preserve},
+			},
 		},
+		weights::WeightMeter,
 	};
 	use frame_system::{ensure_signed, pallet_prelude::*};
 
@@ -41,19 +52,34 @@ pub mod pallet {
 
 	use pallet_nfts::ItemId;
 	use sp_runtime::{
-		BoundedVec, DispatchError, Saturating,
-		traits::{CheckedAdd, CheckedSub, IdentifyAccount, Verify},
+		BoundedVec, DispatchError, Perbill, Saturating,
+		traits::{
+			AccountIdConversion, CheckedAdd, CheckedMul, CheckedSub, IdentifyAccount,
+			SaturatedConversion, Verify, Zero,
+		},
 	};
-	use sp_std::vec::Vec;
+	use sp_std::{vec, vec::Vec};
 
 	type ExecOrderOf<T> = ExecOrder<
 		<T as frame_system::Config>::AccountId,
 		BalanceOf<T>,
 		<T as pallet_timestamp::Config>::Moment,
 		<T as frame_system::Config>::AccountId,
+		AssetIdOf<T>,
 	>;
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
+
+	/// The maximum number of Collection Bids at the price of an Ask that are looked up for a
+	/// match, bounding the weight of creating an Ask.
+	const MAX_COLLECTION_BID_CANDIDATES: usize = 16;
+
+	/// The identifier the escrow accounts of trades settled in escrow are derived from.
+	const ESCROW_PALLET_ID: PalletId = PalletId(*b"mkt/escr");
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -71,6 +97,14 @@ pub mod pallet {
 			+ Mutate<Self::AccountId>
 			+ MutateHold<Self::AccountId, Reason = <Self as pallet::Config>::RuntimeHoldReason>;
 
+		/// The fungible assets orders can be paid in, besides the native currency.
+		type Assets: fungibles::Inspect<Self::AccountId, Balance = BalanceOf<Self>>
+			+ fungibles::Mutate<Self::AccountId>
+			+ fungibles::MutateHold<
+				Self::AccountId,
+				Reason = <Self as pallet::Config>::RuntimeHoldReason,
+			>;
+
 		type Escrow: Escrow<Self::AccountId, BalanceOf<Self>, Self::AccountId>;
 
 		/// Overarching hold reason.
@@ -80,10 +114,48 @@ pub mod pallet {
 		#[pallet::constant]
 		type MinOrderDuration: Get<Self::Moment>;
 
+		/// The granularity of the order expiration index.
+		///
+		/// Orders are indexed by their expiration rounded up to a multiple of this value, so
+		/// the `on_idle` sweeper can walk the index in order.
+		#[pallet::constant]
+		type ExpirationGranularity: Get<Self::Moment>;
+
+		/// The maximum royalty rate that can be set for a collection.
+		#[pallet::constant]
+		type MaxRoyalty: Get<Perbill>;
+
+		/// The maximum number of open Asks, Bids, Collection Bids, bundle orders and auctions of
+		/// an account.
+		#[pallet::constant]
+		type MaxOrdersPerAccount: Get<u32>;
+
+		/// The maximum number of items of a bundle order.
+		#[pallet::constant]
+		type MaxBundleItems: Get<u32>;
+
+		/// The maximum number of accounts allowed to sign orders at the same time.
+		#[pallet::constant]
+		type MaxFeeSigners: Get<u32>;
+
+		/// The maximum number of recent sales kept for each collection.
+		#[pallet::constant]
+		type MaxRecentSales: Get<u32>;
+
+		/// The time after which the escrowed proceeds of a trade can be released to the seller
+		/// without the confirmation of the buyer.
+		#[pallet::constant]
+		type EscrowTimeout: Get<Self::Moment>;
+
 		/// Size of nonce StorageValue
 		#[pallet::constant]
 		type NonceStringLimit: Get<u32>;
 
+		/// The maximum time between the signature of the fee signer being submitted and its
+		/// deadline, bounding how long a used nonce is stored.
+		#[pallet::constant]
+		type MaxNonceLifetime: Get<Self::Moment>;
+
 		/// Off-Chain signature type.
 		///
 		/// Can verify whether a `Self::Signer` created a signature.
@@ -113,17 +185,36 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Authority<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
-	/// The account responsible for signing fee-related operations.
+	/// The accounts responsible for signing fee-related operations, with their restrictions.
 	///
-	/// This account is expected to provide valid signatures for operations
-	/// requiring authentication related to transaction fees.
+	/// An order is accepted if it is signed by any of the accounts active at the time it is
+	/// submitted, so the signing key can be rotated by overlapping the validity windows.
 	#[pallet::storage]
-	pub type FeeSigner<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+	pub type FeeSigners<T: Config> = StorageValue<_, FeeSignersOf<T>, ValueQuery>;
 
-	/// A mapping that stores the state of nonces used for preventing replay attacks.
+	/// A mapping that stores the used nonces, with their deadline, for preventing replay attacks.
+	///
+	/// Nonces are pruned by the `on_idle` hook once their deadline has passed, signatures being
+	/// rejected by time from then on.
 	#[pallet::storage]
 	pub type Nonces<T: Config> =
-		StorageMap<_, Identity, BoundedVec<u8, T::NonceStringLimit>, bool, ValueQuery>;
+		StorageMap<_, Identity, BoundedVec<u8, T::NonceStringLimit>, T::Moment, OptionQuery>;
+
+	/// An index of the used nonces by the expiration slot of their deadline.
+	#[pallet::storage]
+	pub type NonceExpirations<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::Moment,
+		Identity,
+		BoundedVec<u8, T::NonceStringLimit>,
+		(),
+		OptionQuery,
+	>;
+
+	/// The next nonce expiration slot to be pruned by the `on_idle` hook.
+	#[pallet::storage]
+	pub type NextNonceExpirationSlot<T: Config> = StorageValue<_, T::Moment, OptionQuery>;
 
 	/// The account where marketplace payouts will be sent.
 	#[pallet::storage]
@@ -131,14 +222,8 @@ pub mod pallet {
 
 	/// A mapping of active Ask orders in the marketplace.
 	#[pallet::storage]
-	pub type Asks<T: Config> = StorageDoubleMap<
-		_,
-		Blake2_128Concat,
-		T::CollectionId,
-		Blake2_128Concat,
-		ItemId,
-		Ask<T::AccountId, BalanceOf<T>, T::Moment, T::AccountId>,
-	>;
+	pub type Asks<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::CollectionId, Blake2_128Concat, ItemId, AskOf<T>>;
 
 	/// A mapping that stores active Bid orders in the marketplace.
 	#[pallet::storage]
@@ -149,9 +234,128 @@ pub mod pallet {
 			NMapKey<Blake2_128Concat, ItemId>,
 			NMapKey<Blake2_128Concat, BalanceOf<T>>,
 		),
-		Bid<T::AccountId, BalanceOf<T>, T::Moment>,
+		BidOf<T>,
+	>;
+
+	/// A mapping that stores active collection-wide Bid orders in the marketplace, by price and
+	/// buyer.
+	#[pallet::storage]
+	pub type CollectionBids<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Blake2_128Concat, BalanceOf<T>>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		CollectionBidOf<T>,
+	>;
+
+	/// The royalty paid to the creator of a collection on every sale of its items.
+	#[pallet::storage]
+	pub type Royalties<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, Royalty<T::AccountId>, OptionQuery>;
+
+	/// The bounds of the order fees of the collections without a fee policy of their own.
+	#[pallet::storage]
+	pub type DefaultFeePolicy<T: Config> = StorageValue<_, FeePolicy, OptionQuery>;
+
+	/// The bounds of the order fees of a collection, overriding the default fee policy.
+	#[pallet::storage]
+	pub type CollectionFeePolicies<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, FeePolicy, OptionQuery>;
+
+	/// Whether the creation and execution of all orders and auctions is paused.
+	#[pallet::storage]
+	pub type Paused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// The collections whose orders and auctions can't be created nor executed.
+	#[pallet::storage]
+	pub type HaltedCollections<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, (), OptionQuery>;
+
+	/// The running auctions, by item.
+	#[pallet::storage]
+	pub type Auctions<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		ItemId,
+		AuctionOf<T>,
+		OptionQuery,
 	>;
 
+	/// A mapping of active Ask orders on bundles of items.
+	#[pallet::storage]
+	pub type BundleAsks<T: Config> = StorageMap<_, Identity, BundleId, BundleAskOf<T>>;
+
+	/// A mapping that stores active Bid orders on bundles of items.
+	#[pallet::storage]
+	pub type BundleBids<T: Config> =
+		StorageDoubleMap<_, Identity, BundleId, Blake2_128Concat, BalanceOf<T>, BidOf<T>>;
+
+	/// An index of the stored orders by their expiration slot.
+	///
+	/// The expiration slot of an order is its expiration rounded up to a multiple of
+	/// `ExpirationGranularity`.
+	#[pallet::storage]
+	pub type OrderExpirations<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::Moment,
+		Blake2_128Concat,
+		OrderKeyOf<T>,
+		(),
+		OptionQuery,
+	>;
+
+	/// The next expiration slot to be swept by the `on_idle` hook.
+	#[pallet::storage]
+	pub type NextExpirationSlot<T: Config> = StorageValue<_, T::Moment, OptionQuery>;
+
+	/// An index of the open Asks, Bids, Collection Bids, bundle orders and auctions of every
+	/// account.
+	#[pallet::storage]
+	pub type AccountOrders<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<OrderKeyOf<T>, T::MaxOrdersPerAccount>,
+		ValueQuery,
+	>;
+
+	/// The trading statistics of every collection, for each asset it was traded in.
+	#[pallet::storage]
+	pub type TradeStats<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		Option<AssetIdOf<T>>,
+		CollectionStatsOf<T>,
+		OptionQuery,
+	>;
+
+	/// The most recent sales of every collection, from the oldest to the newest.
+	///
+	/// Only the last `MaxRecentSales` sales are kept.
+	#[pallet::storage]
+	pub type RecentSales<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		BoundedVec<SaleOf<T>, T::MaxRecentSales>,
+		ValueQuery,
+	>;
+
+	/// The trades whose seller proceeds are held in escrow, by escrow id.
+	#[pallet::storage]
+	pub type Escrows<T: Config> = StorageMap<_, Twox64Concat, EscrowId, TradeEscrowOf<T>>;
+
+	/// The id of the next trade settled in escrow.
+	#[pallet::storage]
+	pub type NextEscrowId<T: Config> = StorageValue<_, EscrowId, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -159,6 +363,17 @@ pub mod pallet {
 		AuthorityUpdated { authority: T::AccountId },
 		/// The fee signer account was updated.
 		FeeSignerAddressUpdate { fee_signer: T::AccountId },
+		/// A fee signer was added, or its restrictions were updated.
+		FeeSignerAdded { fee_signer: T::AccountId, info: FeeSignerInfoOf<T> },
+		/// A fee signer was removed.
+		FeeSignerRemoved { fee_signer: T::AccountId },
+		/// The fee policy of a collection, or the default one if `collection` is `None`, was set
+		/// or removed.
+		FeePolicySet { collection: Option<T::CollectionId>, policy: Option<FeePolicy> },
+		/// Trading on the marketplace was paused or resumed.
+		TradingPauseSet { paused: bool },
+		/// Trading on a collection was halted or resumed.
+		CollectionHaltSet { collection: T::CollectionId, halted: bool },
 		/// The payout address account was updated.
 		PayoutAddressUpdated { payout_address: T::AccountId },
 		/// An Ask/Bid order was created.
@@ -170,6 +385,9 @@ pub mod pallet {
 			price: BalanceOf<T>,
 			expires_at: T::Moment,
 			fee: BalanceOf<T>,
+			asset: Option<AssetIdOf<T>>,
+			/// The fee signer that approved the order.
+			fee_signer: T::AccountId,
 		},
 		/// A trade of Ask and Bid was executed.
 		OrderExecuted {
@@ -180,9 +398,120 @@ pub mod pallet {
 			price: BalanceOf<T>,
 			seller_fee: BalanceOf<T>,
 			buyer_fee: BalanceOf<T>,
+			/// The account that received the collection royalty, if any.
+			royalty_recipient: Option<T::AccountId>,
+			/// The royalty deducted from the seller proceeds.
+			royalty: BalanceOf<T>,
+			/// The asset the trade was paid in, the native currency if `None`.
+			asset: Option<AssetIdOf<T>>,
+			/// The escrow holding the seller proceeds, if the trade was settled in escrow with
+			/// `EscrowSettlement::Marketplace`.
+			escrow_id: Option<EscrowId>,
 		},
 		/// The order was canceled by the order creator or the pallet's authority.
 		OrderCanceled { collection: T::CollectionId, item: ItemId, who: T::AccountId },
+		/// The order reached its expiration time and was removed.
+		OrderExpired { order: OrderKeyOf<T>, who: T::AccountId },
+		/// The open orders of an account were canceled, `count` being the number of canceled
+		/// orders.
+		AllOrdersCanceled { who: T::AccountId, count: u32 },
+		/// An auction was created.
+		AuctionCreated {
+			collection: T::CollectionId,
+			item: ItemId,
+			seller: T::AccountId,
+			kind: AuctionKind<BalanceOf<T>>,
+			start_price: BalanceOf<T>,
+			starts_at: T::Moment,
+			ends_at: T::Moment,
+			fee: BalanceOf<T>,
+			asset: Option<AssetIdOf<T>>,
+		},
+		/// A new highest bid was placed on an English auction.
+		AuctionBidPlaced {
+			collection: T::CollectionId,
+			item: ItemId,
+			bidder: T::AccountId,
+			amount: BalanceOf<T>,
+			fee: BalanceOf<T>,
+		},
+		/// An auction was settled, the item was sold to the winner if there is one.
+		AuctionSettled {
+			collection: T::CollectionId,
+			item: ItemId,
+			seller: T::AccountId,
+			winner: Option<T::AccountId>,
+			price: Option<BalanceOf<T>>,
+		},
+		/// The royalty of a collection was set or removed.
+		RoyaltySet { collection: T::CollectionId, royalty: Option<Royalty<T::AccountId>> },
+		/// An Ask/Bid order on a bundle of items was created.
+		BundleOrderCreated {
+			who: T::AccountId,
+			order_type: OrderType,
+			bundle: BundleId,
+			items: BundleItemsOf<T>,
+			price: BalanceOf<T>,
+			expires_at: T::Moment,
+			fee: BalanceOf<T>,
+			asset: Option<AssetIdOf<T>>,
+			/// The fee signer that approved the order.
+			fee_signer: T::AccountId,
+		},
+		/// A trade of bundle Ask and Bid was executed, all the items were transferred.
+		BundleOrderExecuted {
+			bundle: BundleId,
+			items: BundleItemsOf<T>,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			price: BalanceOf<T>,
+			seller_fee: BalanceOf<T>,
+			buyer_fee: BalanceOf<T>,
+			/// The total of the royalties deducted from the seller proceeds.
+			royalty: BalanceOf<T>,
+			/// The asset the trade was paid in, the native currency if `None`.
+			asset: Option<AssetIdOf<T>>,
+		},
+		/// The bundle order was canceled by the order creator or the pallet's authority.
+		BundleOrderCanceled { bundle: BundleId, who: T::AccountId },
+		/// The price, expiration and fee of an Ask/Bid order were updated.
+		OrderUpdated {
+			who: T::AccountId,
+			order_type: OrderType,
+			collection: T::CollectionId,
+			item: ItemId,
+			price: BalanceOf<T>,
+			expires_at: T::Moment,
+			fee: BalanceOf<T>,
+			/// The fee signer that approved the update.
+			fee_signer: T::AccountId,
+		},
+		/// The escrowed proceeds of a trade were released to the seller.
+		EscrowReleased { escrow_id: EscrowId, seller: T::AccountId, amount: BalanceOf<T> },
+		/// The buyer disputed an escrowed trade.
+		EscrowDisputed { escrow_id: EscrowId },
+		/// The escrow agent refunded the escrowed proceeds of a disputed trade to the buyer.
+		EscrowRefunded { escrow_id: EscrowId, buyer: T::AccountId, amount: BalanceOf<T> },
+		/// A Collection Bid was created.
+		CollectionBidCreated {
+			who: T::AccountId,
+			collection: T::CollectionId,
+			price: BalanceOf<T>,
+			/// The number of items the bid was created for.
+			quantity: u32,
+			expires_at: T::Moment,
+			fee: BalanceOf<T>,
+			asset: Option<AssetIdOf<T>>,
+			/// The fee signer that approved the order.
+			fee_signer: T::AccountId,
+		},
+		/// The Collection Bid was canceled by its buyer or the pallet's authority.
+		CollectionBidCanceled {
+			collection: T::CollectionId,
+			price: BalanceOf<T>,
+			buyer: T::AccountId,
+			who: T::AccountId,
+		},
 	}
 
 	#[pallet::error]
@@ -201,7 +530,7 @@ pub mod pallet {
 		InvalidPrice,
 		/// Expiration time provided is too low.
 		InvalidExpiration,
-		/// Fee percent provided is too low.
+		/// The fee provided is outside of the bounds of the fee policy.
 		InvalidFeePercent,
 		/// Ask or Bid with the same characteristics already exists.
 		OrderAlreadyExists,
@@ -231,6 +560,104 @@ pub mod pallet {
 		BadNonce,
 		/// An overflow happened.
 		Overflow,
+		/// The collection was not found.
+		CollectionNotFound,
+		/// The quantity of a collection bid must be greater than zero.
+		InvalidQuantity,
+		/// The caller is not the collection owner or the admin account of the pallet.
+		NotCollectionOwnerOrAdmin,
+		/// The royalty rate is above `MaxRoyalty`.
+		RoyaltyTooHigh,
+		/// The auction was not found.
+		AuctionNotFound,
+		/// The auction has not started yet.
+		AuctionNotStarted,
+		/// The auction has already ended.
+		AuctionEnded,
+		/// The auction has not ended yet.
+		AuctionNotEnded,
+		/// The bid is below the minimum amount accepted by the auction.
+		BidTooLow,
+		/// The account reached the maximum number of open orders.
+		TooManyOrders,
+		/// The asset of the order doesn't exist.
+		UnknownAsset,
+		/// Escrow agents can only be used for orders paid in the native currency.
+		EscrowRequiresNativeCurrency,
+		/// The bundle order is not an Ask or Bid, is empty or contains the same item twice.
+		InvalidBundle,
+		/// Only Asks and Bids can be updated or canceled with `cancel_order`.
+		InvalidOrderType,
+		/// The deadline of the nonce has passed.
+		NonceExpired,
+		/// The caller is not the order creator.
+		NotOrderCreator,
+		/// The maximum number of fee signers has been reached.
+		TooManyFeeSigners,
+		/// The account is not a fee signer.
+		FeeSignerNotFound,
+		/// The signatures of the fee signer are not accepted at this time.
+		FeeSignerNotActive,
+		/// The fee is above the maximum fee the fee signer can approve.
+		FeeAboveSignerLimit,
+		/// The minimum fee of the policy is above its maximum fee, or the maximum fee is above
+		/// the order price.
+		InvalidFeePolicy,
+		/// Trading on the marketplace is paused.
+		TradingPaused,
+		/// Trading on the collection is halted.
+		CollectionHalted,
+		/// Only Asks can be restricted to a buyer.
+		BuyerOnlyForAsks,
+		/// The Ask is restricted to another buyer.
+		NotListingBuyer,
+		/// No trade is held in escrow with the given id.
+		EscrowNotFound,
+		/// Only the buyer of the trade can perform this action.
+		NotEscrowBuyer,
+		/// Only the escrow agent of the trade can perform this action.
+		NotEscrowAgent,
+		/// The escrow timeout of the trade has not passed yet.
+		EscrowTimeoutNotPassed,
+		/// The escrow timeout of the trade has already passed.
+		EscrowTimeoutPassed,
+		/// The trade is disputed, only its escrow agent can release the proceeds.
+		EscrowDisputed,
+		/// The trade is not disputed.
+		EscrowNotDisputed,
+		/// The deadline of the nonce is further than `MaxNonceLifetime` from now.
+		NonceDeadlineTooFar,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+
+			while meter.can_consume(<T as Config>::WeightInfo::expire_next_order()) {
+				if !Self::expire_next_order() {
+					break;
+				}
+				meter.consume(<T as Config>::WeightInfo::expire_next_order());
+			}
+
+			while meter.can_consume(<T as Config>::WeightInfo::prune_next_nonce()) {
+				if !Self::prune_next_nonce() {
+					break;
+				}
+				meter.consume(<T as Config>::WeightInfo::prune_next_nonce());
+			}
+
+			meter.consumed()
+		}
+
+		fn integrity_test() {
+			assert!(
+				!T::ExpirationGranularity::get().is_zero(),
+				"ExpirationGranularity must be greater than zero."
+			);
+			assert!(T::MaxFeeSigners::get() > 0, "MaxFeeSigners must be greater than zero.");
+		}
 	}
 
 	#[pallet::call]
@@ -267,6 +694,9 @@ pub mod pallet {
 		///
 		/// Only an account with the authority role can execute this function.
 		///
+		/// The fee signer replaces all the current fee signers, without validity window nor fee
+		/// limit. Use `add_fee_signer` to rotate the signing key without downtime.
+		///
 		/// Parameters:
 		/// - `fee_signer`: The account ID of the fee signer to be set.
 		///
@@ -282,12 +712,11 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 			Self::ensure_authority(&who)?;
 
-			ensure!(
-				FeeSigner::<T>::get().as_ref() != Some(&fee_signer),
-				Error::<T>::AccountAlreadySet
-			);
+			let fee_signers: FeeSignersOf<T> =
+				BoundedVec::truncate_from(vec![(fee_signer.clone(), Default::default())]);
+			ensure!(FeeSigners::<T>::get() != fee_signers, Error::<T>::AccountAlreadySet);
 
-			FeeSigner::<T>::put(fee_signer.clone());
+			FeeSigners::<T>::put(fee_signers);
 			Self::deposit_event(Event::FeeSignerAddressUpdate { fee_signer });
 			Ok(())
 		}
@@ -334,11 +763,23 @@ pub mod pallet {
 		/// - The signature must come from the feeSigner account.
 		/// - The amount the bidder is willing to pay is locked from the user's Balance.
 		///
+		/// Collection Bids:
+		/// - A bid on any item of an existing collection, for up to `quantity` items.
+		/// - The signature must come from the feeSigner account and also covers the quantity.
+		/// - `price * quantity + fee` is locked from the user's Balance.
+		/// - Each Ask on an item of the collection with the same price fills the bid once, the
+		///   remaining fee being spread over the remaining fills.
+		/// - A matching Ask on the order item fills the bid once on creation. With `Force` only
+		///   that Ask is bought and the rest of the bid is not stored.
+		/// - An account can place one Collection Bid per collection and price.
+		///
 		/// Match Exists:
 		/// - If a match between an Ask and Bid exists, the trade is triggered.
 		/// - The seller receives the funds, and the bidder receives the unlocked item.
 		/// - Fees go to payoutAddress.
 		///
+		/// Orders that are stored are removed by the `on_idle` hook once they expire.
+		///
 		/// Parameters:
 		/// - `order`: The details of the order to be created (including type, collection, item, price, expiration, fee, and signature).
 		/// - `execution`: Execution mode to indicate whether order creation should proceed if a valid match exists.
@@ -354,9 +795,7 @@ pub mod pallet {
 			execution: Execution,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-
-			let item_owner = pallet_nfts::Pallet::<T>::owner(order.collection, order.item)
-				.ok_or(Error::<T>::ItemNotFound)?;
+			Self::ensure_trading_allowed(&order.collection)?;
 
 			ensure!(
 				order.expires_at
@@ -365,21 +804,63 @@ pub mod pallet {
 				Error::<T>::InvalidExpiration
 			);
 
-			let message: OrderMessageOf<T> = order.clone().into();
-			Self::verify_fee_signer_signature(&message.encode(), order.signature_data)?;
+			if let Some(ref asset) = order.asset {
+				ensure!(T::Assets::asset_exists(asset.clone()), Error::<T>::UnknownAsset);
+				ensure!(order.escrow_agent.is_none(), Error::<T>::EscrowRequiresNativeCurrency);
+			}
 
-			Self::deposit_event(Event::OrderCreated {
-				who: who.clone(),
-				order_type: order.order_type.clone(),
-				collection: order.collection,
-				item: order.item,
-				price: order.price,
-				expires_at: order.expires_at,
-				fee: order.fee,
-			});
+			let total_price = match order.order_type {
+				OrderType::CollectionBid { quantity } => order
+					.price
+					.checked_mul(&BalanceOf::<T>::from(quantity))
+					.ok_or(Error::<T>::Overflow)?,
+				_ => order.price,
+			};
+			Self::ensure_fee_within_policy(Some(&order.collection), &order.fee, &total_price)?;
+			if let Some(ref buyer) = order.buyer {
+				ensure!(order.order_type == OrderType::Ask, Error::<T>::BuyerOnlyForAsks);
+				ensure!(*buyer != who, Error::<T>::BuyerIsSeller);
+			}
+
+			let message = Self::signing_payload(&order);
+			let fee_signer = Self::verify_fee_signer_signature(
+				&message,
+				order.signature_data.clone(),
+				order.fee,
+				total_price,
+			)?;
+
+			match order.order_type {
+				OrderType::CollectionBid { quantity } => {
+					Self::deposit_event(Event::CollectionBidCreated {
+						who: who.clone(),
+						collection: order.collection,
+						price: order.price,
+						quantity,
+						expires_at: order.expires_at,
+						fee: order.fee,
+						asset: order.asset.clone(),
+						fee_signer,
+					})
+				},
+				_ => Self::deposit_event(Event::OrderCreated {
+					who: who.clone(),
+					order_type: order.order_type.clone(),
+					collection: order.collection,
+					item: order.item,
+					price: order.price,
+					expires_at: order.expires_at,
+					fee: order.fee,
+					asset: order.asset.clone(),
+					fee_signer,
+				}),
+			}
 
 			match order.order_type {
 				OrderType::Ask => {
+					let item_owner = pallet_nfts::Pallet::<T>::owner(order.collection, order.item)
+						.ok_or(Error::<T>::ItemNotFound)?;
+
 					ensure!(
 						!Asks::<T>::contains_key(order.collection, order.item),
 						Error::<T>::OrderAlreadyExists
@@ -394,6 +875,8 @@ pub mod pallet {
 						&order.collection,
 						&order.item,
 						&order.price,
+						&order.asset,
+						order.buyer.as_ref(),
 					) {
 						Self::execute_order(
 							exec_order,
@@ -403,6 +886,7 @@ pub mod pallet {
 							&order.price,
 							&order.fee,
 							order.escrow_agent,
+							order.escrow_settlement,
 						)?;
 					} else {
 						ensure!(
@@ -411,18 +895,29 @@ pub mod pallet {
 						);
 
 						let ask = Ask {
-							seller: who,
+							seller: who.clone(),
 							price: order.price,
 							expiration: order.expires_at,
 							fee: order.fee,
 							escrow_agent: order.escrow_agent,
+							escrow_settlement: order.escrow_settlement,
+							asset: order.asset,
+							buyer: order.buyer,
 						};
 
 						Asks::<T>::insert(order.collection, order.item, ask);
+						Self::index_order(
+							&who,
+							order.expires_at,
+							OrderKey::Ask { collection: order.collection, item: order.item },
+						)?;
 					}
 				},
 
 				OrderType::Bid => {
+					let item_owner = pallet_nfts::Pallet::<T>::owner(order.collection, order.item)
+						.ok_or(Error::<T>::ItemNotFound)?;
+
 					ensure!(
 						!Bids::<T>::contains_key((order.collection, order.item, order.price)),
 						Error::<T>::OrderAlreadyExists
@@ -431,18 +926,16 @@ pub mod pallet {
 
 					// Reserve necessary amount to pay for the item + fees
 					let bid_payment = Self::calc_bid_payment(&order.price, &order.fee)?;
-					<T as crate::Config>::Currency::hold(
-						&HoldReason::MarketplaceBid.into(),
-						&who,
-						bid_payment,
-					)
-					.map_err(|_| Error::<T>::InsufficientFunds)?;
+					Self::hold_funds(&order.asset, &who, bid_payment)
+						.map_err(|_| Error::<T>::InsufficientFunds)?;
 
 					if let Some(exec_order) = Self::valid_match_exists_for(
 						OrderType::Bid,
 						&order.collection,
 						&order.item,
 						&order.price,
+						&order.asset,
+						Some(&who),
 					) {
 						Self::execute_order(
 							exec_order,
@@ -452,6 +945,7 @@ pub mod pallet {
 							&order.price,
 							&order.fee,
 							order.escrow_agent,
+							order.escrow_settlement,
 						)?;
 					} else {
 						ensure!(
@@ -459,9 +953,102 @@ pub mod pallet {
 							Error::<T>::ValidMatchMustExist
 						);
 
-						let bid = Bid { buyer: who, expiration: order.expires_at, fee: order.fee };
+						let bid = Bid {
+							buyer: who.clone(),
+							expiration: order.expires_at,
+							fee: order.fee,
+							asset: order.asset,
+						};
 
 						Bids::<T>::insert((order.collection, order.item, order.price), bid);
+						Self::index_order(
+							&who,
+							order.expires_at,
+							OrderKey::Bid {
+								collection: order.collection,
+								item: order.item,
+								price: order.price,
+							},
+						)?;
+					}
+				},
+
+				OrderType::CollectionBid { quantity } => {
+					ensure!(quantity > 0, Error::<T>::InvalidQuantity);
+					ensure!(
+						pallet_nfts::Pallet::<T>::collection_owner(order.collection).is_some(),
+						Error::<T>::CollectionNotFound
+					);
+					ensure!(
+						!CollectionBids::<T>::contains_key((order.collection, order.price, &who)),
+						Error::<T>::OrderAlreadyExists
+					);
+
+					// Reserve necessary amount to pay for all the items + fees
+					let bid_payment =
+						Self::calc_collection_bid_payment(&order.price, quantity, &order.fee)?;
+					Self::hold_funds(&order.asset, &who, bid_payment)
+						.map_err(|_| Error::<T>::InsufficientFunds)?;
+
+					let mut bid = CollectionBid {
+						buyer: who.clone(),
+						expiration: order.expires_at,
+						fee: order.fee,
+						quantity,
+						asset: order.asset,
+					};
+
+					if let Some(exec_order) = Self::valid_match_exists_for(
+						order.order_type.clone(),
+						&order.collection,
+						&order.item,
+						&order.price,
+						&bid.asset,
+						Some(&who),
+					) {
+						// The Ask on the order item fills the bid once
+						let fill_fee = bid.fee / BalanceOf::<T>::from(bid.quantity);
+						bid.fee = bid.fee.saturating_sub(fill_fee);
+						bid.quantity = bid.quantity.saturating_sub(1);
+
+						Self::execute_order(
+							exec_order,
+							who.clone(),
+							order.collection,
+							order.item,
+							&order.price,
+							&fill_fee,
+							order.escrow_agent,
+							order.escrow_settlement,
+						)?;
+					} else {
+						ensure!(
+							execution == Execution::AllowCreation,
+							Error::<T>::ValidMatchMustExist
+						);
+					}
+
+					if bid.quantity > 0 {
+						if execution == Execution::AllowCreation {
+							CollectionBids::<T>::insert((order.collection, order.price, &who), bid);
+							Self::index_order(
+								&who,
+								order.expires_at,
+								OrderKey::CollectionBid {
+									collection: order.collection,
+									price: order.price,
+									buyer: who.clone(),
+								},
+							)?;
+						} else {
+							// Only the matching Ask is bought with `Force`
+							let remaining = Self::calc_collection_bid_payment(
+								&order.price,
+								bid.quantity,
+								&bid.fee,
+							)?;
+							Self::release_funds(&bid.asset, &who, remaining)?;
+						}
 					}
 				},
 			};
@@ -476,6 +1063,8 @@ pub mod pallet {
 		/// If the order is an Ask, the item is unlocked.
 		/// If the order is a Bid, the bidder's balance is unlocked.
 		///
+		/// Collection Bids are canceled with `cancel_collection_bid`.
+		///
 		/// Parameters:
 		/// - `order_type`: The type of the order to be canceled (Ask or Bid).
 		/// - `collection`: The collection ID of the NFT associated with the order.
@@ -497,46 +1086,1091 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 			let authority = Authority::<T>::get();
 
-			match order_type {
-				// Order type Ask
-				OrderType::Ask => {
-					let ask = Asks::<T>::get(collection, item).ok_or(Error::<T>::OrderNotFound)?;
-					ensure!(
-						ask.seller.clone() == who.clone() || Some(who.clone()) == authority,
-						Error::<T>::NotOrderCreatorOrAdmin
-					);
+			let order = match order_type {
+				OrderType::Ask => OrderKey::Ask { collection, item },
+				OrderType::Bid => OrderKey::Bid { collection, item, price },
+				OrderType::CollectionBid { .. } => return Err(Error::<T>::InvalidOrderType.into()),
+			};
+			let (creator, _) = Self::order_info(&order).ok_or(Error::<T>::OrderNotFound)?;
+			ensure!(
+				creator == who || Some(who.clone()) == authority,
+				Error::<T>::NotOrderCreatorOrAdmin
+			);
 
-					Asks::<T>::remove(collection, item);
+			Self::remove_order(&order)?;
 
-					// Re-enable item transfer
-					pallet_nfts::Pallet::<T>::enable_transfer(&collection, &item)?;
-				},
-				// Order type Bid
-				OrderType::Bid => {
-					let bid = Bids::<T>::get((collection, item, price))
-						.ok_or(Error::<T>::OrderNotFound)?;
+			Self::deposit_event(Event::OrderCanceled { collection, item, who });
 
-					ensure!(
-						bid.buyer.clone() == who.clone() || Some(who.clone()) == authority,
-						Error::<T>::NotOrderCreatorOrAdmin
-					);
+			Ok(())
+		}
 
-					Bids::<T>::remove((collection, item, price));
+		/// Sets or removes the royalty paid to the creator of a collection on every sale.
+		///
+		/// Callable by either the authority or the collection owner.
+		///
+		/// The royalty is deducted from the seller proceeds of every executed order on an item of
+		/// the collection and paid directly to the royalty recipient.
+		///
+		/// Parameters:
+		/// - `collection`: The collection ID the royalty applies to.
+		/// - `royalty`: The royalty recipient and rate, `None` removes the royalty. The rate can't
+		///   be above `MaxRoyalty`.
+		///
+		/// Emits `RoyaltySet` event upon successful execution.
+		///
+		/// Weight: `WeightInfo::set_collection_royalty` (defined in the `Config` trait).
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_collection_royalty())]
+		pub fn set_collection_royalty(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			royalty: Option<Royalty<T::AccountId>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-					let bid_payment = Self::calc_bid_payment(&price, &bid.fee)?;
-					<T as crate::Config>::Currency::release(
-						&HoldReason::MarketplaceBid.into(),
-						&bid.buyer,
-						bid_payment,
-						Exact,
-					)?;
+			let collection_owner = pallet_nfts::Pallet::<T>::collection_owner(collection)
+				.ok_or(Error::<T>::CollectionNotFound)?;
+			ensure!(
+				collection_owner == who || Self::ensure_authority(&who).is_ok(),
+				Error::<T>::NotCollectionOwnerOrAdmin
+			);
+
+			match royalty {
+				Some(ref royalty) => {
+					ensure!(royalty.rate <= T::MaxRoyalty::get(), Error::<T>::RoyaltyTooHigh);
+					Royalties::<T>::insert(collection, royalty);
 				},
+				None => Royalties::<T>::remove(collection),
 			}
 
-			Self::deposit_event(Event::OrderCanceled { collection, item, who });
+			Self::deposit_event(Event::RoyaltySet { collection, royalty });
+			Ok(())
+		}
+
+		/// Create an English or Dutch auction for a specific NFT (collection ID, Item ID).
+		///
+		/// - Only the owner of the NFT can create an auction for it.
+		/// - The signature must come from the feeSigner account.
+		/// - The auction must end after `MinOrderDuration`.
+		/// - After the auction is created, the NFT is locked so it can't be transferred.
+		///
+		/// English auctions:
+		/// - Bids start at `start_price` and must then exceed the highest bid by `min_increment`.
+		/// - The highest bidder wins the item when the auction is settled after its end, which
+		///   happens automatically in the `on_idle` hook or through `settle_auction`.
+		///
+		/// Dutch auctions:
+		/// - The price decays linearly from `start_price` at `starts_at` to `end_price` at
+		///   `ends_at`.
+		/// - The first bidder buys the item at the current price.
+		///
+		/// Parameters:
+		/// - `auction`: The details of the auction to be created (including collection, item, kind,
+		///   start price, start and end times, fee, and signature).
+		///
+		/// Emits `AuctionCreated` event upon successful execution.
+		///
+		/// Weight: `WeightInfo::create_auction` (defined in the `Config` trait).
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_auction())]
+		pub fn create_auction(origin: OriginFor<T>, auction: AuctionOrderOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_trading_allowed(&auction.collection)?;
+
+			ensure!(
+				auction.ends_at > auction.starts_at
+					&& auction.ends_at
+						> pallet_timestamp::Pallet::<T>::get()
+							.saturating_add(T::MinOrderDuration::get()),
+				Error::<T>::InvalidExpiration
+			);
+			match auction.kind {
+				AuctionKind::English { min_increment } => {
+					ensure!(!min_increment.is_zero(), Error::<T>::InvalidPrice)
+				},
+				AuctionKind::Dutch { end_price } => {
+					ensure!(end_price <= auction.start_price, Error::<T>::InvalidPrice)
+				},
+			}
+
+			if let Some(ref asset) = auction.asset {
+				ensure!(T::Assets::asset_exists(asset.clone()), Error::<T>::UnknownAsset);
+			}
+
+			let item_owner = pallet_nfts::Pallet::<T>::owner(auction.collection, auction.item)
+				.ok_or(Error::<T>::ItemNotFound)?;
+			ensure!(item_owner == who, Error::<T>::NotItemOwner);
+			ensure!(
+				!Auctions::<T>::contains_key(auction.collection, auction.item),
+				Error::<T>::OrderAlreadyExists
+			);
+
+			Self::ensure_fee_within_policy(
+				Some(&auction.collection),
+				&auction.fee,
+				&auction.start_price,
+			)?;
+
+			let message: AuctionMessageOf<T> = auction.clone().into();
+			Self::verify_fee_signer_signature(
+				&message.encode(),
+				auction.signature_data,
+				auction.fee,
+				auction.start_price,
+			)?;
+
+			// Check if item is locked
+			pallet_nfts::Pallet::<T>::disable_transfer(&auction.collection, &auction.item)
+				.map_err(|_| Error::<T>::ItemAlreadyLocked)?;
+
+			Auctions::<T>::insert(
+				auction.collection,
+				auction.item,
+				Auction {
+					seller: who.clone(),
+					kind: auction.kind.clone(),
+					start_price: auction.start_price,
+					starts_at: auction.starts_at,
+					ends_at: auction.ends_at,
+					fee: auction.fee,
+					highest_bid: None,
+					asset: auction.asset.clone(),
+				},
+			);
+			Self::index_order(
+				&who,
+				auction.ends_at,
+				OrderKey::Auction { collection: auction.collection, item: auction.item },
+			)?;
+
+			Self::deposit_event(Event::AuctionCreated {
+				collection: auction.collection,
+				item: auction.item,
+				seller: who,
+				kind: auction.kind,
+				start_price: auction.start_price,
+				starts_at: auction.starts_at,
+				ends_at: auction.ends_at,
+				fee: auction.fee,
+				asset: auction.asset,
+			});
+			Ok(())
+		}
+
+		/// Bid on a running auction.
+		///
+		/// - The signature must come from the feeSigner account and cover the amount and fee.
+		/// - The amount plus the fee is locked from the bidder's Balance.
+		///
+		/// English auctions:
+		/// - The amount must be at least the start price, or exceed the highest bid by the
+		///   minimum increment.
+		/// - The funds of the previous highest bidder are unlocked.
+		///
+		/// Dutch auctions:
+		/// - The amount is the maximum price the bidder is willing to pay, it must be at least the
+		///   current price.
+		/// - The item is sold to the bidder at the current price.
+		///
+		/// Parameters:
+		/// - `collection`: The collection ID of the auctioned NFT.
+		/// - `item`: The item ID of the auctioned NFT.
+		/// - `amount`: The amount of the bid.
+		/// - `fee`: The bidder fee, paid on top of the amount if the bid wins.
+		/// - `signature_data`: The signature of the fee signer and the nonce.
+		///
+		/// Emits `AuctionBidPlaced` for English auctions, `OrderExecuted` and `AuctionSettled`
+		/// for Dutch auctions.
+		///
+		/// Weight: `WeightInfo::bid_on_auction` (defined in the `Config` trait).
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::bid_on_auction())]
+		pub fn bid_on_auction(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: ItemId,
+			amount: BalanceOf<T>,
+			fee: BalanceOf<T>,
+			signature_data: SignatureData<T::Signature, Vec<u8>, T::Moment>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_trading_allowed(&collection)?;
+
+			let mut auction =
+				Auctions::<T>::get(collection, item).ok_or(Error::<T>::AuctionNotFound)?;
+			let now = pallet_timestamp::Pallet::<T>::get();
+			ensure!(now >= auction.starts_at, Error::<T>::AuctionNotStarted);
+			ensure!(now < auction.ends_at, Error::<T>::AuctionEnded);
+			ensure!(who != auction.seller, Error::<T>::BuyerIsSeller);
+
+			// A Dutch auction is bought at its current price, the fee applies to that price.
+			let price = match auction.kind {
+				AuctionKind::English { .. } => amount,
+				AuctionKind::Dutch { .. } => Self::auction_price(&auction, now),
+			};
+			Self::ensure_fee_within_policy(Some(&collection), &fee, &price)?;
+
+			let message: AuctionBidMessageOf<T> = AuctionBidMessage {
+				collection,
+				item,
+				amount,
+				fee,
+				nonce: signature_data.nonce.clone(),
+				asset: auction.asset.clone(),
+			};
+			Self::verify_fee_signer_signature(&message.encode(), signature_data, fee, price)?;
+
+			match auction.kind {
+				AuctionKind::English { min_increment } => {
+					let min_amount = match auction.highest_bid {
+						Some(ref bid) => {
+							bid.amount.checked_add(&min_increment).ok_or(Error::<T>::Overflow)?
+						},
+						None => auction.start_price,
+					};
+					ensure!(amount >= min_amount, Error::<T>::BidTooLow);
+
+					let bid_payment = Self::calc_bid_payment(&amount, &fee)?;
+					Self::hold_funds(&auction.asset, &who, bid_payment)
+						.map_err(|_| Error::<T>::InsufficientFunds)?;
+
+					if let Some(outbid) = auction.highest_bid.take() {
+						let outbid_payment = Self::calc_bid_payment(&outbid.amount, &outbid.fee)?;
+						Self::release_funds(&auction.asset, &outbid.bidder, outbid_payment)?;
+					}
+
+					auction.highest_bid = Some(AuctionBid { bidder: who.clone(), amount, fee });
+					Auctions::<T>::insert(collection, item, auction);
+
+					Self::deposit_event(Event::AuctionBidPlaced {
+						collection,
+						item,
+						bidder: who,
+						amount,
+						fee,
+					});
+				},
+				AuctionKind::Dutch { .. } => {
+					ensure!(amount >= price, Error::<T>::BidTooLow);
+
+					let bid_payment = Self::calc_bid_payment(&price, &fee)?;
+					Self::hold_funds(&auction.asset, &who, bid_payment)
+						.map_err(|_| Error::<T>::InsufficientFunds)?;
+
+					auction.highest_bid = Some(AuctionBid { bidder: who, amount: price, fee });
+					Self::settle(collection, item, auction)?;
+				},
+			}
+
+			Ok(())
+		}
+
+		/// Settle an auction that has ended.
+		///
+		/// Callable by any account, auctions are also settled automatically in the `on_idle`
+		/// hook.
+		///
+		/// If the auction has a winning bid the item is sold to the bidder, otherwise the item is
+		/// unlocked.
+		///
+		/// Parameters:
+		/// - `collection`: The collection ID of the auctioned NFT.
+		/// - `item`: The item ID of the auctioned NFT.
+		///
+		/// Emits `AuctionSettled` event upon successful execution, preceded by `OrderExecuted`
+		/// if the item was sold.
+		///
+		/// Weight: `WeightInfo::settle_auction` (defined in the `Config` trait).
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::settle_auction())]
+		pub fn settle_auction(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: ItemId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let auction =
+				Auctions::<T>::get(collection, item).ok_or(Error::<T>::AuctionNotFound)?;
+			ensure!(
+				pallet_timestamp::Pallet::<T>::get() >= auction.ends_at,
+				Error::<T>::AuctionNotEnded
+			);
+
+			Self::settle(collection, item, auction)
+		}
+
+		/// Cancels all the open Asks, Bids, Collection Bids and bundle orders of the caller.
+		///
+		/// The items of the Asks are unlocked and the funds held for the Bids are released.
+		/// Auctions are not canceled, and orders that can't be removed are skipped.
+		///
+		/// Emits `AllOrdersCanceled` event upon successful execution.
+		///
+		/// Weight: `WeightInfo::cancel_all_orders` (defined in the `Config` trait).
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_all_orders(
+			T::MaxOrdersPerAccount::get(),
+			T::MaxOrdersPerAccount::get().saturating_mul(T::MaxBundleItems::get()),
+		))]
+		pub fn cancel_all_orders(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let (count, items) = Self::cancel_orders_of(&who);
+
+			Ok(Some(<T as Config>::WeightInfo::cancel_all_orders(count, items)).into())
+		}
+
+		/// Cancels all the open Asks, Bids, Collection Bids and bundle orders of an account.
+		///
+		/// Only callable by the authority, to clear the orders of a compromised account.
+		///
+		/// Parameters:
+		/// - `who`: The account whose orders are canceled.
+		///
+		/// Emits `AllOrdersCanceled` event upon successful execution.
+		///
+		/// Weight: `WeightInfo::force_cancel_all_orders` (defined in the `Config` trait).
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::force_cancel_all_orders(
+			T::MaxOrdersPerAccount::get(),
+			T::MaxOrdersPerAccount::get().saturating_mul(T::MaxBundleItems::get()),
+		))]
+		pub fn force_cancel_all_orders(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			Self::ensure_authority(&caller)?;
+
+			let (count, items) = Self::cancel_orders_of(&who);
+
+			Ok(Some(<T as Config>::WeightInfo::force_cancel_all_orders(count, items)).into())
+		}
+
+		/// Create an Ask or Bid Order for a bundle of NFTs, possibly from different collections,
+		/// traded together for a single price.
+		///
+		/// The bundle is identified by the hash of its items, in order, so a Bid only matches an
+		/// Ask listing the same items in the same order.
+		///
+		/// Asks:
+		/// - The caller must own every item of the bundle.
+		/// - After the ask is created, all the items are locked so they can't be transferred.
+		///
+		/// Bids:
+		/// - Every item of the bundle must exist and not be owned by the caller.
+		/// - The price plus the fee is locked from the user's Balance.
+		///
+		/// The signature must come from the feeSigner account and the expiration must be above
+		/// `MinOrderDuration`.
+		///
+		/// Match Exists:
+		/// - If a match between a bundle Ask and Bid exists, the trade is triggered.
+		/// - The seller receives the funds, and the bidder receives all the items. The trade
+		///   fails as a whole if any item is missing.
+		/// - The royalty of each item is computed on an equal share of the price.
+		///
+		/// Parameters:
+		/// - `order`: The details of the order to be created (including type, items, price,
+		///   expiration, fee, and signature).
+		/// - `execution`: Execution mode to indicate whether order creation should proceed if a
+		///   valid match exists.
+		///
+		/// Emits `BundleOrderCreated` event upon successful execution.
+		///
+		/// Weight: `WeightInfo::create_bundle_order` (defined in the `Config` trait).
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_bundle_order(order.items.len() as u32))]
+		pub fn create_bundle_order(
+			origin: OriginFor<T>,
+			order: BundleOrderOf<T>,
+			execution: Execution,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				order.expires_at
+					> pallet_timestamp::Pallet::<T>::get()
+						.saturating_add(T::MinOrderDuration::get()),
+				Error::<T>::InvalidExpiration
+			);
+			ensure!(!order.items.is_empty(), Error::<T>::InvalidBundle);
+			for (i, item) in order.items.iter().enumerate() {
+				ensure!(!order.items[..i].contains(item), Error::<T>::InvalidBundle);
+				Self::ensure_trading_allowed(&item.0)?;
+			}
+
+			if let Some(ref asset) = order.asset {
+				ensure!(T::Assets::asset_exists(asset.clone()), Error::<T>::UnknownAsset);
+			}
+
+			for (collection, _) in order.items.iter() {
+				Self::ensure_fee_within_policy(Some(collection), &order.fee, &order.price)?;
+			}
+
+			let message: BundleOrderMessageOf<T> = order.clone().into();
+			let fee_signer = Self::verify_fee_signer_signature(
+				&message.encode(),
+				order.signature_data.clone(),
+				order.fee,
+				order.price,
+			)?;
+
+			let bundle = Self::bundle_id(&order.items);
+			let now = pallet_timestamp::Pallet::<T>::get();
+
+			Self::deposit_event(Event::BundleOrderCreated {
+				who: who.clone(),
+				order_type: order.order_type.clone(),
+				bundle,
+				items: order.items.clone(),
+				price: order.price,
+				expires_at: order.expires_at,
+				fee: order.fee,
+				asset: order.asset.clone(),
+				fee_signer,
+			});
+
+			match order.order_type {
+				OrderType::Ask => {
+					ensure!(!BundleAsks::<T>::contains_key(bundle), Error::<T>::OrderAlreadyExists);
+					for (collection, item) in order.items.iter() {
+						let item_owner = pallet_nfts::Pallet::<T>::owner(*collection, *item)
+							.ok_or(Error::<T>::ItemNotFound)?;
+						ensure!(item_owner == who, Error::<T>::NotItemOwner);
+						// Check if item is locked
+						pallet_nfts::Pallet::<T>::disable_transfer(collection, item)
+							.map_err(|_| Error::<T>::ItemAlreadyLocked)?;
+					}
+
+					let ask = BundleAsk {
+						seller: who.clone(),
+						items: order.items,
+						price: order.price,
+						expiration: order.expires_at,
+						fee: order.fee,
+						asset: order.asset,
+					};
+
+					match BundleBids::<T>::get(bundle, order.price) {
+						Some(bid) if now < bid.expiration && bid.asset == ask.asset => {
+							ensure!(who != bid.buyer, Error::<T>::BuyerIsSeller);
+
+							BundleBids::<T>::remove(bundle, order.price);
+							Self::unindex_order(
+								&bid.buyer,
+								bid.expiration,
+								&OrderKey::BundleBid { bundle, price: order.price },
+							);
+
+							Self::complete_bundle_trade(bundle, ask, bid.buyer, bid.fee)?;
+						},
+						_ => {
+							ensure!(
+								execution == Execution::AllowCreation,
+								Error::<T>::ValidMatchMustExist
+							);
+
+							BundleAsks::<T>::insert(bundle, ask);
+							Self::index_order(
+								&who,
+								order.expires_at,
+								OrderKey::BundleAsk { bundle },
+							)?;
+						},
+					}
+				},
+
+				OrderType::Bid => {
+					ensure!(
+						!BundleBids::<T>::contains_key(bundle, order.price),
+						Error::<T>::OrderAlreadyExists
+					);
+					for (collection, item) in order.items.iter() {
+						let item_owner = pallet_nfts::Pallet::<T>::owner(*collection, *item)
+							.ok_or(Error::<T>::ItemNotFound)?;
+						ensure!(item_owner != who, Error::<T>::BidOnOwnedItem);
+					}
+
+					// Reserve necessary amount to pay for the items + fees
+					let bid_payment = Self::calc_bid_payment(&order.price, &order.fee)?;
+					Self::hold_funds(&order.asset, &who, bid_payment)
+						.map_err(|_| Error::<T>::InsufficientFunds)?;
+
+					match BundleAsks::<T>::get(bundle) {
+						Some(ask)
+							if now < ask.expiration
+								&& ask.price == order.price
+								&& ask.asset == order.asset =>
+						{
+							ensure!(who != ask.seller, Error::<T>::BuyerIsSeller);
+
+							BundleAsks::<T>::remove(bundle);
+							Self::unindex_order(
+								&ask.seller,
+								ask.expiration,
+								&OrderKey::BundleAsk { bundle },
+							);
+
+							Self::complete_bundle_trade(bundle, ask, who, order.fee)?;
+						},
+						_ => {
+							ensure!(
+								execution == Execution::AllowCreation,
+								Error::<T>::ValidMatchMustExist
+							);
+
+							let bid = Bid {
+								buyer: who.clone(),
+								expiration: order.expires_at,
+								fee: order.fee,
+								asset: order.asset,
+							};
+
+							BundleBids::<T>::insert(bundle, order.price, bid);
+							Self::index_order(
+								&who,
+								order.expires_at,
+								OrderKey::BundleBid { bundle, price: order.price },
+							)?;
+						},
+					}
+				},
+
+				OrderType::CollectionBid { .. } => return Err(Error::<T>::InvalidBundle.into()),
+			};
+
+			Ok(())
+		}
+
+		/// Cancellation of a bundle Ask or Bid order.
+		///
+		/// Callable by either the authority or the order creator.
+		///
+		/// If the order is an Ask, all the items of the bundle are unlocked.
+		/// If the order is a Bid, the bidder's balance is unlocked.
+		///
+		/// Parameters:
+		/// - `order_type`: The type of the order to be canceled, Ask or Bid.
+		/// - `bundle`: The id of the bundle associated with the order.
+		/// - `price`: The price associated with the order (used for Bid orders).
+		///
+		/// Emits `BundleOrderCanceled` event upon successful execution.
+		///
+		/// Weight: `WeightInfo::cancel_bundle_order` (defined in the `Config` trait).
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_bundle_order(T::MaxBundleItems::get()))]
+		pub fn cancel_bundle_order(
+			origin: OriginFor<T>,
+			order_type: OrderType,
+			bundle: BundleId,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let authority = Authority::<T>::get();
+
+			let order = match order_type {
+				OrderType::Ask => OrderKey::BundleAsk { bundle },
+				OrderType::Bid => OrderKey::BundleBid { bundle, price },
+				OrderType::CollectionBid { .. } => return Err(Error::<T>::InvalidBundle.into()),
+			};
+			let (creator, _) = Self::order_info(&order).ok_or(Error::<T>::OrderNotFound)?;
+			ensure!(
+				creator == who || Some(who.clone()) == authority,
+				Error::<T>::NotOrderCreatorOrAdmin
+			);
+
+			Self::remove_order(&order)?;
+
+			Self::deposit_event(Event::BundleOrderCanceled { bundle, who });
 
 			Ok(())
 		}
+
+		/// Update the price, expiration and fee of an existing Ask or Bid order.
+		///
+		/// Only callable by the order creator. The signature must come from the feeSigner account
+		/// and the new expiration must be above `MinOrderDuration`. The signed message includes
+		/// the order type, so an approval to update an Ask can't be used to update a Bid.
+		///
+		/// Asks:
+		/// - The item stays locked and the escrow agent and asset of the Ask are kept.
+		///
+		/// Bids:
+		/// - The funds held for the Bid are adjusted by the difference between the new and the
+		///   current price plus fee.
+		///
+		/// Match Exists:
+		/// - If the updated order matches an existing order, the trade is triggered.
+		///
+		/// Parameters:
+		/// - `update`: The order to update (type, collection, item and current price) with its new
+		///   price, expiration, fee, and signature.
+		///
+		/// Emits `OrderUpdated` event upon successful execution.
+		///
+		/// Weight: `WeightInfo::update_order` (defined in the `Config` trait).
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_order())]
+		pub fn update_order(origin: OriginFor<T>, update: OrderUpdateOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_trading_allowed(&update.collection)?;
+
+			ensure!(
+				update.expires_at
+					> pallet_timestamp::Pallet::<T>::get()
+						.saturating_add(T::MinOrderDuration::get()),
+				Error::<T>::InvalidExpiration
+			);
+
+			Self::ensure_fee_within_policy(Some(&update.collection), &update.fee, &update.price)?;
+
+			let message: OrderUpdateMessageOf<T> = update.clone().into();
+			let fee_signer = Self::verify_fee_signer_signature(
+				&message.encode(),
+				update.signature_data.clone(),
+				update.fee,
+				update.price,
+			)?;
+
+			let (collection, item, price) = (update.collection, update.item, update.price);
+
+			Self::deposit_event(Event::OrderUpdated {
+				who: who.clone(),
+				order_type: update.order_type.clone(),
+				collection,
+				item,
+				price,
+				expires_at: update.expires_at,
+				fee: update.fee,
+				fee_signer,
+			});
+
+			match update.order_type {
+				OrderType::Ask => {
+					let ask = Asks::<T>::get(collection, item).ok_or(Error::<T>::OrderNotFound)?;
+					ensure!(ask.seller == who, Error::<T>::NotOrderCreator);
+					let key = OrderKey::Ask { collection, item };
+					Self::unindex_order(&who, ask.expiration, &key);
+
+					if let Some(exec_order) = Self::valid_match_exists_for(
+						OrderType::Ask,
+						&collection,
+						&item,
+						&price,
+						&ask.asset,
+						ask.buyer.as_ref(),
+					) {
+						Asks::<T>::remove(collection, item);
+						Self::execute_order(
+							exec_order,
+							who,
+							collection,
+							item,
+							&price,
+							&update.fee,
+							ask.escrow_agent,
+							ask.escrow_settlement,
+						)?;
+					} else {
+						Asks::<T>::insert(
+							collection,
+							item,
+							Ask { price, expiration: update.expires_at, fee: update.fee, ..ask },
+						);
+						Self::index_order(&who, update.expires_at, key)?;
+					}
+				},
+
+				OrderType::Bid => {
+					let current_price = update.current_price;
+					let bid = Bids::<T>::take((collection, item, current_price))
+						.ok_or(Error::<T>::OrderNotFound)?;
+					ensure!(bid.buyer == who, Error::<T>::NotOrderCreator);
+					ensure!(
+						!Bids::<T>::contains_key((collection, item, price)),
+						Error::<T>::OrderAlreadyExists
+					);
+					Self::unindex_order(
+						&who,
+						bid.expiration,
+						&OrderKey::Bid { collection, item, price: current_price },
+					);
+
+					// Adjust the held funds by the difference only
+					let current_payment = Self::calc_bid_payment(&current_price, &bid.fee)?;
+					let bid_payment = Self::calc_bid_payment(&price, &update.fee)?;
+					if bid_payment > current_payment {
+						Self::hold_funds(&bid.asset, &who, bid_payment - current_payment)
+							.map_err(|_| Error::<T>::InsufficientFunds)?;
+					} else if bid_payment < current_payment {
+						Self::release_funds(&bid.asset, &who, current_payment - bid_payment)?;
+					}
+
+					if let Some(exec_order) = Self::valid_match_exists_for(
+						OrderType::Bid,
+						&collection,
+						&item,
+						&price,
+						&bid.asset,
+						Some(&who),
+					) {
+						Self::execute_order(
+							exec_order,
+							who,
+							collection,
+							item,
+							&price,
+							&update.fee,
+							None,
+							EscrowSettlement::Agent,
+						)?;
+					} else {
+						Bids::<T>::insert(
+							(collection, item, price),
+							Bid { expiration: update.expires_at, fee: update.fee, ..bid },
+						);
+						Self::index_order(
+							&who,
+							update.expires_at,
+							OrderKey::Bid { collection, item, price },
+						)?;
+					}
+				},
+
+				OrderType::CollectionBid { .. } => return Err(Error::<T>::InvalidOrderType.into()),
+			};
+
+			Ok(())
+		}
+
+		/// Adds a fee signer, or updates the restrictions of an existing fee signer.
+		///
+		/// Only an account with the authority role can execute this function.
+		///
+		/// The signatures of all the active fee signers are accepted, so the signing key can be
+		/// rotated without downtime by adding the new signer before the old one stops being valid.
+		///
+		/// Parameters:
+		/// - `fee_signer`: The account ID of the fee signer.
+		/// - `info`: The validity window of the signer and the maximum fee it can approve.
+		///
+		/// Emits `FeeSignerAdded` event upon successful execution.
+		///
+		/// Weight: `WeightInfo::add_fee_signer` (defined in the `Config` trait).
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_fee_signer())]
+		pub fn add_fee_signer(
+			origin: OriginFor<T>,
+			fee_signer: T::AccountId,
+			info: FeeSignerInfoOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_authority(&who)?;
+
+			FeeSigners::<T>::try_mutate(|fee_signers| {
+				match fee_signers.iter_mut().find(|(signer, _)| *signer == fee_signer) {
+					Some((_, current)) => *current = info.clone(),
+					None => fee_signers
+						.try_push((fee_signer.clone(), info.clone()))
+						.map_err(|_| Error::<T>::TooManyFeeSigners)?,
+				}
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::FeeSignerAdded { fee_signer, info });
+			Ok(())
+		}
+
+		/// Removes a fee signer, its signatures are rejected from then on.
+		///
+		/// Only an account with the authority role can execute this function.
+		///
+		/// Parameters:
+		/// - `fee_signer`: The account ID of the fee signer to be removed.
+		///
+		/// Emits `FeeSignerRemoved` event upon successful execution.
+		///
+		/// Weight: `WeightInfo::remove_fee_signer` (defined in the `Config` trait).
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_fee_signer())]
+		pub fn remove_fee_signer(origin: OriginFor<T>, fee_signer: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_authority(&who)?;
+
+			FeeSigners::<T>::try_mutate(|fee_signers| {
+				let index = fee_signers
+					.iter()
+					.position(|(signer, _)| *signer == fee_signer)
+					.ok_or(Error::<T>::FeeSignerNotFound)?;
+				fee_signers.remove(index);
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::FeeSignerRemoved { fee_signer });
+			Ok(())
+		}
+
+		/// Sets or removes the bounds of the order fees, in basis points of the order price.
+		///
+		/// Only an account with the authority role can execute this function.
+		///
+		/// The fee of every order, auction and auction bid must be within the fee policy of its
+		/// collection, or the default fee policy if the collection has none, whatever the fee
+		/// signer approved. The fee of a bundle order must be within the policy of every
+		/// collection of the bundle.
+		///
+		/// Parameters:
+		/// - `collection`: The collection ID the policy applies to, `None` for the default policy.
+		/// - `policy`: The minimum and maximum fee, `None` removes the policy.
+		///
+		/// Emits `FeePolicySet` event upon successful execution.
+		///
+		/// Weight: `WeightInfo::set_fee_policy` (defined in the `Config` trait).
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_fee_policy())]
+		pub fn set_fee_policy(
+			origin: OriginFor<T>,
+			collection: Option<T::CollectionId>,
+			policy: Option<FeePolicy>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_authority(&who)?;
+
+			if let Some(ref policy) = policy {
+				ensure!(policy.is_valid(), Error::<T>::InvalidFeePolicy);
+			}
+
+			match collection {
+				Some(collection) => CollectionFeePolicies::<T>::set(collection, policy.clone()),
+				None => DefaultFeePolicy::<T>::set(policy.clone()),
+			}
+
+			Self::deposit_event(Event::FeePolicySet { collection, policy });
+			Ok(())
+		}
+
+		/// Pauses or resumes trading on the marketplace.
+		///
+		/// Callable by either the root origin or the authority.
+		///
+		/// While trading is paused orders, bundle orders and auctions can't be created, updated
+		/// nor executed, and auctions can't be bid on nor settled. Orders can still be canceled.
+		/// Auctions ending while trading is paused are not settled by the `on_idle` hook, they
+		/// can be settled with `settle_auction` once trading resumes.
+		///
+		/// Parameters:
+		/// - `paused`: Whether trading is paused.
+		///
+		/// Emits `TradingPauseSet` event upon successful execution.
+		///
+		/// Weight: `WeightInfo::set_trading_paused` (defined in the `Config` trait).
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_trading_paused())]
+		pub fn set_trading_paused(origin: OriginFor<T>, paused: bool) -> DispatchResult {
+			Self::ensure_root_or_authority(origin)?;
+
+			Paused::<T>::put(paused);
+
+			Self::deposit_event(Event::TradingPauseSet { paused });
+			Ok(())
+		}
+
+		/// Halts or resumes trading on a collection.
+		///
+		/// Callable by either the root origin or the authority.
+		///
+		/// While a collection is halted, the orders, bundle orders and auctions on its items are
+		/// blocked as if trading was paused. Orders can still be canceled.
+		/// Auctions ending while the collection is halted are not settled by the `on_idle` hook,
+		/// they can be settled with `settle_auction` once trading resumes.
+		///
+		/// Parameters:
+		/// - `collection`: The collection ID to halt or resume.
+		/// - `halted`: Whether trading on the collection is halted.
+		///
+		/// Emits `CollectionHaltSet` event upon successful execution.
+		///
+		/// Weight: `WeightInfo::set_collection_halted` (defined in the `Config` trait).
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_collection_halted())]
+		pub fn set_collection_halted(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			halted: bool,
+		) -> DispatchResult {
+			Self::ensure_root_or_authority(origin)?;
+
+			match halted {
+				true => HaltedCollections::<T>::insert(collection, ()),
+				false => HaltedCollections::<T>::remove(collection),
+			}
+
+			Self::deposit_event(Event::CollectionHaltSet { collection, halted });
+			Ok(())
+		}
+
+		/// Confirms the receipt of the item of a trade settled in escrow, releasing the seller
+		/// proceeds.
+		///
+		/// Only callable by the buyer of the trade, at any time, even if the trade is disputed.
+		///
+		/// Parameters:
+		/// - `escrow_id`: The escrow ID of the trade, reported in its `OrderExecuted` event.
+		///
+		/// Emits `EscrowReleased` event upon successful execution.
+		///
+		/// Weight: `WeightInfo::confirm_receipt` (defined in the `Config` trait).
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as Config>::WeightInfo::confirm_receipt())]
+		pub fn confirm_receipt(origin: OriginFor<T>, escrow_id: EscrowId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let escrow = Escrows::<T>::get(escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+			ensure!(escrow.buyer == who, Error::<T>::NotEscrowBuyer);
+
+			Self::release_escrow_to_seller(escrow_id, escrow)
+		}
+
+		/// Releases the seller proceeds of a trade settled in escrow once its escrow timeout
+		/// has passed.
+		///
+		/// Callable by anyone, unless the buyer disputed the trade.
+		///
+		/// Parameters:
+		/// - `escrow_id`: The escrow ID of the trade, reported in its `OrderExecuted` event.
+		///
+		/// Emits `EscrowReleased` event upon successful execution.
+		///
+		/// Weight: `WeightInfo::release_escrow` (defined in the `Config` trait).
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as Config>::WeightInfo::release_escrow())]
+		pub fn release_escrow(origin: OriginFor<T>, escrow_id: EscrowId) -> DispatchResult {
+			ensure_signed(origin)?;
+			let escrow = Escrows::<T>::get(escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+			ensure!(!escrow.disputed, Error::<T>::EscrowDisputed);
+			ensure!(
+				pallet_timestamp::Pallet::<T>::get() >= escrow.release_at,
+				Error::<T>::EscrowTimeoutNotPassed
+			);
+
+			Self::release_escrow_to_seller(escrow_id, escrow)
+		}
+
+		/// Disputes a trade settled in escrow, so its proceeds are not released at the escrow
+		/// timeout.
+		///
+		/// Only callable by the buyer of the trade before its escrow timeout. The escrow agent
+		/// then either releases the proceeds to the seller or refunds the buyer through
+		/// `resolve_escrow_dispute`.
+		///
+		/// Parameters:
+		/// - `escrow_id`: The escrow ID of the trade, reported in its `OrderExecuted` event.
+		///
+		/// Emits `EscrowDisputed` event upon successful execution.
+		///
+		/// Weight: `WeightInfo::dispute_escrow` (defined in the `Config` trait).
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as Config>::WeightInfo::dispute_escrow())]
+		pub fn dispute_escrow(origin: OriginFor<T>, escrow_id: EscrowId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Escrows::<T>::try_mutate(escrow_id, |maybe_escrow| -> DispatchResult {
+				let escrow = maybe_escrow.as_mut().ok_or(Error::<T>::EscrowNotFound)?;
+				ensure!(escrow.buyer == who, Error::<T>::NotEscrowBuyer);
+				ensure!(!escrow.disputed, Error::<T>::EscrowDisputed);
+				ensure!(
+					pallet_timestamp::Pallet::<T>::get() < escrow.release_at,
+					Error::<T>::EscrowTimeoutPassed
+				);
+
+				escrow.disputed = true;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::EscrowDisputed { escrow_id });
+			Ok(())
+		}
+
+		/// Resolves the dispute of a trade settled in escrow.
+		///
+		/// Only callable by the escrow agent of the trade.
+		///
+		/// Parameters:
+		/// - `escrow_id`: The escrow ID of the disputed trade.
+		/// - `refund`: Whether the proceeds are refunded to the buyer, otherwise they are
+		///   released to the seller.
+		///
+		/// Emits `EscrowRefunded` or `EscrowReleased` event upon successful execution.
+		///
+		/// Weight: `WeightInfo::resolve_escrow_dispute` (defined in the `Config` trait).
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as Config>::WeightInfo::resolve_escrow_dispute())]
+		pub fn resolve_escrow_dispute(
+			origin: OriginFor<T>,
+			escrow_id: EscrowId,
+			refund: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let escrow = Escrows::<T>::get(escrow_id).ok_or(Error::<T>::EscrowNotFound)?;
+			ensure!(escrow.agent == who, Error::<T>::NotEscrowAgent);
+			ensure!(escrow.disputed, Error::<T>::EscrowNotDisputed);
+
+			if !refund {
+				return Self::release_escrow_to_seller(escrow_id, escrow);
+			}
+
+			T::Escrow::revoke_deposit(
+				&escrow.seller,
+				&escrow.buyer,
+				&Self::escrow_account(escrow_id),
+			)?;
+			Escrows::<T>::remove(escrow_id);
+
+			Self::deposit_event(Event::EscrowRefunded {
+				escrow_id,
+				buyer: escrow.buyer,
+				amount: escrow.amount,
+			});
+			Ok(())
+		}
+
+		/// Cancellation of a Collection Bid.
+		///
+		/// Callable by either the authority or the buyer of the bid.
+		///
+		/// The buyer's balance for the unfilled quantity is unlocked.
+		///
+		/// Parameters:
+		/// - `collection`: The collection ID of the bid.
+		/// - `price`: The price of the bid.
+		/// - `buyer`: The account that placed the bid.
+		///
+		/// Emits `CollectionBidCanceled` event upon successful execution.
+		///
+		/// Weight: `WeightInfo::cancel_order` (defined in the `Config` trait).
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_order())]
+		pub fn cancel_collection_bid(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			price: BalanceOf<T>,
+			buyer: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				buyer == who || Authority::<T>::get().as_ref() == Some(&who),
+				Error::<T>::NotOrderCreatorOrAdmin
+			);
+
+			Self::remove_order(&OrderKey::CollectionBid {
+				collection,
+				price,
+				buyer: buyer.clone(),
+			})?;
+
+			Self::deposit_event(Event::CollectionBidCanceled { collection, price, buyer, who });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -547,26 +2181,97 @@ pub mod pallet {
 			}
 		}
 
+		fn ensure_root_or_authority(origin: OriginFor<T>) -> DispatchResult {
+			if ensure_root(origin.clone()).is_ok() {
+				return Ok(());
+			}
+			let who = ensure_signed(origin)?;
+			Self::ensure_authority(&who)?;
+			Ok(())
+		}
+
+		/// Ensures the creation and execution of orders is neither paused nor halted for the
+		/// collection.
+		pub fn ensure_trading_allowed(collection: &T::CollectionId) -> Result<(), Error<T>> {
+			ensure!(!Paused::<T>::get(), Error::<T>::TradingPaused);
+			ensure!(
+				!HaltedCollections::<T>::contains_key(collection),
+				Error::<T>::CollectionHalted
+			);
+			Ok(())
+		}
+
+		/// Ensures the fee of an order of `price` is within the fee policy of the collection, or
+		/// the default fee policy if the collection has none.
+		///
+		/// Any fee is allowed if no fee policy applies.
+		pub fn ensure_fee_within_policy(
+			collection: Option<&T::CollectionId>,
+			fee: &BalanceOf<T>,
+			price: &BalanceOf<T>,
+		) -> Result<(), Error<T>> {
+			let Some(policy) = collection
+				.and_then(CollectionFeePolicies::<T>::get)
+				.or_else(DefaultFeePolicy::<T>::get)
+			else {
+				return Ok(());
+			};
+
+			ensure!(
+				*fee >= policy.min_fee().mul_floor(*price)
+					&& *fee <= policy.max_fee().mul_ceil(*price),
+				Error::<T>::InvalidFeePercent
+			);
+			Ok(())
+		}
+
+		/// The order matching an order of `order_type` on the item, if any.
+		///
+		/// `buyer` is the account an Ask is restricted to, or the account placing a Bid or a
+		/// Collection Bid. A restricted Ask only matches the Bids of its buyer. A Collection Bid
+		/// only matches the Ask on the item of the order.
 		pub fn valid_match_exists_for(
 			order_type: OrderType,
 			collection: &T::CollectionId,
 			item: &ItemId,
 			price: &BalanceOf<T>,
+			asset: &Option<AssetIdOf<T>>,
+			buyer: Option<&T::AccountId>,
 		) -> Option<ExecOrderOf<T>> {
 			let timestamp = pallet_timestamp::Pallet::<T>::get();
+			let allowed = |bidder: &T::AccountId| buyer.is_none_or(|buyer| buyer == bidder);
 
 			match order_type {
 				OrderType::Ask => {
+					// Bids on the item take precedence over bids on the whole collection
 					if let Some(bid) = Bids::<T>::get((collection, item, price)) {
-						if timestamp >= bid.expiration {
-							return None;
+						if timestamp < bid.expiration && bid.asset == *asset && allowed(&bid.buyer)
+						{
+							return Some(ExecOrder::Bid(bid));
 						};
-						return Some(ExecOrder::Bid(bid));
 					}
+
+					// A restricted Ask can only fill the Collection Bid of its buyer
+					let valid = |bid: &CollectionBidOf<T>| {
+						timestamp < bid.expiration && bid.asset == *asset
+					};
+					let collection_bid = match buyer {
+						Some(buyer) => {
+							CollectionBids::<T>::get((collection, price, buyer)).filter(valid)
+						},
+						None => CollectionBids::<T>::iter_prefix_values((collection, price))
+							.take(MAX_COLLECTION_BID_CANDIDATES)
+							.find(valid),
+					};
+					return collection_bid.map(ExecOrder::CollectionBid);
 				},
-				OrderType::Bid => {
+				OrderType::Bid | OrderType::CollectionBid { .. } => {
 					if let Some(ask) = Asks::<T>::get(collection, item) {
-						if timestamp >= ask.expiration || ask.price != *price {
+						if timestamp >= ask.expiration
+							|| ask.price != *price
+							|| ask.asset != *asset
+							|| (ask.buyer.is_some() && ask.buyer.as_ref() != buyer)
+						{
 							return None;
 						};
 						return Some(ExecOrder::Ask(ask));
@@ -576,6 +2281,7 @@ pub mod pallet {
 			None
 		}
 
+		#[allow(clippy::too_many_arguments)]
 		pub fn execute_order(
 			exec_order: ExecOrderOf<T>,
 			who: T::AccountId,
@@ -584,38 +2290,146 @@ pub mod pallet {
 			price: &BalanceOf<T>,
 			fee: &BalanceOf<T>,
 			order_escrow_agent: Option<T::AccountId>,
+			order_escrow_settlement: EscrowSettlement,
 		) -> Result<(), DispatchError> {
 			let seller: T::AccountId;
 			let buyer: T::AccountId;
 			let seller_fee: BalanceOf<T>;
 			let buyer_fee: BalanceOf<T>;
 			let escrow_agent: Option<T::AccountId>;
+			let escrow_settlement: EscrowSettlement;
+			let asset: Option<AssetIdOf<T>>;
 
 			match exec_order {
 				ExecOrder::Bid(bid) => {
 					ensure!(who.clone() != bid.buyer.clone(), Error::<T>::BuyerIsSeller);
 
+					Bids::<T>::remove((collection, item, *price));
+					Self::unindex_order(
+						&bid.buyer,
+						bid.expiration,
+						&OrderKey::Bid { collection, item, price: *price },
+					);
+
 					seller = who;
 					buyer = bid.buyer;
 					seller_fee = *fee;
 					buyer_fee = bid.fee;
 					escrow_agent = order_escrow_agent;
+					escrow_settlement = order_escrow_settlement;
+					asset = bid.asset;
 				},
 				ExecOrder::Ask(ask) => {
 					ensure!(who.clone() != ask.seller.clone(), Error::<T>::BuyerIsSeller);
+					ensure!(
+						ask.buyer.as_ref().is_none_or(|buyer| *buyer == who),
+						Error::<T>::NotListingBuyer
+					);
+
+					Asks::<T>::remove(collection, item);
+					Self::unindex_order(
+						&ask.seller,
+						ask.expiration,
+						&OrderKey::Ask { collection, item },
+					);
 
 					seller = ask.seller;
 					buyer = who;
 					seller_fee = ask.fee;
 					buyer_fee = *fee;
 					escrow_agent = ask.escrow_agent;
+					escrow_settlement = ask.escrow_settlement;
+					asset = ask.asset;
+				},
+				ExecOrder::CollectionBid(bid) => {
+					ensure!(who.clone() != bid.buyer.clone(), Error::<T>::BuyerIsSeller);
+
+					// The remaining fee is spread over the remaining fills, the last one pays
+					// whatever is left. `quantity` is never zero for a stored bid.
+					let fill_fee = bid.fee / BalanceOf::<T>::from(bid.quantity);
+					let quantity = bid.quantity.saturating_sub(1);
+					if quantity == 0 {
+						CollectionBids::<T>::remove((collection, *price, &bid.buyer));
+						Self::unindex_order(
+							&bid.buyer,
+							bid.expiration,
+							&OrderKey::CollectionBid {
+								collection,
+								price: *price,
+								buyer: bid.buyer.clone(),
+							},
+						);
+					} else {
+						CollectionBids::<T>::insert(
+							(collection, *price, &bid.buyer),
+							CollectionBid {
+								buyer: bid.buyer.clone(),
+								expiration: bid.expiration,
+								fee: bid.fee.saturating_sub(fill_fee),
+								quantity,
+								asset: bid.asset.clone(),
+							},
+						);
+					}
+
+					seller = who;
+					buyer = bid.buyer;
+					seller_fee = *fee;
+					buyer_fee = fill_fee;
+					escrow_agent = order_escrow_agent;
+					escrow_settlement = order_escrow_settlement;
+					asset = bid.asset;
 				},
 			};
 
-			Asks::<T>::remove(collection, item);
-			Bids::<T>::remove((collection, item, *price));
+			Self::complete_trade(
+				collection,
+				item,
+				seller,
+				buyer,
+				*price,
+				seller_fee,
+				buyer_fee,
+				escrow_agent,
+				escrow_settlement,
+				asset,
+			)
+		}
 
-			Self::process_fees(&seller, seller_fee, &buyer, buyer_fee, *price, escrow_agent)?;
+		/// Pays the seller, the marketplace and the collection royalty from the funds held for
+		/// the buyer, then transfers the locked item to the buyer.
+		#[allow(clippy::too_many_arguments)]
+		fn complete_trade(
+			collection: T::CollectionId,
+			item: ItemId,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			price: BalanceOf<T>,
+			seller_fee: BalanceOf<T>,
+			buyer_fee: BalanceOf<T>,
+			escrow_agent: Option<T::AccountId>,
+			escrow_settlement: EscrowSettlement,
+			asset: Option<AssetIdOf<T>>,
+		) -> Result<(), DispatchError> {
+			let royalty = Royalties::<T>::get(collection)
+				.map(|royalty| (royalty.recipient, royalty.rate * price));
+
+			// With `EscrowSettlement::Marketplace` the proceeds are deposited for the escrow
+			// account of the trade rather than for its escrow agent, so they can only be released
+			// or refunded through the marketplace.
+			let escrow_id = (escrow_agent.is_some()
+				&& escrow_settlement == EscrowSettlement::Marketplace)
+				.then(Self::next_escrow_id);
+			let seller_proceeds = Self::process_fees(
+				&seller,
+				seller_fee,
+				&buyer,
+				buyer_fee,
+				price,
+				royalty.as_slice(),
+				escrow_id.map(Self::escrow_account).or_else(|| escrow_agent.clone()),
+				&asset,
+			)?;
 
 			pallet_nfts::Pallet::<T>::enable_transfer(&collection, &item)?;
 			<pallet_nfts::Pallet<T> as Transfer<T::AccountId>>::transfer(
@@ -623,15 +2437,267 @@ pub mod pallet {
 				&item,
 				&buyer,
 			)?;
+			Self::record_sale(collection, item, &seller, &buyer, price, &asset);
+			if let (Some(escrow_id), Some(agent)) = (escrow_id, escrow_agent) {
+				Self::open_escrow(
+					escrow_id,
+					collection,
+					item,
+					&seller,
+					&buyer,
+					agent,
+					seller_proceeds,
+				);
+			}
 
 			Self::deposit_event(Event::OrderExecuted {
 				collection,
 				item,
 				seller,
 				buyer,
-				price: *price,
+				price,
 				seller_fee,
 				buyer_fee,
+				royalty: royalty.as_ref().map(|(_, amount)| *amount).unwrap_or_default(),
+				royalty_recipient: royalty.map(|(recipient, _)| recipient),
+				asset,
+				escrow_id,
+			});
+			Ok(())
+		}
+
+		/// Allocates the escrow id of a trade settled in escrow.
+		fn next_escrow_id() -> EscrowId {
+			NextEscrowId::<T>::mutate(|next| {
+				let id = *next;
+				*next = next.saturating_add(1);
+				id
+			})
+		}
+
+		/// The account the seller proceeds of a trade settled in escrow are deposited for. It is
+		/// derived from the escrow id, so deposits of different trades are kept apart and no key
+		/// can release or revoke them outside of the marketplace.
+		pub fn escrow_account(escrow_id: EscrowId) -> T::AccountId {
+			ESCROW_PALLET_ID.into_sub_account_truncating(escrow_id)
+		}
+
+		/// Records the seller proceeds of a trade deposited in escrow, releasable to the seller
+		/// after `EscrowTimeout`.
+		#[allow(clippy::too_many_arguments)]
+		fn open_escrow(
+			escrow_id: EscrowId,
+			collection: T::CollectionId,
+			item: ItemId,
+			seller: &T::AccountId,
+			buyer: &T::AccountId,
+			agent: T::AccountId,
+			amount: BalanceOf<T>,
+		) {
+			let release_at =
+				pallet_timestamp::Pallet::<T>::get().saturating_add(T::EscrowTimeout::get());
+
+			Escrows::<T>::insert(
+				escrow_id,
+				TradeEscrow {
+					collection,
+					item,
+					seller: seller.clone(),
+					buyer: buyer.clone(),
+					agent,
+					amount,
+					release_at,
+					disputed: false,
+				},
+			);
+		}
+
+		/// Releases the escrowed proceeds of a trade to its seller and removes the escrow.
+		fn release_escrow_to_seller(
+			escrow_id: EscrowId,
+			escrow: TradeEscrowOf<T>,
+		) -> DispatchResult {
+			T::Escrow::release_deposit(
+				&escrow.seller,
+				escrow.amount,
+				&Self::escrow_account(escrow_id),
+			)?;
+			Escrows::<T>::remove(escrow_id);
+
+			Self::deposit_event(Event::EscrowReleased {
+				escrow_id,
+				seller: escrow.seller,
+				amount: escrow.amount,
+			});
+			Ok(())
+		}
+
+		/// Pays the seller, the marketplace and the royalties of the bundle collections from the
+		/// funds held for the buyer, then transfers all the locked items to the buyer.
+		///
+		/// Each item pays the royalty of its collection on an equal share of the price. Fails if
+		/// any item is no longer owned by the seller.
+		fn complete_bundle_trade(
+			bundle: BundleId,
+			ask: BundleAskOf<T>,
+			buyer: T::AccountId,
+			buyer_fee: BalanceOf<T>,
+		) -> Result<(), DispatchError> {
+			let share = ask.price / BalanceOf::<T>::from(ask.items.len() as u32);
+			let mut royalties: Vec<(T::AccountId, BalanceOf<T>)> = Vec::new();
+			for (collection, item) in ask.items.iter() {
+				let owner = pallet_nfts::Pallet::<T>::owner(*collection, *item)
+					.ok_or(Error::<T>::ItemNotFound)?;
+				ensure!(owner == ask.seller, Error::<T>::NotItemOwner);
+
+				if let Some(royalty) = Royalties::<T>::get(collection) {
+					royalties.push((royalty.recipient, royalty.rate * share));
+				}
+			}
+
+			Self::process_fees(
+				&ask.seller,
+				ask.fee,
+				&buyer,
+				buyer_fee,
+				ask.price,
+				&royalties,
+				None,
+				&ask.asset,
+			)?;
+
+			for (collection, item) in ask.items.iter() {
+				pallet_nfts::Pallet::<T>::enable_transfer(collection, item)?;
+				<pallet_nfts::Pallet<T> as Transfer<T::AccountId>>::transfer(
+					collection, item, &buyer,
+				)?;
+				Self::record_sale(*collection, *item, &ask.seller, &buyer, share, &ask.asset);
+			}
+
+			Self::deposit_event(Event::BundleOrderExecuted {
+				bundle,
+				items: ask.items,
+				seller: ask.seller,
+				buyer,
+				price: ask.price,
+				seller_fee: ask.fee,
+				buyer_fee,
+				royalty: royalties.iter().fold(Zero::zero(), |total: BalanceOf<T>, (_, amount)| {
+					total.saturating_add(*amount)
+				}),
+				asset: ask.asset,
+			});
+			Ok(())
+		}
+
+		/// Adds a sale to the statistics and the recent sales of its collection, dropping the
+		/// oldest recent sale once `MaxRecentSales` is reached.
+		fn record_sale(
+			collection: T::CollectionId,
+			item: ItemId,
+			seller: &T::AccountId,
+			buyer: &T::AccountId,
+			price: BalanceOf<T>,
+			asset: &Option<AssetIdOf<T>>,
+		) {
+			TradeStats::<T>::mutate(collection, asset, |stats| {
+				let stats = stats.get_or_insert_with(Default::default);
+				stats.last_price = price;
+				stats.volume = stats.volume.saturating_add(price);
+				stats.trades = stats.trades.saturating_add(1);
+			});
+
+			let sale = Sale {
+				item,
+				seller: seller.clone(),
+				buyer: buyer.clone(),
+				price,
+				asset: asset.clone(),
+				timestamp: pallet_timestamp::Pallet::<T>::get(),
+			};
+			RecentSales::<T>::mutate(collection, |sales| {
+				if sales.is_full() && !sales.is_empty() {
+					sales.remove(0);
+				}
+				// Cannot fail, a slot was freed above unless `MaxRecentSales` is zero.
+				let _ = sales.try_push(sale);
+			});
+		}
+
+		/// Returns the id of the bundle made of `items`.
+		pub fn bundle_id(items: &BundleItemsOf<T>) -> BundleId {
+			sp_io::hashing::blake2_256(&items.encode())
+		}
+
+		/// Returns the current price of an auction: the highest bid or start price of an
+		/// English auction, the decayed price of a Dutch auction.
+		pub fn auction_price(auction: &AuctionOf<T>, now: T::Moment) -> BalanceOf<T> {
+			match auction.kind {
+				AuctionKind::English { .. } => {
+					auction.highest_bid.as_ref().map_or(auction.start_price, |bid| bid.amount)
+				},
+				AuctionKind::Dutch { end_price } => {
+					if now <= auction.starts_at {
+						return auction.start_price;
+					}
+					if now >= auction.ends_at {
+						return end_price;
+					}
+
+					let elapsed: u64 = now.saturating_sub(auction.starts_at).saturated_into();
+					let duration: u64 =
+						auction.ends_at.saturating_sub(auction.starts_at).saturated_into();
+					let decay = Perbill::from_rational(elapsed, duration)
+						* auction.start_price.saturating_sub(end_price);
+
+					auction.start_price.saturating_sub(decay)
+				},
+			}
+		}
+
+		/// Removes an auction and sells the item to the highest bidder, if any.
+		fn settle(
+			collection: T::CollectionId,
+			item: ItemId,
+			auction: AuctionOf<T>,
+		) -> Result<(), DispatchError> {
+			Self::ensure_trading_allowed(&collection)?;
+
+			Auctions::<T>::remove(collection, item);
+			Self::unindex_order(
+				&auction.seller,
+				auction.ends_at,
+				&OrderKey::Auction { collection, item },
+			);
+
+			let (winner, price) = match auction.highest_bid {
+				Some(bid) => {
+					Self::complete_trade(
+						collection,
+						item,
+						auction.seller.clone(),
+						bid.bidder.clone(),
+						bid.amount,
+						auction.fee,
+						bid.fee,
+						None,
+						EscrowSettlement::Agent,
+						auction.asset.clone(),
+					)?;
+					(Some(bid.bidder), Some(bid.amount))
+				},
+				None => {
+					pallet_nfts::Pallet::<T>::enable_transfer(&collection, &item)?;
+					(None, None)
+				},
+			};
+
+			Self::deposit_event(Event::AuctionSettled {
+				collection,
+				item,
+				seller: auction.seller,
+				winner,
+				price,
 			});
 			Ok(())
 		}
@@ -643,14 +2709,75 @@ pub mod pallet {
 			price.checked_add(fee).ok_or(Error::<T>::Overflow)
 		}
 
+		pub fn calc_collection_bid_payment(
+			price: &BalanceOf<T>,
+			quantity: u32,
+			fee: &BalanceOf<T>,
+		) -> Result<BalanceOf<T>, Error<T>> {
+			price
+				.checked_mul(&BalanceOf::<T>::from(quantity))
+				.and_then(|amount| amount.checked_add(fee))
+				.ok_or(Error::<T>::Overflow)
+		}
+
+		/// The active Ask on an item, if any.
+		pub fn ask_for(collection: T::CollectionId, item: ItemId) -> Option<AskOf<T>> {
+			let timestamp = pallet_timestamp::Pallet::<T>::get();
+			Asks::<T>::get(collection, item).filter(|ask| timestamp < ask.expiration)
+		}
+
+		/// The active Bids on an item with their price, from the highest to the lowest price.
+		pub fn bids_for(
+			collection: T::CollectionId,
+			item: ItemId,
+		) -> Vec<(BalanceOf<T>, BidOf<T>)> {
+			let timestamp = pallet_timestamp::Pallet::<T>::get();
+			let mut bids: Vec<_> = Bids::<T>::iter_prefix((collection, item))
+				.filter(|(_, bid)| timestamp < bid.expiration)
+				.collect();
+			bids.sort_by(|(a, _), (b, _)| b.cmp(a));
+			bids
+		}
+
+		/// The active Bid with the highest price on an item, if any.
+		pub fn best_bid(
+			collection: T::CollectionId,
+			item: ItemId,
+		) -> Option<(BalanceOf<T>, BidOf<T>)> {
+			Self::bids_for(collection, item).into_iter().next()
+		}
+
+		/// The keys of all the orders and auctions created by an account.
+		pub fn orders_by_account(who: &T::AccountId) -> Vec<OrderKeyOf<T>> {
+			AccountOrders::<T>::get(who).into_inner()
+		}
+
+		/// The trading statistics of a collection in an asset, the native currency if `None`.
+		pub fn collection_stats(
+			collection: T::CollectionId,
+			asset: Option<AssetIdOf<T>>,
+		) -> Option<CollectionStatsOf<T>> {
+			TradeStats::<T>::get(collection, asset)
+		}
+
+		/// The most recent sales of a collection, from the oldest to the newest.
+		pub fn recent_sales(collection: T::CollectionId) -> Vec<SaleOf<T>> {
+			RecentSales::<T>::get(collection).into_inner()
+		}
+
+		/// Pays the marketplace fees, the royalties and the seller proceeds of a trade from the
+		/// funds held for the buyer. Returns the seller proceeds.
+		#[allow(clippy::too_many_arguments)]
 		pub fn process_fees(
 			seller: &T::AccountId,
 			seller_fee: BalanceOf<T>,
 			buyer: &T::AccountId,
 			buyer_fee: BalanceOf<T>,
 			price: BalanceOf<T>,
+			royalties: &[(T::AccountId, BalanceOf<T>)],
 			escrow_agent: Option<T::AccountId>,
-		) -> Result<(), DispatchError> {
+			asset: &Option<AssetIdOf<T>>,
+		) -> Result<BalanceOf<T>, DispatchError> {
 			// Amount to be paid by the buyer
 			let buyer_payment_amount = price.checked_add(&buyer_fee).ok_or(Error::<T>::Overflow)?;
 
@@ -658,58 +2785,468 @@ pub mod pallet {
 			let marketplace_pay_amount =
 				buyer_fee.checked_add(&seller_fee).ok_or(Error::<T>::Overflow)?;
 
-			// Amount to be paid to the seller (Earings - marketFees)
+			// Amount to be paid to the collection creators
+			let royalty_amount = royalties
+				.iter()
+				.try_fold(BalanceOf::<T>::zero(), |total, (_, amount)| total.checked_add(amount))
+				.ok_or(Error::<T>::Overflow)?;
+
+			// Amount to be paid to the seller (Earings - marketFees - royalty)
 			let seller_pay_amount = buyer_payment_amount
 				.checked_sub(&marketplace_pay_amount)
+				.and_then(|amount| amount.checked_sub(&royalty_amount))
 				.ok_or(Error::<T>::Overflow)?;
 
-			<T as Config>::Currency::release(
-				&HoldReason::MarketplaceBid.into(),
-				buyer,
-				buyer_payment_amount,
-				Exact,
-			)?;
+			Self::release_funds(asset, buyer, buyer_payment_amount)?;
 			// Pay fees to PayoutAddress
 			let payout_address =
 				PayoutAddress::<T>::get().ok_or(Error::<T>::PayoutAddressNotSet)?;
-			<T as Config>::Currency::transfer(
-				buyer,
-				&payout_address,
-				marketplace_pay_amount,
-				Preserve,
-			)?;
+			Self::transfer_funds(asset, buyer, &payout_address, marketplace_pay_amount, Preserve)?;
+			// Pay royalties to the collection creators
+			for (recipient, amount) in royalties.iter().filter(|(_, amount)| !amount.is_zero()) {
+				Self::transfer_funds(asset, buyer, recipient, *amount, Preserve)?;
+			}
 			//Pay earnings to seller
 			match escrow_agent {
 				Some(agent) => {
+					ensure!(asset.is_none(), Error::<T>::EscrowRequiresNativeCurrency);
 					T::Escrow::make_deposit(buyer, seller, seller_pay_amount, &agent)?;
 				},
 				None => {
-					<T as Config>::Currency::transfer(buyer, seller, seller_pay_amount, Preserve)?;
+					Self::transfer_funds(asset, buyer, seller, seller_pay_amount, Preserve)?;
+				},
+			}
+
+			Ok(seller_pay_amount)
+		}
+
+		/// Prunes the next used nonce whose deadline has passed.
+		/// Returns `false` if there are no due nonces left, `true` otherwise.
+		///
+		/// Like `expire_next_order`, this function either prunes a single nonce or moves the sweep
+		/// past an empty expiration slot.
+		pub fn prune_next_nonce() -> bool {
+			let Some(slot) = NextNonceExpirationSlot::<T>::get() else {
+				return false;
+			};
+
+			if slot > pallet_timestamp::Pallet::<T>::get() {
+				return false;
+			}
+
+			match NonceExpirations::<T>::iter_key_prefix(slot).next() {
+				Some(nonce) => {
+					NonceExpirations::<T>::remove(slot, &nonce);
+					Nonces::<T>::remove(&nonce);
+				},
+				// Stop sweeping once the index is empty, it restarts with the next nonce.
+				None if NonceExpirations::<T>::iter_keys().next().is_none() => {
+					NextNonceExpirationSlot::<T>::kill()
+				},
+				None => NextNonceExpirationSlot::<T>::put(
+					slot.saturating_add(T::ExpirationGranularity::get()),
+				),
+			}
+
+			true
+		}
+
+		/// Expires the next due order of the expiration index.
+		/// Returns `false` if there are no due orders left, `true` otherwise.
+		///
+		/// This function either expires a single order or moves the sweep past an empty
+		/// expiration slot, to avoid blocking the runtime and to ease calculation of the
+		/// consumed weight.
+		///
+		/// An order that can't be removed, such as an auction that can't be settled while
+		/// trading is paused, is moved to the slot following the current time to be retried.
+		pub fn expire_next_order() -> bool {
+			let Some(slot) = NextExpirationSlot::<T>::get() else {
+				return false;
+			};
+
+			let now = pallet_timestamp::Pallet::<T>::get();
+			if slot > now {
+				return false;
+			}
+
+			match OrderExpirations::<T>::iter_key_prefix(slot).next() {
+				Some(order) => {
+					OrderExpirations::<T>::remove(slot, &order);
+					if !Self::expire_order(order.clone(), now) {
+						Self::index_expiration(
+							now.saturating_add(T::ExpirationGranularity::get()),
+							order,
+						);
+					}
+				},
+				// Stop sweeping once the index is empty, it restarts with the next order.
+				None if OrderExpirations::<T>::iter_keys().next().is_none() => {
+					NextExpirationSlot::<T>::kill()
+				},
+				None => NextExpirationSlot::<T>::put(
+					slot.saturating_add(T::ExpirationGranularity::get()),
+				),
+			}
+
+			true
+		}
+
+		/// Removes an expired order, unlocking its item or releasing the funds held for it.
+		/// Ended auctions are settled.
+		///
+		/// Orders that are not stored or not expired are ignored. If the order can't be
+		/// removed it is left in storage so it can still be canceled, and `false` is returned.
+		pub(crate) fn expire_order(order: OrderKeyOf<T>, now: T::Moment) -> bool {
+			let result = storage::with_transaction(|| match Self::do_expire_order(&order, now) {
+				Ok(who) => TransactionOutcome::Commit(Ok(who)),
+				Err(e) => TransactionOutcome::Rollback(Err(e)),
+			});
+
+			match result {
+				Ok(Some(who)) => Self::deposit_event(Event::OrderExpired { order, who }),
+				Ok(None) => {},
+				Err(e) => {
+					log::warn!(
+						target: "runtime::marketplace",
+						"Failed to expire order {:?}: {:?}",
+						order,
+						e
+					);
+					return false;
+				},
+			}
+			true
+		}
+
+		fn do_expire_order(
+			order: &OrderKeyOf<T>,
+			now: T::Moment,
+		) -> Result<Option<T::AccountId>, DispatchError> {
+			match *order {
+				// Auctions are settled, which emits its own events.
+				OrderKey::Auction { collection, item } => {
+					match Auctions::<T>::get(collection, item) {
+						Some(auction) if auction.ends_at <= now => {
+							Self::settle(collection, item, auction)?;
+							Ok(None)
+						},
+						_ => Ok(None),
+					}
+				},
+				_ => match Self::order_info(order) {
+					Some((_, expiration)) if expiration <= now => {
+						Self::remove_order(order).map(Some)
+					},
+					_ => Ok(None),
+				},
+			}
+		}
+
+		/// The creator and expiration of a stored order, the end of an auction.
+		fn order_info(order: &OrderKeyOf<T>) -> Option<(T::AccountId, T::Moment)> {
+			match *order {
+				OrderKey::Ask { collection, item } => {
+					Asks::<T>::get(collection, item).map(|ask| (ask.seller, ask.expiration))
+				},
+				OrderKey::Bid { collection, item, price } => {
+					Bids::<T>::get((collection, item, price)).map(|bid| (bid.buyer, bid.expiration))
+				},
+				OrderKey::CollectionBid { collection, price, ref buyer } => {
+					CollectionBids::<T>::get((collection, price, buyer))
+						.map(|bid| (bid.buyer, bid.expiration))
+				},
+				OrderKey::Auction { collection, item } => Auctions::<T>::get(collection, item)
+					.map(|auction| (auction.seller, auction.ends_at)),
+				OrderKey::BundleAsk { bundle } => {
+					BundleAsks::<T>::get(bundle).map(|ask| (ask.seller, ask.expiration))
+				},
+				OrderKey::BundleBid { bundle, price } => {
+					BundleBids::<T>::get(bundle, price).map(|bid| (bid.buyer, bid.expiration))
 				},
 			}
+		}
+
+		/// Removes a stored order, other than an auction, from storage and from the indices,
+		/// unlocking its items or releasing the funds held for it.
+		///
+		/// Returns the creator of the order.
+		fn remove_order(order: &OrderKeyOf<T>) -> Result<T::AccountId, DispatchError> {
+			let (who, expiration) = match *order {
+				OrderKey::Ask { collection, item } => {
+					let ask = Asks::<T>::take(collection, item).ok_or(Error::<T>::OrderNotFound)?;
+					pallet_nfts::Pallet::<T>::enable_transfer(&collection, &item)?;
+					(ask.seller, ask.expiration)
+				},
+				OrderKey::Bid { collection, item, price } => {
+					let bid = Bids::<T>::take((collection, item, price))
+						.ok_or(Error::<T>::OrderNotFound)?;
+					let bid_payment = Self::calc_bid_payment(&price, &bid.fee)?;
+					Self::release_funds(&bid.asset, &bid.buyer, bid_payment)?;
+					(bid.buyer, bid.expiration)
+				},
+				OrderKey::CollectionBid { collection, price, ref buyer } => {
+					let bid = CollectionBids::<T>::take((collection, price, buyer))
+						.ok_or(Error::<T>::OrderNotFound)?;
+					let bid_payment =
+						Self::calc_collection_bid_payment(&price, bid.quantity, &bid.fee)?;
+					Self::release_funds(&bid.asset, &bid.buyer, bid_payment)?;
+					(bid.buyer, bid.expiration)
+				},
+				OrderKey::BundleAsk { bundle } => {
+					let ask = BundleAsks::<T>::take(bundle).ok_or(Error::<T>::OrderNotFound)?;
+					for (collection, item) in ask.items.iter() {
+						// Items burned since the ask was created are skipped.
+						if pallet_nfts::Pallet::<T>::owner(*collection, *item).is_some() {
+							pallet_nfts::Pallet::<T>::enable_transfer(collection, item)?;
+						}
+					}
+					(ask.seller, ask.expiration)
+				},
+				OrderKey::BundleBid { bundle, price } => {
+					let bid =
+						BundleBids::<T>::take(bundle, price).ok_or(Error::<T>::OrderNotFound)?;
+					let bid_payment = Self::calc_bid_payment(&price, &bid.fee)?;
+					Self::release_funds(&bid.asset, &bid.buyer, bid_payment)?;
+					(bid.buyer, bid.expiration)
+				},
+				OrderKey::Auction { .. } => return Err(Error::<T>::OrderNotFound.into()),
+			};
+
+			Self::unindex_order(&who, expiration, order);
+			Ok(who)
+		}
+
+		/// Cancels all the open orders of an account, returns the number of canceled orders and
+		/// the number of items unlocked by canceling bundle Asks.
+		///
+		/// Auctions can't be canceled and are kept. An order that can't be removed is skipped and
+		/// left open, so it doesn't prevent canceling the others.
+		fn cancel_orders_of(who: &T::AccountId) -> (u32, u32) {
+			let (mut count, mut items) = (0, 0);
+			for order in AccountOrders::<T>::get(who).iter() {
+				let order_items = match *order {
+					OrderKey::Auction { .. } => continue,
+					OrderKey::BundleAsk { bundle } => {
+						BundleAsks::<T>::get(bundle).map_or(0, |ask| ask.items.len() as u32)
+					},
+					_ => 0,
+				};
+
+				let result = storage::with_transaction(|| match Self::remove_order(order) {
+					Ok(_) => TransactionOutcome::Commit(Ok(())),
+					Err(e) => TransactionOutcome::Rollback(Err(e)),
+				});
+				match result {
+					Ok(()) => {
+						count += 1;
+						items += order_items;
+					},
+					Err(e) => log::warn!(
+						target: "runtime::marketplace",
+						"Failed to cancel order {:?}: {:?}",
+						order,
+						e
+					),
+				}
+			}
 
+			Self::deposit_event(Event::AllOrdersCanceled { who: who.clone(), count });
+			(count, items)
+		}
+
+		/// Adds a stored order to the expiration index and to the open orders of its creator.
+		fn index_order(
+			who: &T::AccountId,
+			expiration: T::Moment,
+			order: OrderKeyOf<T>,
+		) -> DispatchResult {
+			AccountOrders::<T>::try_mutate(who, |orders| {
+				orders.try_push(order.clone()).map_err(|_| Error::<T>::TooManyOrders)
+			})?;
+			Self::index_expiration(expiration, order);
 			Ok(())
 		}
 
+		/// Removes an order from the indices, see `index_order`.
+		fn unindex_order(who: &T::AccountId, expiration: T::Moment, order: &OrderKeyOf<T>) {
+			AccountOrders::<T>::mutate_exists(who, |orders| {
+				if let Some(list) = orders {
+					list.retain(|o| o != order);
+					if list.is_empty() {
+						*orders = None;
+					}
+				}
+			});
+			Self::unindex_expiration(expiration, order);
+		}
+
+		/// Returns the expiration slot of an order expiring at `expiration`.
+		pub fn expiration_slot(expiration: T::Moment) -> T::Moment {
+			let granularity = T::ExpirationGranularity::get();
+			let remainder = expiration % granularity;
+			if remainder.is_zero() {
+				expiration
+			} else {
+				expiration.saturating_sub(remainder).saturating_add(granularity)
+			}
+		}
+
+		pub(crate) fn index_expiration(expiration: T::Moment, order: OrderKeyOf<T>) {
+			let slot = Self::expiration_slot(expiration);
+			OrderExpirations::<T>::insert(slot, order, ());
+			NextExpirationSlot::<T>::mutate(|next| match next {
+				Some(next) if *next <= slot => {},
+				_ => *next = Some(slot),
+			});
+		}
+
+		fn unindex_expiration(expiration: T::Moment, order: &OrderKeyOf<T>) {
+			OrderExpirations::<T>::remove(Self::expiration_slot(expiration), order);
+		}
+
+		/// Returns the payload the fee signer has to sign for `order`.
+		fn signing_payload(order: &OrderOf<T>) -> Vec<u8> {
+			let message: OrderMessageOf<T> = order.clone().into();
+			let mut payload = match order.order_type {
+				OrderType::CollectionBid { quantity } => (message, quantity).encode(),
+				_ => message.encode(),
+			};
+			if let Some(ref asset) = order.asset {
+				asset.encode_to(&mut payload);
+			}
+			if order.escrow_settlement == EscrowSettlement::Marketplace {
+				order.escrow_settlement.encode_to(&mut payload);
+			}
+			payload
+		}
+
+		/// Holds `amount` of `asset`, or of the native currency if `asset` is `None`.
+		fn hold_funds(
+			asset: &Option<AssetIdOf<T>>,
+			who: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let reason: <T as Config>::RuntimeHoldReason = HoldReason::MarketplaceBid.into();
+			match asset {
+				Some(asset) => <T::Assets as fungibles::MutateHold<T::AccountId>>::hold(
+					asset.clone(),
+					&reason,
+					who,
+					amount,
+				),
+				None => <T as Config>::Currency::hold(&reason, who, amount),
+			}
+		}
+
+		/// Releases `amount` of `asset` held by `hold_funds`.
+		fn release_funds(
+			asset: &Option<AssetIdOf<T>>,
+			who: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let reason: <T as Config>::RuntimeHoldReason = HoldReason::MarketplaceBid.into();
+			match asset {
+				Some(asset) => <T::Assets as fungibles::MutateHold<T::AccountId>>::release(
+					asset.clone(),
+					&reason,
+					who,
+					amount,
+					Exact,
+				),
+				None => <T as Config>::Currency::release(&reason, who, amount, Exact),
+			}
+		}
+
+		/// Transfers `amount` of `asset`, or of the native currency if `asset` is `None`.
+		fn transfer_funds(
+			asset: &Option<AssetIdOf<T>>,
+			source: &T::AccountId,
+			dest: &T::AccountId,
+			amount: BalanceOf<T>,
+			preservation: Preservation,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			match asset {
+				Some(asset) => <T::Assets as fungibles::Mutate<T::AccountId>>::transfer(
+					asset.clone(),
+					source,
+					dest,
+					amount,
+					preservation,
+				),
+				None => <T as Config>::Currency::transfer(source, dest, amount, preservation),
+			}
+		}
+
+		/// Verifies the signature of a message by one of the fee signers, and returns the
+		/// approving fee signer.
+		///
+		/// The fee signer must be active and allowed to approve `fee` on an order of `price`.
 		fn verify_fee_signer_signature(
 			message: &Vec<u8>,
-			signature_data: SignatureData<T::Signature, Vec<u8>>,
-		) -> Result<(), DispatchError> {
+			signature_data: SignatureData<T::Signature, Vec<u8>, T::Moment>,
+			fee: BalanceOf<T>,
+			price: BalanceOf<T>,
+		) -> Result<T::AccountId, DispatchError> {
 			let nonce: BoundedVec<u8, T::NonceStringLimit> =
 				signature_data.nonce.try_into().map_err(|_| Error::<T>::BadNonce)?;
 
+			let now = pallet_timestamp::Pallet::<T>::get();
+			let deadline = signature_data.deadline;
+			ensure!(deadline > now, Error::<T>::NonceExpired);
+			ensure!(
+				deadline <= now.saturating_add(T::MaxNonceLifetime::get()),
+				Error::<T>::NonceDeadlineTooFar
+			);
 			ensure!(!Nonces::<T>::contains_key(nonce.clone()), Error::<T>::AlreadyUsedNonce);
 
-			let signer = FeeSigner::<T>::get().ok_or(Error::<T>::FeeSignerAddressNotSet)?;
+			let fee_signers = FeeSigners::<T>::get();
+			ensure!(!fee_signers.is_empty(), Error::<T>::FeeSignerAddressNotSet);
+
+			let mut payload = message.clone();
+			deadline.encode_to(&mut payload);
+			let (signer, info) = fee_signers
+				.into_iter()
+				.find(|(signer, _)| signature_data.signature.verify(payload.as_ref(), signer))
+				.ok_or(Error::<T>::BadSignedMessage)?;
 
-			if !signature_data.signature.verify(message.as_ref(), &signer) {
-				return Err(Error::<T>::BadSignedMessage.into());
+			ensure!(info.is_active(&now), Error::<T>::FeeSignerNotActive);
+			if let Some(max_fee) = info.max_fee {
+				ensure!(fee <= max_fee.mul_floor(price), Error::<T>::FeeAboveSignerLimit);
 			}
 
-			Nonces::<T>::set(nonce, true);
-			Ok(())
+			let slot = Self::expiration_slot(deadline);
+			Nonces::<T>::insert(&nonce, deadline);
+			NonceExpirations::<T>::insert(slot, nonce, ());
+			NextNonceExpirationSlot::<T>::mutate(|next| match next {
+				Some(next) if *next <= slot => {},
+				_ => *next = Some(slot),
+			});
+			Ok(signer)
 		}
 	}
 }
 
 sp_core::generate_feature_enabled_macro!(runtime_benchmarks_enabled, feature = "runtime-benchmarks", $);
+
+use pallet_nfts::ItemId;
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+sp_api::decl_runtime_apis! {
+	pub trait MarketplaceApi<AccountId, Balance, Moment, CollectionId, AssetId>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		Moment: Codec,
+		CollectionId: Codec,
+		AssetId: Codec,
+	{
+		fn ask_for(collection: CollectionId, item: ItemId) -> Option<Ask<AccountId, Balance, Moment, AccountId, AssetId>>;
+		fn bids_for(collection: CollectionId, item: ItemId) -> Vec<(Balance, Bid<AccountId, Balance, Moment, AssetId>)>;
+		fn best_bid(collection: CollectionId, item: ItemId) -> Option<(Balance, Bid<AccountId, Balance, Moment, AssetId>)>;
+		fn orders_by_account(who: AccountId) -> Vec<OrderKey<CollectionId, ItemId, Balance, AccountId>>;
+		fn bid_payment(price: Balance, fee: Balance) -> Option<Balance>;
+		fn collection_stats(collection: CollectionId, asset: Option<AssetId>) -> Option<CollectionStats<Balance>>;
+		fn recent_sales(collection: CollectionId) -> Vec<Sale<AccountId, ItemId, Balance, Moment, AssetId>>;
+	}
+}
@@ -0,0 +1,566 @@
+//! Storage migrations for the marketplace pallet.
+
+use crate::*;
+use frame_support::{
+	migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+};
+
+pub mod v1 {
+	use super::*;
+	use frame_support::storage_alias;
+
+	mod v0 {
+		use super::*;
+
+		#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+		pub struct Ask<AccountId, Amount, Expiration, Agent> {
+			pub seller: AccountId,
+			pub price: Amount,
+			pub expiration: Expiration,
+			pub fee: Amount,
+			pub escrow_agent: Option<Agent>,
+		}
+
+		#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+		pub struct Bid<AccountId, Amount, Expiration> {
+			pub buyer: AccountId,
+			pub expiration: Expiration,
+			pub fee: Amount,
+		}
+	}
+
+	/// The Ask as stored in version 1.
+	#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+	pub struct Ask<AccountId, Amount, Expiration, Agent, AssetId> {
+		pub seller: AccountId,
+		pub price: Amount,
+		pub expiration: Expiration,
+		pub fee: Amount,
+		pub escrow_agent: Option<Agent>,
+		pub asset: Option<AssetId>,
+	}
+
+	#[storage_alias]
+	type Asks<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as pallet_nfts::Config>::CollectionId,
+		Blake2_128Concat,
+		pallet_nfts::ItemId,
+		Ask<
+			<T as frame_system::Config>::AccountId,
+			BalanceOf<T>,
+			<T as pallet_timestamp::Config>::Moment,
+			<T as frame_system::Config>::AccountId,
+			AssetIdOf<T>,
+		>,
+	>;
+
+	/// Sets the asset of every stored ask and bid to the native currency.
+	pub struct UncheckedMigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+
+			Asks::<T>::translate::<v0::Ask<T::AccountId, BalanceOf<T>, T::Moment, T::AccountId>, _>(
+				|_, _, ask| {
+					translated += 1;
+					Some(Ask {
+						seller: ask.seller,
+						price: ask.price,
+						expiration: ask.expiration,
+						fee: ask.fee,
+						escrow_agent: ask.escrow_agent,
+						asset: None,
+					})
+				},
+			);
+
+			Bids::<T>::translate::<v0::Bid<T::AccountId, BalanceOf<T>, T::Moment>, _>(|_, bid| {
+				translated += 1;
+				Some(Bid {
+					buyer: bid.buyer,
+					expiration: bid.expiration,
+					fee: bid.fee,
+					asset: None,
+				})
+			});
+
+			log::info!(target: "runtime::marketplace", "Migrated {} orders to v1", translated);
+
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+	}
+
+	/// Migrates the marketplace storage from version 0 to version 1.
+	pub type MigrateToV1<T> = VersionedMigration<
+		0,
+		1,
+		UncheckedMigrateToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+pub mod v2 {
+	use super::*;
+	use frame_support::{
+		migrations::{SteppedMigration, SteppedMigrationError},
+		weights::WeightMeter,
+	};
+	use sp_std::vec::Vec;
+
+	/// Bumps the storage version once the open orders are indexed by account.
+	///
+	/// The orders stored before the index existed are indexed by `LazyIndexAccountOrders` over
+	/// several blocks.
+	pub struct UncheckedMigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			Weight::zero()
+		}
+	}
+
+	/// Builds the index of the open orders of every account.
+	///
+	/// Orders of accounts that exceed `MaxOrdersPerAccount` are left out of the index, they can
+	/// still be canceled one by one. Orders already in the index are not indexed twice.
+	///
+	/// Runs over several blocks, the cursor being the last indexed order. As multi-block
+	/// migrations only start once every single-block migration is applied, the Asks are read in
+	/// their current layout.
+	pub struct LazyIndexAccountOrders<T>(PhantomData<T>);
+
+	impl<T: Config> SteppedMigration for LazyIndexAccountOrders<T> {
+		type Cursor = OrderKeyOf<T>;
+		type Identifier = [u8; 27];
+
+		fn id() -> Self::Identifier {
+			*b"marketplace::account-orders"
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			// Every order and the orders of its account are read, and the latter written.
+			let required = T::DbWeight::get().reads_writes(2, 1);
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+
+			while meter.try_consume(required).is_ok() {
+				let next = match cursor {
+					None => next_ask::<T>(None)
+						.or_else(|| next_bid::<T>(None))
+						.or_else(|| next_collection_bid::<T>(None)),
+					Some(OrderKey::Ask { collection, item }) => {
+						next_ask::<T>(Some(Asks::<T>::hashed_key_for(collection, item)))
+							.or_else(|| next_bid::<T>(None))
+							.or_else(|| next_collection_bid::<T>(None))
+					},
+					Some(OrderKey::Bid { collection, item, price }) => {
+						next_bid::<T>(Some(Bids::<T>::hashed_key_for((collection, item, price))))
+							.or_else(|| next_collection_bid::<T>(None))
+					},
+					Some(OrderKey::CollectionBid { collection, price, buyer }) => {
+						next_collection_bid::<T>(Some(CollectionBids::<T>::hashed_key_for((
+							collection, price, buyer,
+						))))
+					},
+					Some(_) => return Err(SteppedMigrationError::InvalidCursor),
+				};
+
+				let Some((who, order)) = next else {
+					log::info!(target: "runtime::marketplace", "Indexed the account orders");
+					return Ok(None);
+				};
+				AccountOrders::<T>::mutate(&who, |orders| {
+					if !orders.contains(&order) && orders.try_push(order.clone()).is_err() {
+						log::warn!(
+							target: "runtime::marketplace",
+							"Order {:?} of {:?} left out of the account index",
+							order,
+							who
+						);
+					}
+				});
+				cursor = Some(order);
+			}
+
+			Ok(cursor)
+		}
+	}
+
+	/// The Ask stored after the raw key `from`, or the first one, with its seller.
+	fn next_ask<T: Config>(from: Option<Vec<u8>>) -> Option<(T::AccountId, OrderKeyOf<T>)> {
+		let mut asks = match from {
+			Some(key) => Asks::<T>::iter_from(key),
+			None => Asks::<T>::iter(),
+		};
+		asks.next()
+			.map(|(collection, item, ask)| (ask.seller, OrderKey::Ask { collection, item }))
+	}
+
+	/// The Bid stored after the raw key `from`, or the first one, with its buyer.
+	fn next_bid<T: Config>(from: Option<Vec<u8>>) -> Option<(T::AccountId, OrderKeyOf<T>)> {
+		let mut bids = match from {
+			Some(key) => Bids::<T>::iter_from(key),
+			None => Bids::<T>::iter(),
+		};
+		bids.next().map(|((collection, item, price), bid)| {
+			(bid.buyer, OrderKey::Bid { collection, item, price })
+		})
+	}
+
+	/// The Collection Bid stored after the raw key `from`, or the first one, with its buyer.
+	fn next_collection_bid<T: Config>(
+		from: Option<Vec<u8>>,
+	) -> Option<(T::AccountId, OrderKeyOf<T>)> {
+		let mut collection_bids = match from {
+			Some(key) => CollectionBids::<T>::iter_keys_from(key),
+			None => CollectionBids::<T>::iter_keys(),
+		};
+		collection_bids.next().map(|(collection, price, buyer)| {
+			(buyer.clone(), OrderKey::CollectionBid { collection, price, buyer })
+		})
+	}
+
+	/// Migrates the marketplace storage from version 1 to version 2.
+	pub type MigrateToV2<T> = VersionedMigration<
+		1,
+		2,
+		UncheckedMigrateToV2<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+pub mod v3 {
+	use super::*;
+	use frame_support::{
+		migrations::{SteppedMigration, SteppedMigrationError},
+		weights::WeightMeter,
+	};
+
+	/// Bumps the storage version once the nonces are stored with their deadline.
+	///
+	/// The used nonces stored without a deadline are removed by `LazyClearLegacyNonces` over
+	/// several blocks.
+	pub struct UncheckedMigrateToV3<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			Weight::zero()
+		}
+	}
+
+	/// Removes the used nonces stored without a deadline.
+	///
+	/// The fee signer now signs the nonce deadline along with the message, so signatures made
+	/// before the upgrade no longer verify and the nonces they used are not needed anymore.
+	/// The nonces used since the upgrade are kept.
+	///
+	/// Runs over several blocks, the cursor being the last visited nonce.
+	pub struct LazyClearLegacyNonces<T>(PhantomData<T>);
+
+	impl<T: Config> SteppedMigration for LazyClearLegacyNonces<T> {
+		type Cursor = BoundedVec<u8, T::NonceStringLimit>;
+		type Identifier = [u8; 26];
+
+		fn id() -> Self::Identifier {
+			*b"marketplace::legacy-nonces"
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			let required = T::DbWeight::get().reads_writes(1, 1);
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+
+			while meter.try_consume(required).is_ok() {
+				let mut nonces = match cursor {
+					Some(ref last) => {
+						Nonces::<T>::iter_keys_from(Nonces::<T>::hashed_key_for(last))
+					},
+					None => Nonces::<T>::iter_keys(),
+				};
+				let Some(nonce) = nonces.next() else {
+					log::info!(target: "runtime::marketplace", "Removed the legacy nonces");
+					return Ok(None);
+				};
+
+				// The legacy nonces were stored with a boolean, which is not a valid deadline.
+				if Nonces::<T>::try_get(&nonce).is_err() {
+					Nonces::<T>::remove(&nonce);
+				}
+				cursor = Some(nonce);
+			}
+
+			Ok(cursor)
+		}
+	}
+
+	/// Migrates the marketplace storage from version 2 to version 3.
+	pub type MigrateToV3<T> = VersionedMigration<
+		2,
+		3,
+		UncheckedMigrateToV3<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+pub mod v4 {
+	use super::*;
+	use frame_support::storage_alias;
+
+	mod v3 {
+		use super::*;
+
+		#[storage_alias]
+		pub type FeeSigner<T: Config> =
+			StorageValue<Pallet<T>, <T as frame_system::Config>::AccountId, OptionQuery>;
+	}
+
+	/// Moves the fee signer to the set of fee signers, without validity window nor fee limit.
+	pub struct UncheckedMigrateToV4<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV4<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let Some(fee_signer) = v3::FeeSigner::<T>::take() else {
+				return T::DbWeight::get().reads(1);
+			};
+
+			FeeSigners::<T>::put(BoundedVec::truncate_from(sp_std::vec![(
+				fee_signer,
+				FeeSignerInfo::default()
+			)]));
+
+			log::info!(target: "runtime::marketplace", "Migrated the fee signer to v4");
+
+			T::DbWeight::get().reads_writes(1, 2)
+		}
+	}
+
+	/// Migrates the marketplace storage from version 3 to version 4.
+	pub type MigrateToV4<T> = VersionedMigration<
+		3,
+		4,
+		UncheckedMigrateToV4<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+pub mod v5 {
+	use super::*;
+	use frame_support::storage_alias;
+
+	mod v4 {
+		use super::*;
+
+		#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+		pub struct Ask<AccountId, Amount, Expiration, Agent, AssetId> {
+			pub seller: AccountId,
+			pub price: Amount,
+			pub expiration: Expiration,
+			pub fee: Amount,
+			pub escrow_agent: Option<Agent>,
+			pub asset: Option<AssetId>,
+		}
+	}
+
+	/// The Ask as stored in version 5.
+	#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+	pub struct Ask<AccountId, Amount, Expiration, Agent, AssetId> {
+		pub seller: AccountId,
+		pub price: Amount,
+		pub expiration: Expiration,
+		pub fee: Amount,
+		pub escrow_agent: Option<Agent>,
+		pub asset: Option<AssetId>,
+		pub buyer: Option<AccountId>,
+	}
+
+	#[storage_alias]
+	type Asks<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as pallet_nfts::Config>::CollectionId,
+		Blake2_128Concat,
+		pallet_nfts::ItemId,
+		Ask<
+			<T as frame_system::Config>::AccountId,
+			BalanceOf<T>,
+			<T as pallet_timestamp::Config>::Moment,
+			<T as frame_system::Config>::AccountId,
+			AssetIdOf<T>,
+		>,
+	>;
+
+	/// Opens every stored ask to any buyer.
+	pub struct UncheckedMigrateToV5<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV5<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+
+			Asks::<T>::translate::<
+				v4::Ask<T::AccountId, BalanceOf<T>, T::Moment, T::AccountId, AssetIdOf<T>>,
+				_,
+			>(|_, _, ask| {
+				translated += 1;
+				Some(Ask {
+					seller: ask.seller,
+					price: ask.price,
+					expiration: ask.expiration,
+					fee: ask.fee,
+					escrow_agent: ask.escrow_agent,
+					asset: ask.asset,
+					buyer: None,
+				})
+			});
+
+			log::info!(target: "runtime::marketplace", "Migrated {} asks to v5", translated);
+
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+	}
+
+	/// Migrates the marketplace storage from version 4 to version 5.
+	pub type MigrateToV5<T> = VersionedMigration<
+		4,
+		5,
+		UncheckedMigrateToV5<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+pub mod v6 {
+	use super::*;
+
+	/// Keeps the stored asks with an escrow agent on the settlement through the agent deposit.
+	pub struct UncheckedMigrateToV6<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV6<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+
+			Asks::<T>::translate::<
+				v5::Ask<T::AccountId, BalanceOf<T>, T::Moment, T::AccountId, AssetIdOf<T>>,
+				_,
+			>(|_, _, ask| {
+				translated += 1;
+				Some(Ask {
+					seller: ask.seller,
+					price: ask.price,
+					expiration: ask.expiration,
+					fee: ask.fee,
+					escrow_agent: ask.escrow_agent,
+					escrow_settlement: EscrowSettlement::Agent,
+					asset: ask.asset,
+					buyer: ask.buyer,
+				})
+			});
+
+			log::info!(target: "runtime::marketplace", "Migrated {} asks to v6", translated);
+
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+	}
+
+	/// Migrates the marketplace storage from version 5 to version 6.
+	pub type MigrateToV6<T> = VersionedMigration<
+		5,
+		6,
+		UncheckedMigrateToV6<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+pub mod expirations {
+	use super::*;
+	use frame_support::{
+		migrations::{SteppedMigration, SteppedMigrationError},
+		weights::WeightMeter,
+	};
+	use sp_std::vec::Vec;
+
+	/// Indexes the expiration of the Asks and Bids stored before the expiration index existed,
+	/// so they are removed by the `on_idle` hook once they expire.
+	///
+	/// Runs over several blocks, the cursor being the last indexed order.
+	pub struct LazyIndexOrderExpirations<T>(PhantomData<T>);
+
+	impl<T: Config> SteppedMigration for LazyIndexOrderExpirations<T> {
+		type Cursor = OrderKeyOf<T>;
+		type Identifier = [u8; 30];
+
+		fn id() -> Self::Identifier {
+			*b"marketplace::order-expirations"
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			// Every order is read and indexed, moving the sweep if needed.
+			let required = T::DbWeight::get().reads_writes(2, 2);
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+
+			while meter.try_consume(required).is_ok() {
+				let next = match cursor {
+					None => next_ask::<T>(None).or_else(|| next_bid::<T>(None)),
+					Some(OrderKey::Ask { collection, item }) => {
+						next_ask::<T>(Some(Asks::<T>::hashed_key_for(collection, item)))
+							.or_else(|| next_bid::<T>(None))
+					},
+					Some(OrderKey::Bid { collection, item, price }) => {
+						next_bid::<T>(Some(Bids::<T>::hashed_key_for((collection, item, price))))
+					},
+					Some(_) => return Err(SteppedMigrationError::InvalidCursor),
+				};
+
+				let Some((order, expiration)) = next else {
+					log::info!(target: "runtime::marketplace", "Indexed the order expirations");
+					return Ok(None);
+				};
+				Pallet::<T>::index_expiration(expiration, order.clone());
+				cursor = Some(order);
+			}
+
+			Ok(cursor)
+		}
+	}
+
+	/// The Ask stored after the raw key `from`, or the first one.
+	fn next_ask<T: Config>(from: Option<Vec<u8>>) -> Option<(OrderKeyOf<T>, T::Moment)> {
+		let mut asks = match from {
+			Some(key) => Asks::<T>::iter_from(key),
+			None => Asks::<T>::iter(),
+		};
+		asks.next()
+			.map(|(collection, item, ask)| (OrderKey::Ask { collection, item }, ask.expiration))
+	}
+
+	/// The Bid stored after the raw key `from`, or the first one.
+	fn next_bid<T: Config>(from: Option<Vec<u8>>) -> Option<(OrderKeyOf<T>, T::Moment)> {
+		let mut bids = match from {
+			Some(key) => Bids::<T>::iter_from(key),
+			None => Bids::<T>::iter(),
+		};
+		bids.next().map(|((collection, item, price), bid)| {
+			(OrderKey::Bid { collection, item, price }, bid.expiration)
+		})
+	}
+}
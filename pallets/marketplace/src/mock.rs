@@ -1,17 +1,20 @@
 use frame_support::{
-	derive_impl,
+	Blake2_128Concat, derive_impl, ensure,
 	pallet_prelude::DispatchResult,
 	parameter_types,
 	traits::{
 		AsEnsureOriginWithArg, ConstU32, ConstU64, ConstU128, NamedReservableCurrency,
-		fungible::Mutate,
+		StorageMapShim,
+		fungible::{self, Mutate},
+		fungibles,
+		tokens::{DepositConsequence, Fortitude, Preservation, Provenance, WithdrawConsequence},
 	},
 };
 use frame_system as system;
 use sp_core::H256;
 use sp_keystore::{KeystoreExt, testing::MemoryKeystore};
 use sp_runtime::{
-	BuildStorage,
+	BuildStorage, DispatchError, Perbill, TokenError,
 	traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
 };
 
@@ -26,6 +29,9 @@ type Block = frame_system::mocking::MockBlock<Test>;
 
 pub const ESCROW_RESERVE_NAME: &[u8; 8] = b"escrow__";
 
+/// The only asset besides the native currency orders can be paid in.
+pub const USDC: u32 = 1;
+
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
 	pub enum Test
@@ -33,6 +39,7 @@ frame_support::construct_runtime!(
 		System: frame_system,
 		Marketplace: pallet_marketplace,
 		Balances: pallet_balances,
+		AssetBalances: pallet_balances::<Instance2>,
 		Timestamp: pallet_timestamp,
 		Nfts: pallet_nfts,
 	}
@@ -107,12 +114,24 @@ pub struct EscrowMock {
 	pub deposit: u128,
 }
 
+/// The deposits held by `EscrowMock`, by address and escrow agent.
+#[frame_support::storage_alias]
+pub type EscrowDeposits = StorageDoubleMap<
+	EscrowMock,
+	Blake2_128Concat,
+	AccountId,
+	Blake2_128Concat,
+	AccountId,
+	u128,
+	ValueQuery,
+>;
+
 impl pallet_marketplace::Escrow<AccountId, u128, AccountId> for EscrowMock {
 	fn make_deposit(
 		depositor: &AccountId,
 		destination: &AccountId,
 		value: u128,
-		_escrow_agent: &AccountId,
+		escrow_agent: &AccountId,
 	) -> DispatchResult {
 		Balances::transfer(
 			depositor,
@@ -122,19 +141,62 @@ impl pallet_marketplace::Escrow<AccountId, u128, AccountId> for EscrowMock {
 		)?;
 
 		Balances::reserve_named(ESCROW_RESERVE_NAME, destination, value)?;
+		EscrowDeposits::mutate(destination, escrow_agent, |deposit| *deposit += value);
+
+		Ok(())
+	}
+
+	fn release_deposit(
+		address: &AccountId,
+		value: u128,
+		escrow_agent: &AccountId,
+	) -> DispatchResult {
+		EscrowDeposits::try_mutate(address, escrow_agent, |deposit| -> DispatchResult {
+			*deposit = deposit.checked_sub(value).ok_or(DispatchError::Other("Deposit too low"))?;
+			Ok(())
+		})?;
+		let missing = Balances::unreserve_named(ESCROW_RESERVE_NAME, address, value);
+		ensure!(missing == 0, DispatchError::Other("Deposit too low"));
+
+		Ok(())
+	}
+
+	fn revoke_deposit(
+		address: &AccountId,
+		destination: &AccountId,
+		escrow_agent: &AccountId,
+	) -> DispatchResult {
+		let value = EscrowDeposits::take(address, escrow_agent);
+		ensure!(value > 0, DispatchError::Other("No such deposit"));
+		let missing = Balances::unreserve_named(ESCROW_RESERVE_NAME, address, value);
+		ensure!(missing == 0, DispatchError::Other("Deposit too low"));
+		Balances::transfer(address, destination, value, Preservation::Expendable)?;
 
 		Ok(())
 	}
 }
 
+parameter_types! {
+	pub const MaxRoyalty: Perbill = Perbill::from_percent(50);
+}
+
 impl pallet_marketplace::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type Currency = Balances;
+	type Assets = TestAssets;
 	type Escrow = EscrowMock;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type MinOrderDuration = ConstU64<10>;
+	type ExpirationGranularity = ConstU64<10>;
+	type MaxRoyalty = MaxRoyalty;
+	type MaxOrdersPerAccount = ConstU32<10>;
+	type MaxBundleItems = ConstU32<5>;
+	type MaxFeeSigners = ConstU32<3>;
+	type MaxRecentSales = ConstU32<3>;
+	type EscrowTimeout = ConstU64<100>;
 	type NonceStringLimit = ConstU32<50>;
+	type MaxNonceLifetime = ConstU64<1_000_000>;
 	type Signature = Signature;
 	type Signer = <Signature as Verify>::Signer;
 	type WeightInfo = ();
@@ -160,6 +222,150 @@ impl pallet_balances::Config for Test {
 	type DoneSlashHandler = ();
 }
 
+impl pallet_balances::Config<pallet_balances::Instance2> for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u128;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = StorageMapShim<
+		pallet_balances::Account<Test, pallet_balances::Instance2>,
+		AccountId,
+		pallet_balances::AccountData<u128>,
+	>;
+	type WeightInfo = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type DoneSlashHandler = ();
+}
+
+/// Exposes `AssetBalances` as the `USDC` asset.
+pub struct TestAssets;
+
+impl fungibles::Inspect<AccountId> for TestAssets {
+	type AssetId = u32;
+	type Balance = u128;
+
+	fn total_issuance(asset: u32) -> u128 {
+		if asset == USDC { <AssetBalances as fungible::Inspect<_>>::total_issuance() } else { 0 }
+	}
+
+	fn minimum_balance(asset: u32) -> u128 {
+		if asset == USDC { <AssetBalances as fungible::Inspect<_>>::minimum_balance() } else { 0 }
+	}
+
+	fn total_balance(asset: u32, who: &AccountId) -> u128 {
+		if asset == USDC { <AssetBalances as fungible::Inspect<_>>::total_balance(who) } else { 0 }
+	}
+
+	fn balance(asset: u32, who: &AccountId) -> u128 {
+		if asset == USDC { <AssetBalances as fungible::Inspect<_>>::balance(who) } else { 0 }
+	}
+
+	fn reducible_balance(
+		asset: u32,
+		who: &AccountId,
+		preservation: Preservation,
+		force: Fortitude,
+	) -> u128 {
+		if asset == USDC {
+			<AssetBalances as fungible::Inspect<_>>::reducible_balance(who, preservation, force)
+		} else {
+			0
+		}
+	}
+
+	fn can_deposit(
+		asset: u32,
+		who: &AccountId,
+		amount: u128,
+		provenance: Provenance,
+	) -> DepositConsequence {
+		if asset == USDC {
+			<AssetBalances as fungible::Inspect<_>>::can_deposit(who, amount, provenance)
+		} else {
+			DepositConsequence::UnknownAsset
+		}
+	}
+
+	fn can_withdraw(asset: u32, who: &AccountId, amount: u128) -> WithdrawConsequence<u128> {
+		if asset == USDC {
+			<AssetBalances as fungible::Inspect<_>>::can_withdraw(who, amount)
+		} else {
+			WithdrawConsequence::UnknownAsset
+		}
+	}
+
+	fn asset_exists(asset: u32) -> bool {
+		asset == USDC
+	}
+}
+
+impl fungibles::Unbalanced<AccountId> for TestAssets {
+	fn handle_dust(dust: fungibles::Dust<AccountId, Self>) {
+		<AssetBalances as fungible::Unbalanced<_>>::handle_dust(fungible::Dust(dust.1))
+	}
+
+	fn write_balance(
+		asset: u32,
+		who: &AccountId,
+		amount: u128,
+	) -> Result<Option<u128>, DispatchError> {
+		if asset != USDC {
+			return Err(TokenError::UnknownAsset.into());
+		}
+		<AssetBalances as fungible::Unbalanced<_>>::write_balance(who, amount)
+	}
+
+	fn set_total_issuance(asset: u32, amount: u128) {
+		if asset == USDC {
+			<AssetBalances as fungible::Unbalanced<_>>::set_total_issuance(amount)
+		}
+	}
+}
+
+impl fungibles::Mutate<AccountId> for TestAssets {}
+
+impl fungibles::InspectHold<AccountId> for TestAssets {
+	type Reason = RuntimeHoldReason;
+
+	fn total_balance_on_hold(asset: u32, who: &AccountId) -> u128 {
+		if asset == USDC {
+			<AssetBalances as fungible::InspectHold<_>>::total_balance_on_hold(who)
+		} else {
+			0
+		}
+	}
+
+	fn balance_on_hold(asset: u32, reason: &RuntimeHoldReason, who: &AccountId) -> u128 {
+		if asset == USDC {
+			<AssetBalances as fungible::InspectHold<_>>::balance_on_hold(reason, who)
+		} else {
+			0
+		}
+	}
+}
+
+impl fungibles::UnbalancedHold<AccountId> for TestAssets {
+	fn set_balance_on_hold(
+		asset: u32,
+		reason: &RuntimeHoldReason,
+		who: &AccountId,
+		amount: u128,
+	) -> DispatchResult {
+		if asset != USDC {
+			return Err(TokenError::UnknownAsset.into());
+		}
+		<AssetBalances as fungible::UnbalancedHold<_>>::set_balance_on_hold(reason, who, amount)
+	}
+}
+
+impl fungibles::MutateHold<AccountId> for TestAssets {}
+
 impl pallet_timestamp::Config for Test {
 	type Moment = u64;
 	type OnTimestampSet = ();
@@ -36,6 +36,9 @@ type MessageOf<Test> = OrderMessage<
 	Vec<u8>,
 >;
 
+/// The deadline of the nonces used in the tests, past the end of all of them.
+const NONCE_DEADLINE: Moment<Test> = 1_000_000;
+
 fn account(id: u8) -> AccountIdOf<Test> {
 	[id; 20].into()
 }
@@ -67,20 +70,20 @@ fn collection_config_with_all_settings_enabled()
 	}
 }
 
-fn append_valid_signature(
-	fee_signer_pair: KeyPair,
-	order: &mut Order<
-		CollectionId<Test>,
-		ItemId,
-		BalanceOf<Test>,
-		Moment<Test>,
-		OffchainSignature<Test>,
-		AccountIdOf<Test>,
-		Vec<u8>,
-	>,
-) {
+fn append_valid_signature(fee_signer_pair: KeyPair, order: &mut OrderOf<Test>) {
 	let message: MessageOf<Test> = order.clone().into();
-	let hashed = keccak_256(&message.encode());
+	let mut payload = match order.order_type {
+		OrderType::CollectionBid { quantity } => (message, quantity).encode(),
+		_ => message.encode(),
+	};
+	if let Some(asset) = order.asset {
+		asset.encode_to(&mut payload);
+	}
+	if order.escrow_settlement == EscrowSettlement::Marketplace {
+		order.escrow_settlement.encode_to(&mut payload);
+	}
+	order.signature_data.deadline.encode_to(&mut payload);
+	let hashed = keccak_256(&payload);
 
 	let signature = EthereumSignature::from(fee_signer_pair.sign_prehashed(&hashed));
 	order.signature_data.signature = signature;
@@ -123,10 +126,14 @@ pub fn create_valid_order(
 		price: 10000,
 		fee: 1,
 		escrow_agent: None,
+		escrow_settlement: EscrowSettlement::Agent,
+		asset: None,
 		signature_data: SignatureData {
 			signature: raw_signature([0; 65]),
 			nonce: <Vec<u8>>::new(),
+			deadline: NONCE_DEADLINE,
 		},
+		buyer: None,
 	};
 	append_valid_signature(fee_signer_pair, &mut order);
 
@@ -185,7 +192,10 @@ mod set_fee_signer {
 				RuntimeOrigin::signed(account(1)),
 				account(2)
 			));
-			assert_eq!(FeeSigner::<Test>::get(), Some(account(2)));
+			assert_eq!(
+				FeeSigners::<Test>::get().into_inner(),
+				vec![(account(2), FeeSignerInfo::default())]
+			);
 		})
 	}
 
@@ -298,10 +308,14 @@ mod create_order_initial_checks {
 				price: 1,
 				fee: 1,
 				escrow_agent: None,
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
 				signature_data: SignatureData {
 					signature: raw_signature([0; 65]),
 					nonce: <Vec<u8>>::new(),
+					deadline: NONCE_DEADLINE,
 				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair, &mut order);
 
@@ -333,10 +347,14 @@ mod create_order_initial_checks {
 				price: 10000,
 				fee: 1,
 				escrow_agent: None,
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
 				signature_data: SignatureData {
 					signature: raw_signature([0; 65]),
 					nonce: <Vec<u8>>::new(),
+					deadline: NONCE_DEADLINE,
 				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair, &mut order);
 
@@ -367,10 +385,14 @@ mod create_order_initial_checks {
 				price: 1,
 				fee: 1,
 				escrow_agent: None,
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
 				signature_data: SignatureData {
 					signature: raw_signature([0; 65]),
 					nonce: <Vec<u8>>::new(),
+					deadline: NONCE_DEADLINE,
 				},
+				buyer: None,
 			};
 
 			assert_noop!(
@@ -388,7 +410,7 @@ mod create_order_initial_checks {
 	fn fee_signer_nonce_already_used() {
 		new_test_ext().execute_with(|| {
 			let nonce: BoundedVec<u8, ConstU32<50>> = vec![0u8].try_into().unwrap();
-			Nonces::<Test>::set(nonce.clone(), true);
+			Nonces::<Test>::insert(nonce.clone(), NONCE_DEADLINE);
 
 			let (_, fee_signer_pair) = admin_accounts_setup();
 
@@ -403,10 +425,14 @@ mod create_order_initial_checks {
 				price: 10000,
 				fee: 1,
 				escrow_agent: None,
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
 				signature_data: SignatureData {
 					signature: raw_signature([0; 65]),
 					nonce: vec![0u8],
+					deadline: NONCE_DEADLINE,
 				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair, &mut order);
 
@@ -441,10 +467,14 @@ mod create_ask {
 				price: 10000,
 				fee: 1,
 				escrow_agent: None,
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
 				signature_data: SignatureData {
 					signature: raw_signature([0; 65]),
 					nonce: <Vec<u8>>::new(),
+					deadline: NONCE_DEADLINE,
 				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair, &mut order);
 
@@ -476,10 +506,14 @@ mod create_ask {
 				price: 10000,
 				fee: 1,
 				escrow_agent: None,
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
 				signature_data: SignatureData {
 					signature: raw_signature([0; 65]),
 					nonce: <Vec<u8>>::new(),
+					deadline: NONCE_DEADLINE,
 				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair, &mut order);
 
@@ -510,10 +544,14 @@ mod create_ask {
 				price: 10000,
 				fee: 1,
 				escrow_agent: None,
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
 				signature_data: SignatureData {
 					signature: raw_signature([0; 65]),
 					nonce: <Vec<u8>>::new(),
+					deadline: NONCE_DEADLINE,
 				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair, &mut order);
 
@@ -529,6 +567,9 @@ mod create_ask {
 				expiration: order.expires_at,
 				fee: order.fee,
 				escrow_agent: order.escrow_agent,
+				escrow_settlement: order.escrow_settlement,
+				asset: None,
+				buyer: None,
 			};
 
 			assert_eq!(Asks::<Test>::get(0, 1), Some(ask));
@@ -552,10 +593,14 @@ mod create_ask {
 				price: 10000,
 				fee: 1,
 				escrow_agent: None,
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
 				signature_data: SignatureData {
 					signature: raw_signature([0; 65]),
 					nonce: <Vec<u8>>::new(),
+					deadline: NONCE_DEADLINE,
 				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair, &mut order);
 
@@ -587,7 +632,14 @@ mod create_ask {
 				price: 10000,
 				fee: 1,
 				escrow_agent: None,
-				signature_data: SignatureData { signature: raw_signature([0; 65]), nonce: vec![1] },
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
+				signature_data: SignatureData {
+					signature: raw_signature([0; 65]),
+					nonce: vec![1],
+					deadline: NONCE_DEADLINE,
+				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair, &mut order);
 
@@ -626,10 +678,14 @@ mod create_bid {
 				price: 10000,
 				fee: 1,
 				escrow_agent: None,
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
 				signature_data: SignatureData {
 					signature: raw_signature([0; 65]),
 					nonce: <Vec<u8>>::new(),
+					deadline: NONCE_DEADLINE,
 				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair, &mut order);
 
@@ -639,7 +695,12 @@ mod create_bid {
 				Execution::AllowCreation
 			));
 
-			let bid = Bid { buyer: account(1), expiration: order.expires_at, fee: order.fee };
+			let bid = Bid {
+				buyer: account(1),
+				expiration: order.expires_at,
+				fee: order.fee,
+				asset: None,
+			};
 			assert_eq!(
 				Some(
 					Balances::balance_on_hold(&HoldReason::MarketplaceBid.into(), &account(1))
@@ -669,10 +730,14 @@ mod create_bid {
 				price: 10000,
 				fee: 1,
 				escrow_agent: None,
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
 				signature_data: SignatureData {
 					signature: raw_signature([0; 65]),
 					nonce: <Vec<u8>>::new(),
+					deadline: NONCE_DEADLINE,
 				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair, &mut order);
 
@@ -704,10 +769,14 @@ mod create_bid {
 				price: 10000,
 				fee: 1,
 				escrow_agent: None,
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
 				signature_data: SignatureData {
 					signature: raw_signature([0; 65]),
 					nonce: <Vec<u8>>::new(),
+					deadline: NONCE_DEADLINE,
 				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair, &mut order);
 
@@ -739,10 +808,14 @@ mod create_bid {
 				price: 10000,
 				fee: 1,
 				escrow_agent: None,
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
 				signature_data: SignatureData {
 					signature: raw_signature([0; 65]),
 					nonce: <Vec<u8>>::new(),
+					deadline: NONCE_DEADLINE,
 				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair.clone(), &mut order);
 
@@ -795,10 +868,14 @@ mod create_bid {
 				price: 10000000000,
 				fee: 1,
 				escrow_agent: None,
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
 				signature_data: SignatureData {
 					signature: raw_signature([0; 65]),
 					nonce: <Vec<u8>>::new(),
+					deadline: NONCE_DEADLINE,
 				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair, &mut order);
 
@@ -836,7 +913,14 @@ mod create_bid {
 				price: ask_price,
 				fee: ask_fee,
 				escrow_agent: None,
-				signature_data: SignatureData { signature: raw_signature([0; 65]), nonce: vec![1] },
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
+				signature_data: SignatureData {
+					signature: raw_signature([0; 65]),
+					nonce: vec![1],
+					deadline: NONCE_DEADLINE,
+				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair.clone(), &mut order);
 
@@ -858,10 +942,14 @@ mod create_bid {
 				price: bid_price,
 				fee: bid_fee,
 				escrow_agent: None,
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
 				signature_data: SignatureData {
 					signature: raw_signature([0; 65]),
 					nonce: <Vec<u8>>::new(),
+					deadline: NONCE_DEADLINE,
 				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair, &mut bid);
 
@@ -871,7 +959,7 @@ mod create_bid {
 				Execution::AllowCreation
 			));
 
-			let stored_bid = Bid { buyer, expiration: expires_at, fee: bid_fee };
+			let stored_bid = Bid { buyer, expiration: expires_at, fee: bid_fee, asset: None };
 			assert_eq!(Bids::<Test>::get((0, 1, bid_price)), Some(stored_bid));
 		})
 	}
@@ -903,10 +991,14 @@ mod execute_ask_with_existing_bid {
 				price,
 				fee: bid_fee,
 				escrow_agent: None,
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
 				signature_data: SignatureData {
 					signature: raw_signature([0; 65]),
 					nonce: <Vec<u8>>::new(),
+					deadline: NONCE_DEADLINE,
 				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair.clone(), &mut bid);
 
@@ -931,7 +1023,14 @@ mod execute_ask_with_existing_bid {
 				price,
 				fee: ask_fee,
 				escrow_agent: None,
-				signature_data: SignatureData { signature: raw_signature([0; 65]), nonce: vec![1] },
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
+				signature_data: SignatureData {
+					signature: raw_signature([0; 65]),
+					nonce: vec![1],
+					deadline: NONCE_DEADLINE,
+				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair, &mut order);
 
@@ -979,7 +1078,14 @@ mod execute_ask_with_existing_bid {
 				price: 10000,
 				fee: 1,
 				escrow_agent: None,
-				signature_data: SignatureData { signature: raw_signature([0; 65]), nonce: vec![1] },
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
+				signature_data: SignatureData {
+					signature: raw_signature([0; 65]),
+					nonce: vec![1],
+					deadline: NONCE_DEADLINE,
+				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair, &mut order);
 
@@ -1021,10 +1127,14 @@ mod execute_ask_with_existing_bid {
 				price,
 				fee: bid_fee,
 				escrow_agent: None,
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
 				signature_data: SignatureData {
 					signature: raw_signature([0; 65]),
 					nonce: <Vec<u8>>::new(),
+					deadline: NONCE_DEADLINE,
 				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair.clone(), &mut bid);
 
@@ -1043,7 +1153,14 @@ mod execute_ask_with_existing_bid {
 				price,
 				fee: ask_fee,
 				escrow_agent: None,
-				signature_data: SignatureData { signature: raw_signature([0; 65]), nonce: vec![1] },
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
+				signature_data: SignatureData {
+					signature: raw_signature([0; 65]),
+					nonce: vec![1],
+					deadline: NONCE_DEADLINE,
+				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair, &mut order);
 
@@ -1081,10 +1198,14 @@ mod execute_ask_with_existing_bid {
 				price,
 				fee: bid_fee,
 				escrow_agent: None,
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
 				signature_data: SignatureData {
 					signature: raw_signature([0; 65]),
 					nonce: <Vec<u8>>::new(),
+					deadline: NONCE_DEADLINE,
 				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair.clone(), &mut bid);
 
@@ -1109,7 +1230,14 @@ mod execute_ask_with_existing_bid {
 				price,
 				fee: ask_fee,
 				escrow_agent: None,
-				signature_data: SignatureData { signature: raw_signature([0; 65]), nonce: vec![1] },
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
+				signature_data: SignatureData {
+					signature: raw_signature([0; 65]),
+					nonce: vec![1],
+					deadline: NONCE_DEADLINE,
+				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair, &mut order);
 
@@ -1162,7 +1290,14 @@ mod execute_bid_with_existing_ask {
 				price,
 				fee: ask_fee,
 				escrow_agent: None,
-				signature_data: SignatureData { signature: raw_signature([0; 65]), nonce: vec![1] },
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
+				signature_data: SignatureData {
+					signature: raw_signature([0; 65]),
+					nonce: vec![1],
+					deadline: NONCE_DEADLINE,
+				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair.clone(), &mut order);
 
@@ -1188,10 +1323,14 @@ mod execute_bid_with_existing_ask {
 				price,
 				fee: bid_fee,
 				escrow_agent: None,
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
 				signature_data: SignatureData {
 					signature: raw_signature([0; 65]),
 					nonce: <Vec<u8>>::new(),
+					deadline: NONCE_DEADLINE,
 				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair, &mut bid);
 
@@ -1238,7 +1377,14 @@ mod execute_bid_with_existing_ask {
 				price,
 				fee: ask_fee,
 				escrow_agent: None,
-				signature_data: SignatureData { signature: raw_signature([0; 65]), nonce: vec![1] },
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
+				signature_data: SignatureData {
+					signature: raw_signature([0; 65]),
+					nonce: vec![1],
+					deadline: NONCE_DEADLINE,
+				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair.clone(), &mut order);
 
@@ -1264,10 +1410,14 @@ mod execute_bid_with_existing_ask {
 				price,
 				fee: bid_fee,
 				escrow_agent: None,
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
 				signature_data: SignatureData {
 					signature: raw_signature([0; 65]),
 					nonce: <Vec<u8>>::new(),
+					deadline: NONCE_DEADLINE,
 				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair, &mut bid);
 
@@ -1316,7 +1466,14 @@ mod execute_bid_with_existing_ask {
 				price,
 				fee: ask_fee,
 				escrow_agent: Some(escrow_agent),
-				signature_data: SignatureData { signature: raw_signature([0; 65]), nonce: vec![1] },
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
+				signature_data: SignatureData {
+					signature: raw_signature([0; 65]),
+					nonce: vec![1],
+					deadline: NONCE_DEADLINE,
+				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair.clone(), &mut order);
 
@@ -1342,10 +1499,14 @@ mod execute_bid_with_existing_ask {
 				price,
 				fee: bid_fee,
 				escrow_agent: None,
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
 				signature_data: SignatureData {
 					signature: raw_signature([0; 65]),
 					nonce: <Vec<u8>>::new(),
+					deadline: NONCE_DEADLINE,
 				},
+				buyer: None,
 			};
 			append_valid_signature(fee_signer_pair, &mut bid);
 
@@ -1368,6 +1529,10 @@ mod execute_bid_with_existing_ask {
 			assert_eq!(buyer_balance_before - buyer_payment, Balances::balance(&buyer));
 			assert_eq!(seller_balance_before, Balances::balance(&seller));
 			assert_eq!(seller_pay, Balances::reserved_balance_named(ESCROW_RESERVE_NAME, &seller));
+			// Agent settlement deposits for the escrow agent without opening a trade escrow
+			assert_eq!(EscrowDeposits::get(seller, escrow_agent), seller_pay);
+			assert_eq!(Escrows::<Test>::get(0), None);
+			assert_eq!(NextEscrowId::<Test>::get(), 0);
 		})
 	}
 }
@@ -1581,3 +1746,3908 @@ mod cancel_bid {
 		})
 	}
 }
+
+mod collection_bids {
+	use super::*;
+
+	fn collection_bid_order(
+		quantity: u32,
+		price: Balance<Test>,
+		fee: Balance<Test>,
+		nonce: Vec<u8>,
+	) -> OrderOf<Test> {
+		Order {
+			order_type: OrderType::CollectionBid { quantity },
+			collection: 0,
+			item: 0,
+			expires_at: get_valid_expiration(),
+			price,
+			fee,
+			escrow_agent: None,
+			escrow_settlement: EscrowSettlement::Agent,
+			asset: None,
+			signature_data: SignatureData {
+				signature: raw_signature([0; 65]),
+				nonce,
+				deadline: NONCE_DEADLINE,
+			},
+			buyer: None,
+		}
+	}
+
+	fn ask_order(
+		item: ItemId,
+		price: Balance<Test>,
+		fee: Balance<Test>,
+		nonce: Vec<u8>,
+	) -> OrderOf<Test> {
+		Order {
+			order_type: OrderType::Ask,
+			collection: 0,
+			item,
+			expires_at: get_valid_expiration(),
+			price,
+			fee,
+			escrow_agent: None,
+			escrow_settlement: EscrowSettlement::Agent,
+			asset: None,
+			signature_data: SignatureData {
+				signature: raw_signature([0; 65]),
+				nonce,
+				deadline: NONCE_DEADLINE,
+			},
+			buyer: None,
+		}
+	}
+
+	#[test]
+	fn collection_bid_created() {
+		new_test_ext().execute_with(|| {
+			mint_item(1, account(2));
+			let (_, fee_signer_pair) = admin_accounts_setup();
+			Balances::set_balance(&account(1), 100000);
+
+			let mut order = collection_bid_order(3, 10000, 3, vec![]);
+			append_valid_signature(fee_signer_pair, &mut order);
+
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(1)),
+				order.clone(),
+				Execution::AllowCreation
+			));
+
+			let bid = CollectionBid {
+				buyer: account(1),
+				expiration: order.expires_at,
+				fee: order.fee,
+				quantity: 3,
+				asset: None,
+			};
+			assert_eq!(CollectionBids::<Test>::get((0, order.price, account(1))), Some(bid));
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::MarketplaceBid.into(), &account(1)),
+				30003
+			);
+		})
+	}
+
+	#[test]
+	fn should_calculate_collection_bid_payment() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(Marketplace::calc_collection_bid_payment(&1000, 3, &20).ok(), Some(3020));
+			assert!(Marketplace::calc_collection_bid_payment(&u128::MAX, 2, &0).is_err());
+		})
+	}
+
+	#[test]
+	fn invalid_quantity() {
+		new_test_ext().execute_with(|| {
+			mint_item(1, account(2));
+			let (_, fee_signer_pair) = admin_accounts_setup();
+			Balances::set_balance(&account(1), 100000);
+
+			let mut order = collection_bid_order(0, 10000, 3, vec![]);
+			append_valid_signature(fee_signer_pair, &mut order);
+
+			assert_noop!(
+				Marketplace::create_order(
+					RuntimeOrigin::signed(account(1)),
+					order,
+					Execution::AllowCreation
+				),
+				Error::<Test>::InvalidQuantity
+			);
+		})
+	}
+
+	#[test]
+	fn collection_not_found() {
+		new_test_ext().execute_with(|| {
+			let (_, fee_signer_pair) = admin_accounts_setup();
+			Balances::set_balance(&account(1), 100000);
+
+			let mut order = collection_bid_order(1, 10000, 3, vec![]);
+			append_valid_signature(fee_signer_pair, &mut order);
+
+			assert_noop!(
+				Marketplace::create_order(
+					RuntimeOrigin::signed(account(1)),
+					order,
+					Execution::AllowCreation
+				),
+				Error::<Test>::CollectionNotFound
+			);
+		})
+	}
+
+	#[test]
+	fn quantity_is_signed() {
+		new_test_ext().execute_with(|| {
+			mint_item(1, account(2));
+			let (_, fee_signer_pair) = admin_accounts_setup();
+			Balances::set_balance(&account(1), 100000);
+
+			let mut order = collection_bid_order(1, 100, 3, vec![]);
+			append_valid_signature(fee_signer_pair, &mut order);
+			order.order_type = OrderType::CollectionBid { quantity: 5 };
+
+			assert_noop!(
+				Marketplace::create_order(
+					RuntimeOrigin::signed(account(1)),
+					order,
+					Execution::AllowCreation
+				),
+				Error::<Test>::BadSignedMessage
+			);
+		})
+	}
+
+	#[test]
+	fn should_not_create_with_execution_force() {
+		new_test_ext().execute_with(|| {
+			mint_item(1, account(2));
+			let (_, fee_signer_pair) = admin_accounts_setup();
+			Balances::set_balance(&account(1), 100000);
+
+			let mut order = collection_bid_order(1, 10000, 3, vec![]);
+			append_valid_signature(fee_signer_pair, &mut order);
+
+			assert_noop!(
+				Marketplace::create_order(
+					RuntimeOrigin::signed(account(1)),
+					order,
+					Execution::Force
+				),
+				Error::<Test>::ValidMatchMustExist
+			);
+		})
+	}
+
+	#[test]
+	fn collection_bid_already_exists() {
+		new_test_ext().execute_with(|| {
+			mint_item(1, account(2));
+			let (_, fee_signer_pair) = admin_accounts_setup();
+			Balances::set_balance(&account(1), 100000);
+			Balances::set_balance(&account(3), 100000);
+
+			let mut order = collection_bid_order(1, 10000, 3, vec![]);
+			append_valid_signature(fee_signer_pair.clone(), &mut order);
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(1)),
+				order,
+				Execution::AllowCreation
+			));
+
+			// Another buyer can bid at the same price
+			let mut order = collection_bid_order(2, 10000, 3, vec![1]);
+			append_valid_signature(fee_signer_pair.clone(), &mut order);
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(3)),
+				order,
+				Execution::AllowCreation
+			));
+			assert!(CollectionBids::<Test>::get((0, 10000, account(1))).is_some());
+			assert_eq!(CollectionBids::<Test>::get((0, 10000, account(3))).unwrap().quantity, 2);
+
+			let mut order = collection_bid_order(2, 10000, 3, vec![2]);
+			append_valid_signature(fee_signer_pair, &mut order);
+			assert_noop!(
+				Marketplace::create_order(
+					RuntimeOrigin::signed(account(1)),
+					order,
+					Execution::AllowCreation
+				),
+				Error::<Test>::OrderAlreadyExists
+			);
+		})
+	}
+
+	#[test]
+	fn collection_bid_fills_existing_ask() {
+		new_test_ext().execute_with(|| {
+			let buyer = account(2);
+			let seller = account(3);
+			let price = 10000;
+
+			mint_item(1, seller);
+			let (admin, fee_signer_pair) = admin_accounts_setup();
+			Balances::set_balance(&buyer, 100000);
+
+			let mut ask = ask_order(1, price, 2, vec![]);
+			append_valid_signature(fee_signer_pair.clone(), &mut ask);
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(seller),
+				ask,
+				Execution::AllowCreation
+			));
+
+			let mut order = collection_bid_order(2, price, 3, vec![1]);
+			order.item = 1;
+			append_valid_signature(fee_signer_pair, &mut order);
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(buyer),
+				order.clone(),
+				Execution::AllowCreation
+			));
+
+			assert_eq!(Nfts::owner(0, 1), Some(buyer));
+			assert!(Asks::<Test>::get(0, 1).is_none());
+			assert_eq!(
+				CollectionBids::<Test>::get((0, price, buyer)),
+				Some(CollectionBid {
+					buyer,
+					expiration: order.expires_at,
+					fee: 2,
+					quantity: 1,
+					asset: None,
+				})
+			);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::MarketplaceBid.into(), &buyer),
+				price + 2
+			);
+			System::assert_has_event(
+				Event::CollectionBidCreated {
+					who: buyer,
+					collection: 0,
+					price,
+					quantity: 2,
+					expires_at: order.expires_at,
+					fee: 3,
+					asset: None,
+					fee_signer: admin,
+				}
+				.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn force_only_buys_matching_ask() {
+		new_test_ext().execute_with(|| {
+			let buyer = account(2);
+			let price = 10000;
+
+			mint_item(1, account(3));
+			let (_, fee_signer_pair) = admin_accounts_setup();
+			Balances::set_balance(&buyer, 100000);
+
+			let mut ask = ask_order(1, price, 2, vec![]);
+			append_valid_signature(fee_signer_pair.clone(), &mut ask);
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(3)),
+				ask,
+				Execution::AllowCreation
+			));
+
+			let mut order = collection_bid_order(3, price, 3, vec![1]);
+			order.item = 1;
+			append_valid_signature(fee_signer_pair, &mut order);
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(buyer),
+				order,
+				Execution::Force
+			));
+
+			assert_eq!(Nfts::owner(0, 1), Some(buyer));
+			assert!(CollectionBids::<Test>::get((0, price, buyer)).is_none());
+			assert!(!AccountOrders::<Test>::contains_key(buyer));
+			assert_eq!(Balances::balance_on_hold(&HoldReason::MarketplaceBid.into(), &buyer), 0);
+			assert_eq!(Balances::balance(&buyer), 100000 - price - 1);
+		})
+	}
+
+	#[test]
+	fn asks_fill_collection_bid() {
+		new_test_ext().execute_with(|| {
+			let buyer = account(2);
+			let seller = account(3);
+			let price = 10000;
+			let bid_fee = 3;
+			let ask_fee = 2;
+
+			mint_item(1, seller);
+			mint_item(2, seller);
+			let (_, fee_signer_pair) = admin_accounts_setup();
+			Balances::set_balance(&buyer, 100000);
+			Balances::set_balance(&seller, 1000);
+
+			let mut order = collection_bid_order(2, price, bid_fee, vec![]);
+			append_valid_signature(fee_signer_pair.clone(), &mut order);
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(buyer),
+				order.clone(),
+				Execution::AllowCreation
+			));
+
+			let payout_address = PayoutAddress::<Test>::get().unwrap();
+			let payout_address_balance_before = Balances::balance(&payout_address);
+			let seller_balance_before = Balances::balance(&seller);
+
+			// First fill pays half of the fee, rounded down
+			let mut ask = ask_order(1, price, ask_fee, vec![1]);
+			append_valid_signature(fee_signer_pair.clone(), &mut ask);
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(seller),
+				ask,
+				Execution::Force
+			));
+
+			assert_eq!(Nfts::owner(0, 1), Some(buyer));
+			assert!(Nfts::can_transfer(&0, &1));
+			assert_eq!(
+				CollectionBids::<Test>::get((0, price, buyer)),
+				Some(CollectionBid {
+					buyer,
+					expiration: order.expires_at,
+					fee: bid_fee - 1,
+					quantity: 1,
+					asset: None,
+				})
+			);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::MarketplaceBid.into(), &buyer),
+				price + bid_fee - 1
+			);
+			assert_eq!(
+				payout_address_balance_before + ask_fee + 1,
+				Balances::balance(&payout_address)
+			);
+			assert_eq!(seller_balance_before + price - ask_fee, Balances::balance(&seller));
+
+			// Last fill pays the remaining fee
+			let mut ask = ask_order(2, price, ask_fee, vec![2]);
+			append_valid_signature(fee_signer_pair, &mut ask);
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(seller),
+				ask,
+				Execution::Force
+			));
+
+			assert_eq!(Nfts::owner(0, 2), Some(buyer));
+			assert!(CollectionBids::<Test>::get((0, price, buyer)).is_none());
+			assert_eq!(Balances::balance_on_hold(&HoldReason::MarketplaceBid.into(), &buyer), 0);
+			assert_eq!(
+				payout_address_balance_before + 2 * ask_fee + bid_fee,
+				Balances::balance(&payout_address)
+			);
+		})
+	}
+
+	#[test]
+	fn item_bid_takes_precedence() {
+		new_test_ext().execute_with(|| {
+			let (_, fee_signer_pair) = admin_accounts_setup();
+
+			create_valid_order(OrderType::Bid, account(2), account(1));
+			Balances::set_balance(&account(3), 100000);
+
+			let mut order = collection_bid_order(1, 10000, 1, vec![1]);
+			append_valid_signature(fee_signer_pair.clone(), &mut order);
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(3)),
+				order,
+				Execution::AllowCreation
+			));
+
+			let mut ask = ask_order(1, 10000, 1, vec![2]);
+			append_valid_signature(fee_signer_pair, &mut ask);
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(1)),
+				ask,
+				Execution::Force
+			));
+
+			assert_eq!(Nfts::owner(0, 1), Some(account(2)));
+			assert!(Bids::<Test>::get((0, 1, 10000)).is_none());
+			assert!(CollectionBids::<Test>::get((0, 10000, account(3))).is_some());
+		})
+	}
+
+	#[test]
+	fn expired_collection_bid_is_not_filled() {
+		new_test_ext().execute_with(|| {
+			mint_item(1, account(1));
+			let (_, fee_signer_pair) = admin_accounts_setup();
+			Balances::set_balance(&account(2), 100000);
+
+			let mut order = collection_bid_order(1, 10000, 1, vec![]);
+			append_valid_signature(fee_signer_pair.clone(), &mut order);
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(2)),
+				order.clone(),
+				Execution::AllowCreation
+			));
+
+			Timestamp::set_timestamp(order.expires_at);
+
+			let mut ask = ask_order(1, 10000, 1, vec![1]);
+			append_valid_signature(fee_signer_pair, &mut ask);
+			assert_noop!(
+				Marketplace::create_order(RuntimeOrigin::signed(account(1)), ask, Execution::Force),
+				Error::<Test>::ValidMatchMustExist
+			);
+		})
+	}
+
+	#[test]
+	fn cancel_releases_remaining_funds() {
+		new_test_ext().execute_with(|| {
+			let buyer = account(2);
+			let price = 10000;
+
+			mint_item(1, account(1));
+			let (_, fee_signer_pair) = admin_accounts_setup();
+			Balances::set_balance(&buyer, 100000);
+
+			let mut order = collection_bid_order(3, price, 3, vec![]);
+			append_valid_signature(fee_signer_pair.clone(), &mut order);
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(buyer),
+				order.clone(),
+				Execution::AllowCreation
+			));
+
+			let mut ask = ask_order(1, price, 1, vec![1]);
+			append_valid_signature(fee_signer_pair, &mut ask);
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(1)),
+				ask,
+				Execution::Force
+			));
+
+			assert_noop!(
+				Marketplace::cancel_collection_bid(
+					RuntimeOrigin::signed(account(3)),
+					0,
+					price,
+					buyer
+				),
+				Error::<Test>::NotOrderCreatorOrAdmin
+			);
+			assert_noop!(
+				Marketplace::cancel_order(
+					RuntimeOrigin::signed(buyer),
+					order.order_type,
+					0,
+					0,
+					price
+				),
+				Error::<Test>::InvalidOrderType
+			);
+
+			assert_ok!(Marketplace::cancel_collection_bid(
+				RuntimeOrigin::signed(buyer),
+				0,
+				price,
+				buyer
+			));
+
+			System::assert_last_event(
+				Event::CollectionBidCanceled { collection: 0, price, buyer, who: buyer }.into(),
+			);
+			assert!(CollectionBids::<Test>::get((0, price, buyer)).is_none());
+			assert_eq!(Balances::balance_on_hold(&HoldReason::MarketplaceBid.into(), &buyer), 0);
+		})
+	}
+}
+
+mod expire_orders {
+	use super::*;
+	use frame_support::{traits::Hooks, weights::Weight};
+
+	fn create_order(
+		order_type: OrderType,
+		who: AccountIdOf<Test>,
+		item: ItemId,
+		price: Balance<Test>,
+	) {
+		let fee_signer_pair = Pair::from_string("//Alice", None).unwrap();
+		let mut order = Order {
+			order_type,
+			collection: 0,
+			item,
+			expires_at: get_valid_expiration(),
+			price,
+			fee: 1,
+			escrow_agent: None,
+			escrow_settlement: EscrowSettlement::Agent,
+			asset: None,
+			signature_data: SignatureData {
+				signature: raw_signature([0; 65]),
+				nonce: vec![],
+				deadline: NONCE_DEADLINE,
+			},
+			buyer: None,
+		};
+		append_valid_signature(fee_signer_pair, &mut order);
+
+		assert_ok!(Marketplace::create_order(
+			RuntimeOrigin::signed(who),
+			order,
+			Execution::AllowCreation
+		));
+	}
+
+	#[test]
+	fn order_is_indexed_by_expiration_slot() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			create_valid_order(OrderType::Ask, account(1), account(1));
+
+			// The order expires at 11, which is rounded up to the slot 20.
+			assert_eq!(Asks::<Test>::get(0, 1).unwrap().expiration, 11);
+			assert_eq!(Marketplace::expiration_slot(11), 20);
+			assert_eq!(Marketplace::expiration_slot(20), 20);
+			assert!(OrderExpirations::<Test>::contains_key(
+				20,
+				OrderKey::Ask { collection: 0, item: 1 }
+			));
+			assert_eq!(NextExpirationSlot::<Test>::get(), Some(20));
+		})
+	}
+
+	#[test]
+	fn expired_ask_is_removed() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			create_valid_order(OrderType::Ask, account(1), account(1));
+			assert!(!Nfts::can_transfer(&0, &1));
+
+			Timestamp::set_timestamp(20);
+			Marketplace::on_idle(System::block_number(), Weight::MAX);
+
+			assert!(Asks::<Test>::get(0, 1).is_none());
+			assert!(Nfts::can_transfer(&0, &1));
+			assert_eq!(OrderExpirations::<Test>::iter().count(), 0);
+			assert_eq!(NextExpirationSlot::<Test>::get(), None);
+			System::assert_last_event(
+				Event::OrderExpired {
+					order: OrderKey::Ask { collection: 0, item: 1 },
+					who: account(1),
+				}
+				.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn expired_bid_releases_funds() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			create_valid_order(OrderType::Bid, account(2), account(1));
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::MarketplaceBid.into(), &account(2)),
+				10001
+			);
+
+			Timestamp::set_timestamp(20);
+			Marketplace::on_idle(System::block_number(), Weight::MAX);
+
+			assert!(Bids::<Test>::get((0, 1, 10000)).is_none());
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::MarketplaceBid.into(), &account(2)),
+				0
+			);
+			System::assert_last_event(
+				Event::OrderExpired {
+					order: OrderKey::Bid { collection: 0, item: 1, price: 10000 },
+					who: account(2),
+				}
+				.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn expired_collection_bid_releases_funds() {
+		new_test_ext().execute_with(|| {
+			mint_item(1, account(1));
+			let _ = admin_accounts_setup();
+			Balances::set_balance(&account(2), 100000);
+			create_order(OrderType::CollectionBid { quantity: 3 }, account(2), 0, 10000);
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::MarketplaceBid.into(), &account(2)),
+				30001
+			);
+
+			Timestamp::set_timestamp(20);
+			Marketplace::on_idle(System::block_number(), Weight::MAX);
+
+			assert!(CollectionBids::<Test>::get((0, 10000, account(2))).is_none());
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::MarketplaceBid.into(), &account(2)),
+				0
+			);
+			System::assert_last_event(
+				Event::OrderExpired {
+					order: OrderKey::CollectionBid {
+						collection: 0,
+						price: 10000,
+						buyer: account(2),
+					},
+					who: account(2),
+				}
+				.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn order_not_expired_is_kept() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			create_valid_order(OrderType::Ask, account(1), account(1));
+
+			Timestamp::set_timestamp(19);
+			assert_eq!(Marketplace::on_idle(System::block_number(), Weight::MAX), Weight::zero());
+
+			assert!(Asks::<Test>::get(0, 1).is_some());
+			assert_eq!(NextExpirationSlot::<Test>::get(), Some(20));
+		})
+	}
+
+	#[test]
+	fn canceled_order_is_unindexed() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			create_valid_order(OrderType::Bid, account(2), account(1));
+
+			assert_ok!(Marketplace::cancel_order(
+				RuntimeOrigin::signed(account(2)),
+				OrderType::Bid,
+				0,
+				1,
+				10000
+			));
+			assert_eq!(OrderExpirations::<Test>::iter().count(), 0);
+
+			// The sweep skips the empty slot and stops once the index is empty.
+			Timestamp::set_timestamp(20);
+			Marketplace::on_idle(System::block_number(), Weight::MAX);
+			assert_eq!(NextExpirationSlot::<Test>::get(), None);
+		})
+	}
+
+	#[test]
+	fn executed_order_is_unindexed() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			create_valid_order(OrderType::Ask, account(1), account(1));
+			Balances::set_balance(&account(2), 100000);
+			create_order(OrderType::Bid, account(2), 1, 10000);
+
+			assert!(Asks::<Test>::get(0, 1).is_none());
+			assert_eq!(OrderExpirations::<Test>::iter().count(), 0);
+		})
+	}
+
+	#[test]
+	fn expiration_is_limited_by_weight() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			mint_item(1, account(1));
+			mint_item(2, account(1));
+			create_order(OrderType::Ask, account(1), 1, 10000);
+			create_order(OrderType::Ask, account(1), 2, 10000);
+
+			Timestamp::set_timestamp(20);
+			let weight = <Test as Config>::WeightInfo::expire_next_order();
+
+			assert_eq!(Marketplace::on_idle(System::block_number(), weight), weight);
+			assert_eq!(Asks::<Test>::iter().count(), 1);
+
+			assert_eq!(Marketplace::on_idle(System::block_number(), weight), weight);
+			assert_eq!(Asks::<Test>::iter().count(), 0);
+		})
+	}
+}
+
+mod royalties {
+	use super::*;
+	use sp_runtime::Perbill;
+
+	fn royalty(recipient: AccountIdOf<Test>, percent: u32) -> Option<Royalty<AccountIdOf<Test>>> {
+		Some(Royalty { recipient, rate: Perbill::from_percent(percent) })
+	}
+
+	#[test]
+	fn set_by_collection_owner() {
+		new_test_ext().execute_with(|| {
+			mint_item(1, account(2));
+
+			assert_ok!(Marketplace::set_collection_royalty(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				royalty(account(5), 10)
+			));
+
+			assert_eq!(Royalties::<Test>::get(0), royalty(account(5), 10));
+			System::assert_last_event(
+				Event::RoyaltySet { collection: 0, royalty: royalty(account(5), 10) }.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn set_by_authority() {
+		new_test_ext().execute_with(|| {
+			mint_item(1, account(2));
+			let (admin, _) = admin_accounts_setup();
+
+			assert_ok!(Marketplace::set_collection_royalty(
+				RuntimeOrigin::signed(admin),
+				0,
+				royalty(account(5), 10)
+			));
+
+			assert_eq!(Royalties::<Test>::get(0), royalty(account(5), 10));
+		})
+	}
+
+	#[test]
+	fn remove_royalty() {
+		new_test_ext().execute_with(|| {
+			mint_item(1, account(2));
+			assert_ok!(Marketplace::set_collection_royalty(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				royalty(account(5), 10)
+			));
+
+			assert_ok!(Marketplace::set_collection_royalty(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				None
+			));
+
+			assert!(Royalties::<Test>::get(0).is_none());
+			System::assert_last_event(Event::RoyaltySet { collection: 0, royalty: None }.into());
+		})
+	}
+
+	#[test]
+	fn not_collection_owner_or_admin() {
+		new_test_ext().execute_with(|| {
+			mint_item(1, account(2));
+			let _ = admin_accounts_setup();
+
+			assert_noop!(
+				Marketplace::set_collection_royalty(
+					RuntimeOrigin::signed(account(2)),
+					0,
+					royalty(account(2), 10)
+				),
+				Error::<Test>::NotCollectionOwnerOrAdmin
+			);
+		})
+	}
+
+	#[test]
+	fn collection_not_found() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Marketplace::set_collection_royalty(
+					RuntimeOrigin::signed(account(1)),
+					0,
+					royalty(account(5), 10)
+				),
+				Error::<Test>::CollectionNotFound
+			);
+		})
+	}
+
+	#[test]
+	fn royalty_too_high() {
+		new_test_ext().execute_with(|| {
+			mint_item(1, account(2));
+
+			assert_noop!(
+				Marketplace::set_collection_royalty(
+					RuntimeOrigin::signed(account(1)),
+					0,
+					royalty(account(5), 51)
+				),
+				Error::<Test>::RoyaltyTooHigh
+			);
+		})
+	}
+
+	#[test]
+	fn royalty_paid_on_execution() {
+		new_test_ext().execute_with(|| {
+			let seller = account(3);
+			let buyer = account(2);
+			let creator = account(5);
+			let (_, fee_signer_pair) = admin_accounts_setup();
+
+			create_valid_order(OrderType::Ask, seller, seller);
+			assert_ok!(Marketplace::set_collection_royalty(
+				RuntimeOrigin::signed(account(1)),
+				0,
+				royalty(creator, 10)
+			));
+			Balances::set_balance(&buyer, 100000);
+			Balances::set_balance(&creator, 1000);
+			let seller_balance_before = Balances::balance(&seller);
+
+			let mut order = Order {
+				order_type: OrderType::Bid,
+				collection: 0,
+				item: 1,
+				expires_at: get_valid_expiration(),
+				price: 10000,
+				fee: 1,
+				escrow_agent: None,
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
+				signature_data: SignatureData {
+					signature: raw_signature([0; 65]),
+					nonce: vec![1],
+					deadline: NONCE_DEADLINE,
+				},
+				buyer: None,
+			};
+			append_valid_signature(fee_signer_pair, &mut order);
+
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(buyer),
+				order,
+				Execution::AllowCreation
+			));
+
+			// The royalty is deducted from the seller proceeds, the fees are unchanged.
+			assert_eq!(Nfts::owner(0, 1), Some(buyer));
+			assert_eq!(Balances::balance(&creator), 1000 + 1000);
+			assert_eq!(Balances::balance(&seller), seller_balance_before + 10000 - 1 - 1000);
+			System::assert_last_event(
+				Event::OrderExecuted {
+					collection: 0,
+					item: 1,
+					seller,
+					buyer,
+					price: 10000,
+					seller_fee: 1,
+					buyer_fee: 1,
+					royalty_recipient: Some(creator),
+					royalty: 1000,
+					asset: None,
+					escrow_id: None,
+				}
+				.into(),
+			);
+		})
+	}
+}
+
+mod auctions {
+	use super::*;
+	use frame_support::{traits::Hooks, weights::Weight};
+	use sp_runtime::DispatchResult;
+
+	fn sign(payload: &[u8]) -> EthereumSignature {
+		let fee_signer_pair = Pair::from_string("//Alice", None).unwrap();
+		let mut payload = payload.to_vec();
+		NONCE_DEADLINE.encode_to(&mut payload);
+		EthereumSignature::from(fee_signer_pair.sign_prehashed(&keccak_256(&payload)))
+	}
+
+	fn auction_order(
+		kind: AuctionKind<BalanceOf<Test>>,
+		starts_at: Moment<Test>,
+		ends_at: Moment<Test>,
+	) -> AuctionOrderOf<Test> {
+		let mut auction = AuctionOrder {
+			collection: 0,
+			item: 1,
+			kind,
+			start_price: 10000,
+			starts_at,
+			ends_at,
+			fee: 2,
+			asset: None,
+			signature_data: SignatureData {
+				signature: raw_signature([0; 65]),
+				nonce: vec![0],
+				deadline: NONCE_DEADLINE,
+			},
+		};
+		let message: AuctionMessageOf<Test> = auction.clone().into();
+		auction.signature_data.signature = sign(&message.encode());
+		auction
+	}
+
+	fn english() -> AuctionKind<BalanceOf<Test>> {
+		AuctionKind::English { min_increment: 100 }
+	}
+
+	fn dutch() -> AuctionKind<BalanceOf<Test>> {
+		AuctionKind::Dutch { end_price: 5000 }
+	}
+
+	fn create_auction(kind: AuctionKind<BalanceOf<Test>>) {
+		mint_item(1, account(1));
+		assert_ok!(Marketplace::create_auction(
+			RuntimeOrigin::signed(account(1)),
+			auction_order(kind, 0, 100)
+		));
+	}
+
+	fn bid(
+		who: AccountIdOf<Test>,
+		amount: BalanceOf<Test>,
+		fee: BalanceOf<Test>,
+		nonce: u8,
+	) -> DispatchResult {
+		let nonce = vec![nonce];
+		let message: AuctionBidMessageOf<Test> = AuctionBidMessage {
+			collection: 0,
+			item: 1,
+			amount,
+			fee,
+			nonce: nonce.clone(),
+			asset: None,
+		};
+		let signature_data =
+			SignatureData { signature: sign(&message.encode()), nonce, deadline: NONCE_DEADLINE };
+
+		Marketplace::bid_on_auction(RuntimeOrigin::signed(who), 0, 1, amount, fee, signature_data)
+	}
+
+	fn on_hold(who: AccountIdOf<Test>) -> Balance<Test> {
+		Balances::balance_on_hold(&HoldReason::MarketplaceBid.into(), &who)
+	}
+
+	#[test]
+	fn auction_created() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			create_auction(english());
+
+			assert_eq!(
+				Auctions::<Test>::get(0, 1),
+				Some(Auction {
+					seller: account(1),
+					kind: english(),
+					start_price: 10000,
+					starts_at: 0,
+					ends_at: 100,
+					fee: 2,
+					highest_bid: None,
+					asset: None,
+				})
+			);
+			assert!(!Nfts::can_transfer(&0, &1));
+			assert!(OrderExpirations::<Test>::contains_key(
+				100,
+				OrderKey::Auction { collection: 0, item: 1 }
+			));
+			assert_eq!(
+				Marketplace::orders_by_account(&account(1)),
+				vec![OrderKey::Auction { collection: 0, item: 1 }]
+			);
+			System::assert_last_event(
+				Event::AuctionCreated {
+					collection: 0,
+					item: 1,
+					seller: account(1),
+					kind: english(),
+					start_price: 10000,
+					starts_at: 0,
+					ends_at: 100,
+					fee: 2,
+					asset: None,
+				}
+				.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn invalid_auction_parameters() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			mint_item(1, account(1));
+
+			assert_noop!(
+				Marketplace::create_auction(
+					RuntimeOrigin::signed(account(1)),
+					auction_order(english(), 50, 50)
+				),
+				Error::<Test>::InvalidExpiration
+			);
+			assert_noop!(
+				Marketplace::create_auction(
+					RuntimeOrigin::signed(account(1)),
+					auction_order(english(), 0, 10)
+				),
+				Error::<Test>::InvalidExpiration
+			);
+			assert_noop!(
+				Marketplace::create_auction(
+					RuntimeOrigin::signed(account(1)),
+					auction_order(AuctionKind::English { min_increment: 0 }, 0, 100)
+				),
+				Error::<Test>::InvalidPrice
+			);
+			assert_noop!(
+				Marketplace::create_auction(
+					RuntimeOrigin::signed(account(1)),
+					auction_order(AuctionKind::Dutch { end_price: 10001 }, 0, 100)
+				),
+				Error::<Test>::InvalidPrice
+			);
+		})
+	}
+
+	#[test]
+	fn not_item_owner() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			mint_item(1, account(1));
+
+			assert_noop!(
+				Marketplace::create_auction(
+					RuntimeOrigin::signed(account(2)),
+					auction_order(english(), 0, 100)
+				),
+				Error::<Test>::NotItemOwner
+			);
+		})
+	}
+
+	#[test]
+	fn item_already_locked() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			create_valid_order(OrderType::Ask, account(1), account(1));
+
+			assert_noop!(
+				Marketplace::create_auction(
+					RuntimeOrigin::signed(account(1)),
+					auction_order(english(), 0, 100)
+				),
+				Error::<Test>::ItemAlreadyLocked
+			);
+		})
+	}
+
+	#[test]
+	fn bad_signature() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			mint_item(1, account(1));
+
+			let mut auction = auction_order(english(), 0, 100);
+			auction.start_price = 1;
+
+			assert_noop!(
+				Marketplace::create_auction(RuntimeOrigin::signed(account(1)), auction),
+				Error::<Test>::BadSignedMessage
+			);
+		})
+	}
+
+	#[test]
+	fn english_bids_must_increase() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			create_auction(english());
+			Balances::set_balance(&account(2), 100000);
+
+			assert_noop!(bid(account(2), 9999, 1, 1), Error::<Test>::BidTooLow);
+			assert_ok!(bid(account(2), 10000, 1, 1));
+			assert_noop!(bid(account(2), 10099, 1, 2), Error::<Test>::BidTooLow);
+			assert_ok!(bid(account(2), 10100, 1, 2));
+		})
+	}
+
+	#[test]
+	fn english_outbid_releases_funds() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			create_auction(english());
+			Balances::set_balance(&account(2), 100000);
+			Balances::set_balance(&account(3), 100000);
+
+			assert_ok!(bid(account(2), 10000, 1, 1));
+			assert_eq!(on_hold(account(2)), 10001);
+
+			assert_ok!(bid(account(3), 10500, 3, 2));
+			assert_eq!(on_hold(account(2)), 0);
+			assert_eq!(on_hold(account(3)), 10503);
+			assert_eq!(
+				Auctions::<Test>::get(0, 1).unwrap().highest_bid,
+				Some(AuctionBid { bidder: account(3), amount: 10500, fee: 3 })
+			);
+			System::assert_last_event(
+				Event::AuctionBidPlaced {
+					collection: 0,
+					item: 1,
+					bidder: account(3),
+					amount: 10500,
+					fee: 3,
+				}
+				.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn bid_outside_auction_time() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			mint_item(1, account(1));
+			assert_ok!(Marketplace::create_auction(
+				RuntimeOrigin::signed(account(1)),
+				auction_order(english(), 50, 100)
+			));
+			Balances::set_balance(&account(2), 100000);
+
+			assert_noop!(bid(account(2), 10000, 1, 1), Error::<Test>::AuctionNotStarted);
+			Timestamp::set_timestamp(100);
+			assert_noop!(bid(account(2), 10000, 1, 1), Error::<Test>::AuctionEnded);
+		})
+	}
+
+	#[test]
+	fn seller_cannot_bid() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			create_auction(english());
+
+			assert_noop!(bid(account(1), 10000, 1, 1), Error::<Test>::BuyerIsSeller);
+		})
+	}
+
+	#[test]
+	fn english_auction_settled_on_idle() {
+		new_test_ext().execute_with(|| {
+			let (admin, _) = admin_accounts_setup();
+			create_auction(english());
+			Balances::set_balance(&account(2), 100000);
+			assert_ok!(bid(account(2), 10000, 1, 1));
+			let seller_balance_before = Balances::balance(&account(1));
+			let payout_balance_before = Balances::balance(&admin);
+
+			Timestamp::set_timestamp(100);
+			Marketplace::on_idle(System::block_number(), Weight::MAX);
+
+			assert!(Auctions::<Test>::get(0, 1).is_none());
+			assert_eq!(Nfts::owner(0, 1), Some(account(2)));
+			assert!(Nfts::can_transfer(&0, &1));
+			assert_eq!(on_hold(account(2)), 0);
+			assert_eq!(Balances::balance(&account(1)), seller_balance_before + 10000 - 2);
+			assert_eq!(Balances::balance(&admin), payout_balance_before + 3);
+			System::assert_last_event(
+				Event::AuctionSettled {
+					collection: 0,
+					item: 1,
+					seller: account(1),
+					winner: Some(account(2)),
+					price: Some(10000),
+				}
+				.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn auction_settlement_retried_while_paused() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			create_auction(english());
+			Balances::set_balance(&account(2), 100000);
+			assert_ok!(bid(account(2), 10000, 1, 1));
+			assert_ok!(Marketplace::set_trading_paused(RuntimeOrigin::root(), true));
+
+			Timestamp::set_timestamp(100);
+			Marketplace::on_idle(System::block_number(), Weight::MAX);
+
+			// The auction can't be settled, it is moved to the next slot.
+			assert!(Auctions::<Test>::get(0, 1).is_some());
+			assert!(OrderExpirations::<Test>::contains_key(
+				110,
+				OrderKey::Auction { collection: 0, item: 1 }
+			));
+			assert_eq!(NextExpirationSlot::<Test>::get(), Some(110));
+
+			assert_ok!(Marketplace::set_trading_paused(RuntimeOrigin::root(), false));
+			Timestamp::set_timestamp(110);
+			Marketplace::on_idle(System::block_number(), Weight::MAX);
+
+			assert!(Auctions::<Test>::get(0, 1).is_none());
+			assert_eq!(Nfts::owner(0, 1), Some(account(2)));
+			assert_eq!(OrderExpirations::<Test>::iter().count(), 0);
+		})
+	}
+
+	#[test]
+	fn settle_auction() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			create_auction(english());
+
+			assert_noop!(
+				Marketplace::settle_auction(RuntimeOrigin::signed(account(3)), 0, 1),
+				Error::<Test>::AuctionNotEnded
+			);
+
+			Timestamp::set_timestamp(100);
+			assert_ok!(Marketplace::settle_auction(RuntimeOrigin::signed(account(3)), 0, 1));
+
+			// Without bids the item is unlocked and kept by the seller.
+			assert!(Auctions::<Test>::get(0, 1).is_none());
+			assert_eq!(Nfts::owner(0, 1), Some(account(1)));
+			assert!(Nfts::can_transfer(&0, &1));
+			assert_eq!(OrderExpirations::<Test>::iter().count(), 0);
+			assert!(Marketplace::orders_by_account(&account(1)).is_empty());
+			System::assert_last_event(
+				Event::AuctionSettled {
+					collection: 0,
+					item: 1,
+					seller: account(1),
+					winner: None,
+					price: None,
+				}
+				.into(),
+			);
+			assert_noop!(
+				Marketplace::settle_auction(RuntimeOrigin::signed(account(3)), 0, 1),
+				Error::<Test>::AuctionNotFound
+			);
+		})
+	}
+
+	#[test]
+	fn dutch_bid_fee_applies_to_current_price() {
+		new_test_ext().execute_with(|| {
+			let (admin, _) = admin_accounts_setup();
+			create_auction(dutch());
+			Balances::set_balance(&account(2), 100000);
+			assert_ok!(Marketplace::set_fee_policy(
+				RuntimeOrigin::signed(admin),
+				None,
+				Some(FeePolicy { min_fee_bps: 0, max_fee_bps: 100 })
+			));
+
+			// The item is bought at 7500, so the fee can't exceed 75 whatever the bid amount.
+			Timestamp::set_timestamp(50);
+			assert_noop!(bid(account(2), 10000, 76, 1), Error::<Test>::InvalidFeePercent);
+			assert_ok!(bid(account(2), 10000, 75, 1));
+			assert_eq!(Balances::balance(&account(2)), 100000 - 7500 - 75);
+		})
+	}
+
+	#[test]
+	fn auction_kept_by_cancel_all_orders() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			create_auction(english());
+
+			assert_ok!(Marketplace::cancel_all_orders(RuntimeOrigin::signed(account(1))));
+
+			assert!(Auctions::<Test>::get(0, 1).is_some());
+			assert_eq!(
+				Marketplace::orders_by_account(&account(1)),
+				vec![OrderKey::Auction { collection: 0, item: 1 }]
+			);
+			System::assert_last_event(
+				Event::AllOrdersCanceled { who: account(1), count: 0 }.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn dutch_price_decays() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			create_auction(dutch());
+			let auction = Auctions::<Test>::get(0, 1).unwrap();
+
+			assert_eq!(Marketplace::auction_price(&auction, 0), 10000);
+			assert_eq!(Marketplace::auction_price(&auction, 25), 8750);
+			assert_eq!(Marketplace::auction_price(&auction, 50), 7500);
+			assert_eq!(Marketplace::auction_price(&auction, 100), 5000);
+		})
+	}
+
+	#[test]
+	fn dutch_bid_buys_at_current_price() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			create_auction(dutch());
+			Balances::set_balance(&account(2), 100000);
+			let seller_balance_before = Balances::balance(&account(1));
+
+			Timestamp::set_timestamp(50);
+			assert_noop!(bid(account(2), 7499, 1, 1), Error::<Test>::BidTooLow);
+			assert_ok!(bid(account(2), 9000, 1, 1));
+
+			assert!(Auctions::<Test>::get(0, 1).is_none());
+			assert_eq!(Nfts::owner(0, 1), Some(account(2)));
+			assert_eq!(Balances::balance(&account(2)), 100000 - 7500 - 1);
+			assert_eq!(Balances::balance(&account(1)), seller_balance_before + 7500 - 2);
+			assert_eq!(OrderExpirations::<Test>::iter().count(), 0);
+			System::assert_last_event(
+				Event::AuctionSettled {
+					collection: 0,
+					item: 1,
+					seller: account(1),
+					winner: Some(account(2)),
+					price: Some(7500),
+				}
+				.into(),
+			);
+		})
+	}
+}
+
+mod assets {
+	use super::*;
+
+	fn order(
+		order_type: OrderType,
+		price: Balance<Test>,
+		escrow_agent: Option<AccountIdOf<Test>>,
+		asset: Option<u32>,
+		nonce: Vec<u8>,
+	) -> OrderOf<Test> {
+		let fee_signer_pair = Pair::from_string("//Alice", None).unwrap();
+		let mut order = Order {
+			order_type,
+			collection: 0,
+			item: 1,
+			expires_at: get_valid_expiration(),
+			price,
+			fee: 1,
+			escrow_agent,
+			escrow_settlement: EscrowSettlement::Agent,
+			asset,
+			signature_data: SignatureData {
+				signature: raw_signature([0; 65]),
+				nonce,
+				deadline: NONCE_DEADLINE,
+			},
+			buyer: None,
+		};
+		append_valid_signature(fee_signer_pair, &mut order);
+		order
+	}
+
+	#[test]
+	fn bid_holds_asset() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			let buyer = account(2);
+			mint_item(1, account(1));
+			Balances::set_balance(&buyer, 1000);
+			AssetBalances::set_balance(&buyer, 100000);
+
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(buyer),
+				order(OrderType::Bid, 10000, None, Some(USDC), vec![1]),
+				Execution::AllowCreation
+			));
+
+			assert_eq!(
+				Bids::<Test>::get((0, 1, 10000)),
+				Some(Bid { buyer, expiration: get_valid_expiration(), fee: 1, asset: Some(USDC) })
+			);
+			assert_eq!(
+				AssetBalances::balance_on_hold(&HoldReason::MarketplaceBid.into(), &buyer),
+				10001
+			);
+			assert_eq!(Balances::balance_on_hold(&HoldReason::MarketplaceBid.into(), &buyer), 0);
+
+			assert_ok!(Marketplace::cancel_order(
+				RuntimeOrigin::signed(buyer),
+				OrderType::Bid,
+				0,
+				1,
+				10000
+			));
+			assert_eq!(
+				AssetBalances::balance_on_hold(&HoldReason::MarketplaceBid.into(), &buyer),
+				0
+			);
+			assert_eq!(AssetBalances::balance(&buyer), 100000);
+		})
+	}
+
+	#[test]
+	fn order_executed_in_asset() {
+		new_test_ext().execute_with(|| {
+			let (payout_address, _) = admin_accounts_setup();
+			let seller = account(1);
+			let buyer = account(2);
+			mint_item(1, seller);
+			Balances::set_balance(&buyer, 1000);
+			AssetBalances::set_balance(&buyer, 100000);
+			let seller_balance_before = Balances::balance(&seller);
+
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(seller),
+				order(OrderType::Ask, 10000, None, Some(USDC), vec![1]),
+				Execution::AllowCreation
+			));
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(buyer),
+				order(OrderType::Bid, 10000, None, Some(USDC), vec![2]),
+				Execution::Force
+			));
+
+			assert_eq!(Nfts::owner(0, 1), Some(buyer));
+			assert!(Nfts::can_transfer(&0, &1));
+			assert_eq!(AssetBalances::balance(&buyer), 100000 - 10001);
+			assert_eq!(AssetBalances::balance(&seller), 10000 - 1);
+			assert_eq!(AssetBalances::balance(&payout_address), 2);
+			assert_eq!(Balances::balance(&seller), seller_balance_before);
+			assert_eq!(Balances::balance(&buyer), 1000);
+			System::assert_last_event(
+				Event::OrderExecuted {
+					collection: 0,
+					item: 1,
+					seller,
+					buyer,
+					price: 10000,
+					seller_fee: 1,
+					buyer_fee: 1,
+					royalty_recipient: None,
+					royalty: 0,
+					asset: Some(USDC),
+					escrow_id: None,
+				}
+				.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn orders_in_different_assets_do_not_match() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			let seller = account(1);
+			let buyer = account(2);
+			mint_item(1, seller);
+			Balances::set_balance(&buyer, 100000);
+
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(seller),
+				order(OrderType::Ask, 10000, None, Some(USDC), vec![1]),
+				Execution::AllowCreation
+			));
+			assert_noop!(
+				Marketplace::create_order(
+					RuntimeOrigin::signed(buyer),
+					order(OrderType::Bid, 10000, None, None, vec![2]),
+					Execution::Force
+				),
+				Error::<Test>::ValidMatchMustExist
+			);
+		})
+	}
+
+	#[test]
+	fn unknown_asset() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			mint_item(1, account(1));
+
+			assert_noop!(
+				Marketplace::create_order(
+					RuntimeOrigin::signed(account(1)),
+					order(OrderType::Ask, 10000, None, Some(USDC + 1), vec![1]),
+					Execution::AllowCreation
+				),
+				Error::<Test>::UnknownAsset
+			);
+		})
+	}
+
+	#[test]
+	fn escrow_requires_native_currency() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			mint_item(1, account(1));
+
+			assert_noop!(
+				Marketplace::create_order(
+					RuntimeOrigin::signed(account(1)),
+					order(OrderType::Ask, 10000, Some(account(5)), Some(USDC), vec![1]),
+					Execution::AllowCreation
+				),
+				Error::<Test>::EscrowRequiresNativeCurrency
+			);
+		})
+	}
+}
+
+mod order_book {
+	use super::*;
+
+	fn create_order(
+		order_type: OrderType,
+		who: AccountIdOf<Test>,
+		item: ItemId,
+		price: Balance<Test>,
+		nonce: u8,
+	) {
+		let fee_signer_pair = Pair::from_string("//Alice", None).unwrap();
+		let mut order = Order {
+			order_type,
+			collection: 0,
+			item,
+			expires_at: get_valid_expiration(),
+			price,
+			fee: 1,
+			escrow_agent: None,
+			escrow_settlement: EscrowSettlement::Agent,
+			asset: None,
+			signature_data: SignatureData {
+				signature: raw_signature([0; 65]),
+				nonce: vec![nonce],
+				deadline: NONCE_DEADLINE,
+			},
+			buyer: None,
+		};
+		append_valid_signature(fee_signer_pair, &mut order);
+
+		assert_ok!(Marketplace::create_order(
+			RuntimeOrigin::signed(who),
+			order,
+			Execution::AllowCreation
+		));
+	}
+
+	#[test]
+	fn ask_for() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			mint_item(1, account(1));
+			assert_eq!(Marketplace::ask_for(0, 1), None);
+
+			create_order(OrderType::Ask, account(1), 1, 10000, 0);
+			let ask = Asks::<Test>::get(0, 1).unwrap();
+			assert_eq!(Marketplace::ask_for(0, 1), Some(ask.clone()));
+
+			Timestamp::set_timestamp(ask.expiration);
+			assert_eq!(Marketplace::ask_for(0, 1), None);
+		})
+	}
+
+	#[test]
+	fn bids_sorted_by_price() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			mint_item(1, account(1));
+			assert_eq!(Marketplace::best_bid(0, 1), None);
+
+			for (nonce, (who, price)) in [(account(2), 500), (account(3), 1500), (account(4), 1000)]
+				.into_iter()
+				.enumerate()
+			{
+				Balances::set_balance(&who, 100000);
+				create_order(OrderType::Bid, who, 1, price, nonce as u8);
+			}
+
+			let prices: Vec<_> =
+				Marketplace::bids_for(0, 1).into_iter().map(|(price, _)| price).collect();
+			assert_eq!(prices, vec![1500, 1000, 500]);
+			let (price, bid) = Marketplace::best_bid(0, 1).unwrap();
+			assert_eq!(price, 1500);
+			assert_eq!(bid.buyer, account(3));
+			assert!(Marketplace::bids_for(0, 2).is_empty());
+		})
+	}
+
+	#[test]
+	fn orders_by_account() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			mint_item(1, account(1));
+			mint_item(2, account(2));
+			Balances::set_balance(&account(2), 100000);
+
+			create_order(OrderType::Ask, account(1), 1, 10000, 0);
+			create_order(OrderType::Bid, account(2), 1, 5000, 1);
+			create_order(OrderType::CollectionBid { quantity: 2 }, account(2), 0, 1000, 2);
+
+			assert_eq!(
+				Marketplace::orders_by_account(&account(1)),
+				vec![OrderKey::Ask { collection: 0, item: 1 }]
+			);
+			assert_eq!(
+				Marketplace::orders_by_account(&account(2)),
+				vec![
+					OrderKey::Bid { collection: 0, item: 1, price: 5000 },
+					OrderKey::CollectionBid { collection: 0, price: 1000, buyer: account(2) }
+				]
+			);
+			assert!(Marketplace::orders_by_account(&account(3)).is_empty());
+		})
+	}
+}
+
+mod account_orders {
+	use super::*;
+	use sp_runtime::DispatchResult;
+
+	fn create_order(
+		order_type: OrderType,
+		who: AccountIdOf<Test>,
+		item: ItemId,
+		price: Balance<Test>,
+		nonce: u8,
+	) -> DispatchResult {
+		let fee_signer_pair = Pair::from_string("//Alice", None).unwrap();
+		let mut order = Order {
+			order_type,
+			collection: 0,
+			item,
+			expires_at: get_valid_expiration(),
+			price,
+			fee: 1,
+			escrow_agent: None,
+			escrow_settlement: EscrowSettlement::Agent,
+			asset: None,
+			signature_data: SignatureData {
+				signature: raw_signature([0; 65]),
+				nonce: vec![nonce],
+				deadline: NONCE_DEADLINE,
+			},
+			buyer: None,
+		};
+		append_valid_signature(fee_signer_pair, &mut order);
+
+		Marketplace::create_order(RuntimeOrigin::signed(who), order, Execution::AllowCreation)
+	}
+
+	fn open_orders_setup() {
+		let _ = admin_accounts_setup();
+		mint_item(1, account(1));
+		mint_item(2, account(2));
+		Balances::set_balance(&account(1), 100000);
+
+		assert_ok!(create_order(OrderType::Ask, account(1), 1, 10000, 0));
+		assert_ok!(create_order(OrderType::Bid, account(1), 2, 5000, 1));
+		assert_ok!(create_order(OrderType::CollectionBid { quantity: 2 }, account(1), 0, 1000, 2));
+	}
+
+	#[test]
+	fn orders_are_indexed() {
+		new_test_ext().execute_with(|| {
+			open_orders_setup();
+
+			assert_eq!(
+				AccountOrders::<Test>::get(account(1)).into_inner(),
+				vec![
+					OrderKey::Ask { collection: 0, item: 1 },
+					OrderKey::Bid { collection: 0, item: 2, price: 5000 },
+					OrderKey::CollectionBid { collection: 0, price: 1000, buyer: account(1) },
+				]
+			);
+
+			assert_ok!(Marketplace::cancel_order(
+				RuntimeOrigin::signed(account(1)),
+				OrderType::Bid,
+				0,
+				2,
+				5000
+			));
+			assert_eq!(
+				AccountOrders::<Test>::get(account(1)).into_inner(),
+				vec![
+					OrderKey::Ask { collection: 0, item: 1 },
+					OrderKey::CollectionBid { collection: 0, price: 1000, buyer: account(1) },
+				]
+			);
+		})
+	}
+
+	#[test]
+	fn executed_orders_are_unindexed() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			mint_item(1, account(1));
+			Balances::set_balance(&account(2), 100000);
+
+			assert_ok!(create_order(OrderType::Ask, account(1), 1, 10000, 0));
+			assert_ok!(create_order(OrderType::Bid, account(2), 1, 10000, 1));
+
+			assert_eq!(Nfts::owner(0, 1), Some(account(2)));
+			assert!(!AccountOrders::<Test>::contains_key(account(1)));
+			assert!(!AccountOrders::<Test>::contains_key(account(2)));
+		})
+	}
+
+	#[test]
+	fn too_many_orders() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			mint_item(1, account(1));
+			Balances::set_balance(&account(2), 100000);
+
+			let max_orders: u32 = <Test as Config>::MaxOrdersPerAccount::get();
+			for i in 0..max_orders {
+				assert_ok!(create_order(OrderType::Bid, account(2), 1, 1000 + i as u128, i as u8));
+			}
+			assert_noop!(
+				create_order(OrderType::Bid, account(2), 1, 500, max_orders as u8),
+				Error::<Test>::TooManyOrders
+			);
+		})
+	}
+
+	#[test]
+	fn cancel_all_orders() {
+		new_test_ext().execute_with(|| {
+			open_orders_setup();
+
+			assert_ok!(Marketplace::cancel_all_orders(RuntimeOrigin::signed(account(1))));
+
+			assert!(!AccountOrders::<Test>::contains_key(account(1)));
+			assert!(!Asks::<Test>::contains_key(0, 1));
+			assert!(!Bids::<Test>::contains_key((0, 2, 5000)));
+			assert!(!CollectionBids::<Test>::contains_key((0, 1000, account(1))));
+			assert!(Nfts::can_transfer(&0, &1));
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::MarketplaceBid.into(), &account(1)),
+				0
+			);
+			System::assert_last_event(
+				Event::AllOrdersCanceled { who: account(1), count: 3 }.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn cancel_all_orders_skips_failing_orders() {
+		new_test_ext().execute_with(|| {
+			open_orders_setup();
+			// The funds held for the Bids can't be released anymore.
+			assert_ok!(<Balances as frame_support::traits::fungible::MutateHold<_>>::release_all(
+				&HoldReason::MarketplaceBid.into(),
+				&account(1),
+				frame_support::traits::tokens::Precision::BestEffort
+			));
+
+			assert_ok!(Marketplace::cancel_all_orders(RuntimeOrigin::signed(account(1))));
+
+			assert!(!Asks::<Test>::contains_key(0, 1));
+			assert!(Nfts::can_transfer(&0, &1));
+			assert!(Bids::<Test>::contains_key((0, 2, 5000)));
+			assert!(CollectionBids::<Test>::contains_key((0, 1000, account(1))));
+			assert_eq!(
+				Marketplace::orders_by_account(&account(1)),
+				vec![
+					OrderKey::Bid { collection: 0, item: 2, price: 5000 },
+					OrderKey::CollectionBid { collection: 0, price: 1000, buyer: account(1) }
+				]
+			);
+			System::assert_last_event(
+				Event::AllOrdersCanceled { who: account(1), count: 1 }.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn cancel_all_orders_without_orders() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Marketplace::cancel_all_orders(RuntimeOrigin::signed(account(1))));
+			System::assert_last_event(
+				Event::AllOrdersCanceled { who: account(1), count: 0 }.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn force_cancel_all_orders() {
+		new_test_ext().execute_with(|| {
+			open_orders_setup();
+			let admin = Authority::<Test>::get().unwrap();
+
+			assert_noop!(
+				Marketplace::force_cancel_all_orders(RuntimeOrigin::signed(account(2)), account(1)),
+				Error::<Test>::NotAuthority
+			);
+			assert_ok!(Marketplace::force_cancel_all_orders(
+				RuntimeOrigin::signed(admin),
+				account(1)
+			));
+
+			assert!(!AccountOrders::<Test>::contains_key(account(1)));
+			assert!(Nfts::can_transfer(&0, &1));
+			assert_eq!(
+				Balances::balance_on_hold(&HoldReason::MarketplaceBid.into(), &account(1)),
+				0
+			);
+			System::assert_last_event(
+				Event::AllOrdersCanceled { who: account(1), count: 3 }.into(),
+			);
+		})
+	}
+}
+
+mod bundles {
+	use super::*;
+	use sp_runtime::{DispatchResult, Perbill};
+
+	fn bundle(items: Vec<(CollectionId<Test>, ItemId)>) -> BundleItemsOf<Test> {
+		items.try_into().unwrap()
+	}
+
+	/// Mints items 1 and 2 of collection 0 and item 1 of collection 1 to `account(1)`.
+	fn mint_bundle() -> BundleItemsOf<Test> {
+		mint_item(1, account(1));
+		mint_item(2, account(1));
+		assert_ok!(Nfts::create(
+			RuntimeOrigin::signed(account(1)),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+		assert_ok!(Nfts::mint(RuntimeOrigin::signed(account(1)), 1, Some(1), account(1), None));
+
+		bundle(vec![(0, 1), (0, 2), (1, 1)])
+	}
+
+	fn create_bundle_order(
+		order_type: OrderType,
+		who: AccountIdOf<Test>,
+		items: BundleItemsOf<Test>,
+		price: Balance<Test>,
+		fee: Balance<Test>,
+		nonce: u8,
+		execution: Execution,
+	) -> DispatchResult {
+		let fee_signer_pair = Pair::from_string("//Alice", None).unwrap();
+		let mut order = BundleOrder {
+			order_type,
+			items,
+			price,
+			expires_at: get_valid_expiration(),
+			fee,
+			asset: None,
+			signature_data: SignatureData {
+				signature: raw_signature([0; 65]),
+				nonce: vec![nonce],
+				deadline: NONCE_DEADLINE,
+			},
+		};
+		let message: BundleOrderMessageOf<Test> = order.clone().into();
+		order.signature_data.signature = EthereumSignature::from(
+			fee_signer_pair.sign_prehashed(&keccak_256(&(message, NONCE_DEADLINE).encode())),
+		);
+
+		Marketplace::create_bundle_order(RuntimeOrigin::signed(who), order, execution)
+	}
+
+	#[test]
+	fn ask_locks_all_items() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			let items = mint_bundle();
+			let bundle_id = Marketplace::bundle_id(&items);
+
+			assert_ok!(create_bundle_order(
+				OrderType::Ask,
+				account(1),
+				items.clone(),
+				9000,
+				100,
+				0,
+				Execution::AllowCreation
+			));
+
+			assert_eq!(
+				BundleAsks::<Test>::get(bundle_id),
+				Some(BundleAsk {
+					seller: account(1),
+					items: items.clone(),
+					price: 9000,
+					expiration: get_valid_expiration(),
+					fee: 100,
+					asset: None,
+				})
+			);
+			for (collection, item) in items.iter() {
+				assert!(!Nfts::can_transfer(collection, item));
+			}
+			assert_eq!(
+				AccountOrders::<Test>::get(account(1)).into_inner(),
+				vec![OrderKey::BundleAsk { bundle: bundle_id }]
+			);
+		})
+	}
+
+	#[test]
+	fn invalid_bundles() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			let _ = mint_bundle();
+
+			for (order_type, items) in [
+				(OrderType::Ask, bundle(vec![])),
+				(OrderType::Ask, bundle(vec![(0, 1), (0, 2), (0, 1)])),
+				(OrderType::CollectionBid { quantity: 1 }, bundle(vec![(0, 1)])),
+			] {
+				assert_noop!(
+					create_bundle_order(
+						order_type,
+						account(1),
+						items,
+						9000,
+						100,
+						0,
+						Execution::AllowCreation
+					),
+					Error::<Test>::InvalidBundle
+				);
+			}
+		})
+	}
+
+	#[test]
+	fn ask_fails_if_any_item_is_locked() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			let items = mint_bundle();
+			assert_ok!(Nfts::disable_transfer(&1, &1));
+
+			assert_noop!(
+				create_bundle_order(
+					OrderType::Ask,
+					account(1),
+					items,
+					9000,
+					100,
+					0,
+					Execution::AllowCreation
+				),
+				Error::<Test>::ItemAlreadyLocked
+			);
+			assert!(Nfts::can_transfer(&0, &1));
+			assert!(Nfts::can_transfer(&0, &2));
+		})
+	}
+
+	#[test]
+	fn ask_fails_if_any_item_is_not_owned() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			let _ = mint_bundle();
+			mint_item(3, account(2));
+
+			assert_noop!(
+				create_bundle_order(
+					OrderType::Ask,
+					account(1),
+					bundle(vec![(0, 1), (0, 3)]),
+					9000,
+					100,
+					0,
+					Execution::AllowCreation
+				),
+				Error::<Test>::NotItemOwner
+			);
+		})
+	}
+
+	#[test]
+	fn bid_fails_if_any_item_is_missing() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			let _ = mint_bundle();
+			Balances::set_balance(&account(2), 100000);
+
+			assert_noop!(
+				create_bundle_order(
+					OrderType::Bid,
+					account(2),
+					bundle(vec![(0, 1), (0, 5)]),
+					9000,
+					10,
+					0,
+					Execution::AllowCreation
+				),
+				Error::<Test>::ItemNotFound
+			);
+		})
+	}
+
+	#[test]
+	fn bid_executes_bundle_ask() {
+		new_test_ext().execute_with(|| {
+			let (payout_address, _) = admin_accounts_setup();
+			let items = mint_bundle();
+			let bundle_id = Marketplace::bundle_id(&items);
+			let seller = account(1);
+			let buyer = account(2);
+			Balances::set_balance(&buyer, 100000);
+			let creator = account(3);
+			Balances::set_balance(&creator, 1000);
+			assert_ok!(Marketplace::set_collection_royalty(
+				RuntimeOrigin::signed(seller),
+				0,
+				Some(Royalty { recipient: creator, rate: Perbill::from_percent(10) })
+			));
+
+			assert_ok!(create_bundle_order(
+				OrderType::Ask,
+				seller,
+				items.clone(),
+				9000,
+				100,
+				0,
+				Execution::AllowCreation
+			));
+			let seller_balance_before = Balances::balance(&seller);
+			let payout_balance_before = Balances::balance(&payout_address);
+
+			assert_ok!(create_bundle_order(
+				OrderType::Bid,
+				buyer,
+				items.clone(),
+				9000,
+				10,
+				1,
+				Execution::Force
+			));
+
+			for (collection, item) in items.iter() {
+				assert_eq!(Nfts::owner(*collection, *item), Some(buyer));
+				assert!(Nfts::can_transfer(collection, item));
+			}
+			// Two of the three items are in the collection with a royalty
+			assert_eq!(Balances::balance(&creator), 1000 + 600);
+			assert_eq!(Balances::balance(&seller), seller_balance_before + 9000 - 100 - 600);
+			assert_eq!(Balances::balance(&payout_address), payout_balance_before + 110);
+			assert_eq!(Balances::balance(&buyer), 100000 - 9010);
+			assert_eq!(Balances::balance_on_hold(&HoldReason::MarketplaceBid.into(), &buyer), 0);
+			assert!(BundleAsks::<Test>::get(bundle_id).is_none());
+			assert!(AccountOrders::<Test>::get(seller).is_empty());
+			System::assert_last_event(
+				Event::BundleOrderExecuted {
+					bundle: bundle_id,
+					items,
+					seller,
+					buyer,
+					price: 9000,
+					seller_fee: 100,
+					buyer_fee: 10,
+					royalty: 600,
+					asset: None,
+				}
+				.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn bid_fails_if_bundle_item_changed_owner() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			let items = mint_bundle();
+			Balances::set_balance(&account(2), 100000);
+
+			assert_ok!(create_bundle_order(
+				OrderType::Ask,
+				account(1),
+				items.clone(),
+				9000,
+				100,
+				0,
+				Execution::AllowCreation
+			));
+			// One of the locked items is moved out of the seller account anyway
+			assert_ok!(Nfts::enable_transfer(&0, &2));
+			assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(1)), 0, 2, account(3)));
+
+			assert_noop!(
+				create_bundle_order(
+					OrderType::Bid,
+					account(2),
+					items,
+					9000,
+					10,
+					1,
+					Execution::Force
+				),
+				Error::<Test>::NotItemOwner
+			);
+		})
+	}
+
+	#[test]
+	fn ask_executes_bundle_bid() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			let items = mint_bundle();
+			let bundle_id = Marketplace::bundle_id(&items);
+			let buyer = account(2);
+			Balances::set_balance(&buyer, 100000);
+
+			assert_ok!(create_bundle_order(
+				OrderType::Bid,
+				buyer,
+				items.clone(),
+				9000,
+				10,
+				0,
+				Execution::AllowCreation
+			));
+			assert_eq!(Balances::balance_on_hold(&HoldReason::MarketplaceBid.into(), &buyer), 9010);
+
+			assert_ok!(create_bundle_order(
+				OrderType::Ask,
+				account(1),
+				items.clone(),
+				9000,
+				100,
+				1,
+				Execution::Force
+			));
+
+			for (collection, item) in items.iter() {
+				assert_eq!(Nfts::owner(*collection, *item), Some(buyer));
+			}
+			assert!(BundleBids::<Test>::get(bundle_id, 9000).is_none());
+			assert!(AccountOrders::<Test>::get(buyer).is_empty());
+		})
+	}
+
+	#[test]
+	fn items_in_other_order_do_not_match() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			let items = mint_bundle();
+			Balances::set_balance(&account(2), 100000);
+
+			assert_ok!(create_bundle_order(
+				OrderType::Ask,
+				account(1),
+				items,
+				9000,
+				100,
+				0,
+				Execution::AllowCreation
+			));
+			assert_noop!(
+				create_bundle_order(
+					OrderType::Bid,
+					account(2),
+					bundle(vec![(1, 1), (0, 2), (0, 1)]),
+					9000,
+					10,
+					1,
+					Execution::Force
+				),
+				Error::<Test>::ValidMatchMustExist
+			);
+		})
+	}
+
+	#[test]
+	fn cancel_bundle_orders() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			let items = mint_bundle();
+			let bundle_id = Marketplace::bundle_id(&items);
+			let buyer = account(2);
+			Balances::set_balance(&buyer, 100000);
+
+			assert_ok!(create_bundle_order(
+				OrderType::Ask,
+				account(1),
+				items.clone(),
+				9000,
+				100,
+				0,
+				Execution::AllowCreation
+			));
+			assert_ok!(create_bundle_order(
+				OrderType::Bid,
+				buyer,
+				items.clone(),
+				8000,
+				10,
+				1,
+				Execution::AllowCreation
+			));
+
+			assert_noop!(
+				Marketplace::cancel_bundle_order(
+					RuntimeOrigin::signed(buyer),
+					OrderType::Ask,
+					bundle_id,
+					0
+				),
+				Error::<Test>::NotOrderCreatorOrAdmin
+			);
+
+			assert_ok!(Marketplace::cancel_bundle_order(
+				RuntimeOrigin::signed(account(1)),
+				OrderType::Ask,
+				bundle_id,
+				0
+			));
+			for (collection, item) in items.iter() {
+				assert!(Nfts::can_transfer(collection, item));
+			}
+			System::assert_last_event(
+				Event::BundleOrderCanceled { bundle: bundle_id, who: account(1) }.into(),
+			);
+
+			assert_ok!(Marketplace::cancel_bundle_order(
+				RuntimeOrigin::signed(buyer),
+				OrderType::Bid,
+				bundle_id,
+				8000
+			));
+			assert_eq!(Balances::balance_on_hold(&HoldReason::MarketplaceBid.into(), &buyer), 0);
+			assert!(BundleAsks::<Test>::get(bundle_id).is_none());
+			assert!(BundleBids::<Test>::get(bundle_id, 8000).is_none());
+		})
+	}
+
+	#[test]
+	fn bundle_orders_expire() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			let items = mint_bundle();
+			let bundle_id = Marketplace::bundle_id(&items);
+
+			assert_ok!(create_bundle_order(
+				OrderType::Ask,
+				account(1),
+				items.clone(),
+				9000,
+				100,
+				0,
+				Execution::AllowCreation
+			));
+
+			assert!(Marketplace::expire_order(
+				OrderKey::BundleAsk { bundle: bundle_id },
+				get_valid_expiration(),
+			));
+
+			assert!(BundleAsks::<Test>::get(bundle_id).is_none());
+			for (collection, item) in items.iter() {
+				assert!(Nfts::can_transfer(collection, item));
+			}
+			System::assert_last_event(
+				Event::OrderExpired {
+					order: OrderKey::BundleAsk { bundle: bundle_id },
+					who: account(1),
+				}
+				.into(),
+			);
+		})
+	}
+}
+
+mod update_order {
+	use super::*;
+
+	fn order_update(
+		order_type: OrderType,
+		current_price: Balance<Test>,
+		price: Balance<Test>,
+		expires_at: Moment<Test>,
+		fee: Balance<Test>,
+		nonce: u8,
+	) -> OrderUpdateOf<Test> {
+		let fee_signer_pair = Pair::from_string("//Alice", None).unwrap();
+		let mut update = OrderUpdate {
+			order_type,
+			collection: 0,
+			item: 1,
+			current_price,
+			price,
+			expires_at,
+			fee,
+			signature_data: SignatureData {
+				signature: raw_signature([0; 65]),
+				nonce: vec![nonce],
+				deadline: NONCE_DEADLINE,
+			},
+		};
+		let message: OrderUpdateMessageOf<Test> = update.clone().into();
+		update.signature_data.signature = EthereumSignature::from(
+			fee_signer_pair.sign_prehashed(&keccak_256(&(message, NONCE_DEADLINE).encode())),
+		);
+		update
+	}
+
+	fn on_hold(who: AccountIdOf<Test>) -> Balance<Test> {
+		Balances::balance_on_hold(&HoldReason::MarketplaceBid.into(), &who)
+	}
+
+	#[test]
+	fn ask_updated() {
+		new_test_ext().execute_with(|| {
+			let (admin, _) = admin_accounts_setup();
+			let seller = account(1);
+			create_valid_order(OrderType::Ask, seller, seller);
+			let expiration = get_valid_expiration();
+			let new_expiration = expiration + 100000;
+
+			assert_ok!(Marketplace::update_order(
+				RuntimeOrigin::signed(seller),
+				order_update(OrderType::Ask, 0, 12000, new_expiration, 2, 1)
+			));
+
+			assert_eq!(
+				Asks::<Test>::get(0, 1),
+				Some(Ask {
+					seller,
+					price: 12000,
+					expiration: new_expiration,
+					fee: 2,
+					escrow_agent: None,
+					escrow_settlement: EscrowSettlement::Agent,
+					asset: None,
+					buyer: None,
+				})
+			);
+			assert!(!Nfts::can_transfer(&0, &1));
+			let key = OrderKey::Ask { collection: 0, item: 1 };
+			assert!(!OrderExpirations::<Test>::contains_key(
+				Marketplace::expiration_slot(expiration),
+				&key
+			));
+			assert!(OrderExpirations::<Test>::contains_key(
+				Marketplace::expiration_slot(new_expiration),
+				&key
+			));
+			assert_eq!(AccountOrders::<Test>::get(seller).into_inner(), vec![key]);
+			System::assert_last_event(
+				Event::OrderUpdated {
+					who: seller,
+					order_type: OrderType::Ask,
+					collection: 0,
+					item: 1,
+					price: 12000,
+					expires_at: new_expiration,
+					fee: 2,
+					fee_signer: admin,
+				}
+				.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn bid_hold_adjusted_by_difference() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			let buyer = account(2);
+			create_valid_order(OrderType::Bid, buyer, account(1));
+			assert_eq!(on_hold(buyer), 10001);
+			let expires_at = get_valid_expiration();
+
+			assert_ok!(Marketplace::update_order(
+				RuntimeOrigin::signed(buyer),
+				order_update(OrderType::Bid, 10000, 12000, expires_at, 5, 1)
+			));
+			assert_eq!(on_hold(buyer), 12005);
+			assert!(Bids::<Test>::get((0, 1, 10000)).is_none());
+			assert_eq!(
+				Bids::<Test>::get((0, 1, 12000)),
+				Some(Bid { buyer, expiration: expires_at, fee: 5, asset: None })
+			);
+			assert_eq!(
+				AccountOrders::<Test>::get(buyer).into_inner(),
+				vec![OrderKey::Bid { collection: 0, item: 1, price: 12000 }]
+			);
+
+			assert_ok!(Marketplace::update_order(
+				RuntimeOrigin::signed(buyer),
+				order_update(OrderType::Bid, 12000, 8000, expires_at, 1, 2)
+			));
+			assert_eq!(on_hold(buyer), 8001);
+			assert_eq!(Balances::balance(&buyer), 100000 - 8001);
+		})
+	}
+
+	#[test]
+	fn bid_update_fails_without_funds() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			let buyer = account(2);
+			create_valid_order(OrderType::Bid, buyer, account(1));
+
+			assert_noop!(
+				Marketplace::update_order(
+					RuntimeOrigin::signed(buyer),
+					order_update(OrderType::Bid, 10000, 200000, get_valid_expiration(), 1, 1)
+				),
+				Error::<Test>::InsufficientFunds
+			);
+		})
+	}
+
+	#[test]
+	fn updated_ask_executes_matching_bid() {
+		new_test_ext().execute_with(|| {
+			let (payout_address, fee_signer_pair) = admin_accounts_setup();
+			let seller = account(1);
+			let buyer = account(2);
+			create_valid_order(OrderType::Bid, buyer, seller);
+
+			let mut ask = Order {
+				order_type: OrderType::Ask,
+				collection: 0,
+				item: 1,
+				expires_at: get_valid_expiration(),
+				price: 12000,
+				fee: 1,
+				escrow_agent: None,
+				escrow_settlement: EscrowSettlement::Agent,
+				asset: None,
+				signature_data: SignatureData {
+					signature: raw_signature([0; 65]),
+					nonce: vec![1],
+					deadline: NONCE_DEADLINE,
+				},
+				buyer: None,
+			};
+			append_valid_signature(fee_signer_pair, &mut ask);
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(seller),
+				ask,
+				Execution::AllowCreation
+			));
+			let payout_balance_before = Balances::balance(&payout_address);
+
+			assert_ok!(Marketplace::update_order(
+				RuntimeOrigin::signed(seller),
+				order_update(OrderType::Ask, 0, 10000, get_valid_expiration(), 1, 2)
+			));
+
+			assert_eq!(Nfts::owner(0, 1), Some(buyer));
+			assert!(Asks::<Test>::get(0, 1).is_none());
+			assert!(Bids::<Test>::get((0, 1, 10000)).is_none());
+			assert!(AccountOrders::<Test>::get(seller).is_empty());
+			assert!(AccountOrders::<Test>::get(buyer).is_empty());
+			assert_eq!(on_hold(buyer), 0);
+			assert_eq!(Balances::balance(&payout_address), payout_balance_before + 2);
+		})
+	}
+
+	#[test]
+	fn only_creator_can_update() {
+		new_test_ext().execute_with(|| {
+			let (admin, _) = admin_accounts_setup();
+			create_valid_order(OrderType::Ask, account(1), account(1));
+
+			assert_noop!(
+				Marketplace::update_order(
+					RuntimeOrigin::signed(admin),
+					order_update(OrderType::Ask, 0, 12000, get_valid_expiration(), 1, 1)
+				),
+				Error::<Test>::NotOrderCreator
+			);
+		})
+	}
+
+	#[test]
+	fn ask_approval_not_replayable_on_bid() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			let buyer = account(2);
+			create_valid_order(OrderType::Bid, buyer, account(1));
+			let expires_at = get_valid_expiration();
+
+			let update = OrderUpdate {
+				order_type: OrderType::Bid,
+				..order_update(OrderType::Ask, 10000, 12000, expires_at, 5, 1)
+			};
+			assert_noop!(
+				Marketplace::update_order(RuntimeOrigin::signed(buyer), update),
+				Error::<Test>::BadSignedMessage
+			);
+		})
+	}
+
+	#[test]
+	fn invalid_updates() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			let expires_at = get_valid_expiration();
+			mint_item(1, account(1));
+
+			assert_noop!(
+				Marketplace::update_order(
+					RuntimeOrigin::signed(account(1)),
+					order_update(OrderType::Ask, 0, 12000, expires_at, 1, 1)
+				),
+				Error::<Test>::OrderNotFound
+			);
+			assert_noop!(
+				Marketplace::update_order(
+					RuntimeOrigin::signed(account(1)),
+					order_update(
+						OrderType::CollectionBid { quantity: 1 },
+						0,
+						12000,
+						expires_at,
+						1,
+						1
+					)
+				),
+				Error::<Test>::InvalidOrderType
+			);
+			assert_noop!(
+				Marketplace::update_order(
+					RuntimeOrigin::signed(account(1)),
+					order_update(OrderType::Ask, 0, 12000, 0, 1, 1)
+				),
+				Error::<Test>::InvalidExpiration
+			);
+		})
+	}
+}
+
+mod nonces {
+	use super::*;
+	use frame_support::{traits::Hooks, weights::Weight};
+
+	fn bid_order(deadline: Moment<Test>) -> OrderOf<Test> {
+		let fee_signer_pair = Pair::from_string("//Alice", None).unwrap();
+		let mut order = Order {
+			order_type: OrderType::Bid,
+			collection: 0,
+			item: 1,
+			expires_at: get_valid_expiration(),
+			price: 10000,
+			fee: 1,
+			escrow_agent: None,
+			escrow_settlement: EscrowSettlement::Agent,
+			asset: None,
+			signature_data: SignatureData {
+				signature: raw_signature([0; 65]),
+				nonce: vec![1],
+				deadline,
+			},
+			buyer: None,
+		};
+		append_valid_signature(fee_signer_pair, &mut order);
+		order
+	}
+
+	#[test]
+	fn nonce_stored_with_deadline() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			mint_item(1, account(1));
+			Balances::set_balance(&account(2), 100000);
+
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(2)),
+				bid_order(25),
+				Execution::AllowCreation
+			));
+
+			let nonce: BoundedVec<u8, <Test as Config>::NonceStringLimit> =
+				vec![1].try_into().unwrap();
+			assert_eq!(Nonces::<Test>::get(&nonce), Some(25));
+			assert!(NonceExpirations::<Test>::contains_key(30, &nonce));
+			assert_eq!(NextNonceExpirationSlot::<Test>::get(), Some(30));
+		})
+	}
+
+	#[test]
+	fn expired_nonce_rejected() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			mint_item(1, account(1));
+			Balances::set_balance(&account(2), 100000);
+			Timestamp::set_timestamp(5);
+
+			assert_noop!(
+				Marketplace::create_order(
+					RuntimeOrigin::signed(account(2)),
+					bid_order(5),
+					Execution::AllowCreation
+				),
+				Error::<Test>::NonceExpired
+			);
+		})
+	}
+
+	#[test]
+	fn deadline_too_far_rejected() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			mint_item(1, account(1));
+			Balances::set_balance(&account(2), 100000);
+			Timestamp::set_timestamp(5);
+
+			assert_noop!(
+				Marketplace::create_order(
+					RuntimeOrigin::signed(account(2)),
+					bid_order(1_000_006),
+					Execution::AllowCreation
+				),
+				Error::<Test>::NonceDeadlineTooFar
+			);
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(2)),
+				bid_order(1_000_005),
+				Execution::AllowCreation
+			));
+		})
+	}
+
+	#[test]
+	fn deadline_is_signed() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			mint_item(1, account(1));
+			Balances::set_balance(&account(2), 100000);
+			let mut order = bid_order(25);
+			order.signature_data.deadline = 26;
+
+			assert_noop!(
+				Marketplace::create_order(
+					RuntimeOrigin::signed(account(2)),
+					order,
+					Execution::AllowCreation
+				),
+				Error::<Test>::BadSignedMessage
+			);
+		})
+	}
+
+	#[test]
+	fn nonces_pruned_on_idle() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			mint_item(1, account(1));
+			Balances::set_balance(&account(2), 100000);
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(2)),
+				bid_order(25),
+				Execution::AllowCreation
+			));
+
+			Timestamp::set_timestamp(29);
+			Marketplace::on_idle(System::block_number(), Weight::MAX);
+			assert_eq!(Nonces::<Test>::iter().count(), 1);
+
+			Timestamp::set_timestamp(30);
+			Marketplace::on_idle(System::block_number(), Weight::MAX);
+			assert_eq!(Nonces::<Test>::iter().count(), 0);
+			assert_eq!(NonceExpirations::<Test>::iter().count(), 0);
+			assert_eq!(NextNonceExpirationSlot::<Test>::get(), None);
+		})
+	}
+}
+
+mod fee_signers {
+	use super::*;
+	use sp_runtime::Perbill;
+
+	fn signer_pair(seed: &str) -> (AccountIdOf<Test>, KeyPair) {
+		let pair = Pair::from_string(seed, None).unwrap();
+		let signer: EthereumSigner = pair.public().into();
+		(signer.into_account(), pair)
+	}
+
+	fn bid_order(fee_signer_pair: KeyPair, fee: Balance<Test>) -> OrderOf<Test> {
+		let mut order = Order {
+			order_type: OrderType::Bid,
+			collection: 0,
+			item: 1,
+			expires_at: get_valid_expiration(),
+			price: 10000,
+			fee,
+			escrow_agent: None,
+			escrow_settlement: EscrowSettlement::Agent,
+			asset: None,
+			signature_data: SignatureData {
+				signature: raw_signature([0; 65]),
+				nonce: vec![1],
+				deadline: NONCE_DEADLINE,
+			},
+			buyer: None,
+		};
+		append_valid_signature(fee_signer_pair, &mut order);
+		order
+	}
+
+	fn setup() -> AccountIdOf<Test> {
+		let (admin, _) = admin_accounts_setup();
+		mint_item(1, account(1));
+		Balances::set_balance(&account(2), 100000);
+		admin
+	}
+
+	#[test]
+	fn add_fee_signer_works() {
+		new_test_ext().execute_with(|| {
+			let admin = setup();
+			let info = FeeSignerInfo {
+				valid_from: Some(10),
+				valid_until: None,
+				max_fee: Some(Perbill::from_percent(5)),
+			};
+
+			assert_ok!(Marketplace::add_fee_signer(
+				RuntimeOrigin::signed(admin),
+				account(3),
+				info.clone()
+			));
+
+			assert_eq!(
+				FeeSigners::<Test>::get().into_inner(),
+				vec![(admin, FeeSignerInfo::default()), (account(3), info.clone())]
+			);
+			System::assert_last_event(
+				Event::FeeSignerAdded { fee_signer: account(3), info }.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn add_existing_fee_signer_updates_it() {
+		new_test_ext().execute_with(|| {
+			let admin = setup();
+			let info = FeeSignerInfo { valid_from: None, valid_until: Some(10), max_fee: None };
+
+			assert_ok!(Marketplace::add_fee_signer(
+				RuntimeOrigin::signed(admin),
+				admin,
+				info.clone()
+			));
+
+			assert_eq!(FeeSigners::<Test>::get().into_inner(), vec![(admin, info)]);
+		})
+	}
+
+	#[test]
+	fn add_fails_not_authority() {
+		new_test_ext().execute_with(|| {
+			let _ = setup();
+
+			assert_noop!(
+				Marketplace::add_fee_signer(
+					RuntimeOrigin::signed(account(1)),
+					account(3),
+					FeeSignerInfo::default()
+				),
+				Error::<Test>::NotAuthority
+			);
+		})
+	}
+
+	#[test]
+	fn add_fails_too_many_fee_signers() {
+		new_test_ext().execute_with(|| {
+			let admin = setup();
+			let max: u32 = <Test as Config>::MaxFeeSigners::get();
+			for id in 1..max {
+				assert_ok!(Marketplace::add_fee_signer(
+					RuntimeOrigin::signed(admin),
+					account(id as u8),
+					FeeSignerInfo::default()
+				));
+			}
+
+			assert_noop!(
+				Marketplace::add_fee_signer(
+					RuntimeOrigin::signed(admin),
+					account(max as u8),
+					FeeSignerInfo::default()
+				),
+				Error::<Test>::TooManyFeeSigners
+			);
+		})
+	}
+
+	#[test]
+	fn remove_fee_signer_works() {
+		new_test_ext().execute_with(|| {
+			let admin = setup();
+			let (bob, bob_pair) = signer_pair("//Bob");
+			assert_ok!(Marketplace::add_fee_signer(
+				RuntimeOrigin::signed(admin),
+				bob,
+				FeeSignerInfo::default()
+			));
+
+			assert_ok!(Marketplace::remove_fee_signer(RuntimeOrigin::signed(admin), bob));
+
+			assert_eq!(
+				FeeSigners::<Test>::get().into_inner(),
+				vec![(admin, FeeSignerInfo::default())]
+			);
+			System::assert_last_event(Event::FeeSignerRemoved { fee_signer: bob }.into());
+			assert_noop!(
+				Marketplace::create_order(
+					RuntimeOrigin::signed(account(2)),
+					bid_order(bob_pair, 1),
+					Execution::AllowCreation
+				),
+				Error::<Test>::BadSignedMessage
+			);
+		})
+	}
+
+	#[test]
+	fn remove_fails_fee_signer_not_found() {
+		new_test_ext().execute_with(|| {
+			let admin = setup();
+
+			assert_noop!(
+				Marketplace::remove_fee_signer(RuntimeOrigin::signed(admin), account(3)),
+				Error::<Test>::FeeSignerNotFound
+			);
+		})
+	}
+
+	#[test]
+	fn set_fee_signer_address_replaces_fee_signers() {
+		new_test_ext().execute_with(|| {
+			let admin = setup();
+			assert_ok!(Marketplace::add_fee_signer(
+				RuntimeOrigin::signed(admin),
+				account(3),
+				FeeSignerInfo::default()
+			));
+
+			assert_ok!(Marketplace::set_fee_signer_address(
+				RuntimeOrigin::signed(admin),
+				account(4)
+			));
+
+			assert_eq!(
+				FeeSigners::<Test>::get().into_inner(),
+				vec![(account(4), FeeSignerInfo::default())]
+			);
+		})
+	}
+
+	#[test]
+	fn rotation_accepts_both_signers() {
+		new_test_ext().execute_with(|| {
+			let admin = setup();
+			let (_, admin_pair) = signer_pair("//Alice");
+			let (bob, bob_pair) = signer_pair("//Bob");
+			assert_ok!(Marketplace::add_fee_signer(
+				RuntimeOrigin::signed(admin),
+				admin,
+				FeeSignerInfo { valid_from: None, valid_until: Some(20), max_fee: None }
+			));
+			assert_ok!(Marketplace::add_fee_signer(
+				RuntimeOrigin::signed(admin),
+				bob,
+				FeeSignerInfo { valid_from: Some(10), valid_until: None, max_fee: None }
+			));
+			Timestamp::set_timestamp(15);
+
+			let order = bid_order(bob_pair, 1);
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(2)),
+				order.clone(),
+				Execution::AllowCreation
+			));
+			System::assert_last_event(
+				Event::OrderCreated {
+					who: account(2),
+					order_type: OrderType::Bid,
+					collection: 0,
+					item: 1,
+					price: 10000,
+					expires_at: order.expires_at,
+					fee: 1,
+					asset: None,
+					fee_signer: bob,
+				}
+				.into(),
+			);
+
+			let mut order = bid_order(admin_pair.clone(), 1);
+			order.price = 9000;
+			order.signature_data.nonce = vec![2];
+			append_valid_signature(admin_pair, &mut order);
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(2)),
+				order,
+				Execution::AllowCreation
+			));
+			assert!(matches!(
+				System::events().last().map(|record| record.event.clone()),
+				Some(RuntimeEvent::Marketplace(Event::OrderCreated { fee_signer, .. }))
+					if fee_signer == admin
+			));
+		})
+	}
+
+	#[test]
+	fn inactive_fee_signer_rejected() {
+		new_test_ext().execute_with(|| {
+			let admin = setup();
+			let (bob, bob_pair) = signer_pair("//Bob");
+			assert_ok!(Marketplace::add_fee_signer(
+				RuntimeOrigin::signed(admin),
+				bob,
+				FeeSignerInfo { valid_from: Some(10), valid_until: Some(20), max_fee: None }
+			));
+
+			for now in [5, 20] {
+				Timestamp::set_timestamp(now);
+				assert_noop!(
+					Marketplace::create_order(
+						RuntimeOrigin::signed(account(2)),
+						bid_order(bob_pair.clone(), 1),
+						Execution::AllowCreation
+					),
+					Error::<Test>::FeeSignerNotActive
+				);
+			}
+		})
+	}
+
+	#[test]
+	fn fee_above_signer_limit_rejected() {
+		new_test_ext().execute_with(|| {
+			let admin = setup();
+			let (bob, bob_pair) = signer_pair("//Bob");
+			assert_ok!(Marketplace::add_fee_signer(
+				RuntimeOrigin::signed(admin),
+				bob,
+				FeeSignerInfo {
+					valid_from: None,
+					valid_until: None,
+					max_fee: Some(Perbill::from_percent(1)),
+				}
+			));
+
+			assert_noop!(
+				Marketplace::create_order(
+					RuntimeOrigin::signed(account(2)),
+					bid_order(bob_pair.clone(), 101),
+					Execution::AllowCreation
+				),
+				Error::<Test>::FeeAboveSignerLimit
+			);
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(2)),
+				bid_order(bob_pair, 100),
+				Execution::AllowCreation
+			));
+		})
+	}
+}
+
+mod fee_policy {
+	use super::*;
+
+	fn policy(min_fee_bps: u16, max_fee_bps: u16) -> Option<FeePolicy> {
+		Some(FeePolicy { min_fee_bps, max_fee_bps })
+	}
+
+	fn bid_order(collection: CollectionId<Test>, fee: Balance<Test>) -> OrderOf<Test> {
+		let fee_signer_pair = Pair::from_string("//Alice", None).unwrap();
+		let mut order = Order {
+			order_type: OrderType::Bid,
+			collection,
+			item: 1,
+			expires_at: get_valid_expiration(),
+			price: 10000,
+			fee,
+			escrow_agent: None,
+			escrow_settlement: EscrowSettlement::Agent,
+			asset: None,
+			signature_data: SignatureData {
+				signature: raw_signature([0; 65]),
+				nonce: vec![fee as u8],
+				deadline: NONCE_DEADLINE,
+			},
+			buyer: None,
+		};
+		append_valid_signature(fee_signer_pair, &mut order);
+		order
+	}
+
+	fn setup() -> AccountIdOf<Test> {
+		let (admin, _) = admin_accounts_setup();
+		mint_item(1, account(1));
+		Balances::set_balance(&account(2), 100000);
+		admin
+	}
+
+	#[test]
+	fn set_fee_policy_works() {
+		new_test_ext().execute_with(|| {
+			let admin = setup();
+
+			assert_ok!(Marketplace::set_fee_policy(
+				RuntimeOrigin::signed(admin),
+				None,
+				policy(100, 500)
+			));
+			assert_eq!(DefaultFeePolicy::<Test>::get(), policy(100, 500));
+			System::assert_last_event(
+				Event::FeePolicySet { collection: None, policy: policy(100, 500) }.into(),
+			);
+
+			assert_ok!(Marketplace::set_fee_policy(
+				RuntimeOrigin::signed(admin),
+				Some(0),
+				policy(0, 1000)
+			));
+			assert_eq!(CollectionFeePolicies::<Test>::get(0), policy(0, 1000));
+
+			assert_ok!(Marketplace::set_fee_policy(RuntimeOrigin::signed(admin), Some(0), None));
+			assert_eq!(CollectionFeePolicies::<Test>::get(0), None);
+			System::assert_last_event(
+				Event::FeePolicySet { collection: Some(0), policy: None }.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn fails_not_authority() {
+		new_test_ext().execute_with(|| {
+			let _ = setup();
+
+			assert_noop!(
+				Marketplace::set_fee_policy(
+					RuntimeOrigin::signed(account(1)),
+					None,
+					policy(100, 500)
+				),
+				Error::<Test>::NotAuthority
+			);
+		})
+	}
+
+	#[test]
+	fn fails_invalid_fee_policy() {
+		new_test_ext().execute_with(|| {
+			let admin = setup();
+
+			assert_noop!(
+				Marketplace::set_fee_policy(RuntimeOrigin::signed(admin), None, policy(500, 100)),
+				Error::<Test>::InvalidFeePolicy
+			);
+			assert_noop!(
+				Marketplace::set_fee_policy(RuntimeOrigin::signed(admin), None, policy(0, 10001)),
+				Error::<Test>::InvalidFeePolicy
+			);
+		})
+	}
+
+	#[test]
+	fn fee_outside_default_policy_rejected() {
+		new_test_ext().execute_with(|| {
+			let admin = setup();
+			assert_ok!(Marketplace::set_fee_policy(
+				RuntimeOrigin::signed(admin),
+				None,
+				policy(100, 500)
+			));
+
+			for fee in [0, 99, 501] {
+				assert_noop!(
+					Marketplace::create_order(
+						RuntimeOrigin::signed(account(2)),
+						bid_order(0, fee),
+						Execution::AllowCreation
+					),
+					Error::<Test>::InvalidFeePercent
+				);
+			}
+			for fee in [100, 500] {
+				assert_ok!(Marketplace::create_order(
+					RuntimeOrigin::signed(account(2)),
+					bid_order(0, fee),
+					Execution::AllowCreation
+				));
+				assert_ok!(Marketplace::cancel_order(
+					RuntimeOrigin::signed(account(2)),
+					OrderType::Bid,
+					0,
+					1,
+					10000
+				));
+			}
+		})
+	}
+
+	#[test]
+	fn collection_policy_overrides_default_policy() {
+		new_test_ext().execute_with(|| {
+			let admin = setup();
+			assert_ok!(Marketplace::set_fee_policy(
+				RuntimeOrigin::signed(admin),
+				None,
+				policy(100, 500)
+			));
+			assert_ok!(Marketplace::set_fee_policy(
+				RuntimeOrigin::signed(admin),
+				Some(0),
+				policy(0, 50)
+			));
+
+			assert_noop!(
+				Marketplace::create_order(
+					RuntimeOrigin::signed(account(2)),
+					bid_order(0, 100),
+					Execution::AllowCreation
+				),
+				Error::<Test>::InvalidFeePercent
+			);
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(2)),
+				bid_order(0, 0),
+				Execution::AllowCreation
+			));
+		})
+	}
+
+	#[test]
+	fn update_outside_policy_rejected() {
+		new_test_ext().execute_with(|| {
+			let admin = setup();
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(2)),
+				bid_order(0, 100),
+				Execution::AllowCreation
+			));
+			assert_ok!(Marketplace::set_fee_policy(
+				RuntimeOrigin::signed(admin),
+				Some(0),
+				policy(100, 500)
+			));
+
+			let fee_signer_pair = Pair::from_string("//Alice", None).unwrap();
+			let mut update = OrderUpdate {
+				order_type: OrderType::Bid,
+				collection: 0,
+				item: 1,
+				current_price: 10000,
+				price: 10000,
+				expires_at: get_valid_expiration(),
+				fee: 0,
+				signature_data: SignatureData {
+					signature: raw_signature([0; 65]),
+					nonce: vec![0],
+					deadline: NONCE_DEADLINE,
+				},
+			};
+			let message: OrderUpdateMessageOf<Test> = update.clone().into();
+			update.signature_data.signature = EthereumSignature::from(
+				fee_signer_pair.sign_prehashed(&keccak_256(&(message, NONCE_DEADLINE).encode())),
+			);
+
+			assert_noop!(
+				Marketplace::update_order(RuntimeOrigin::signed(account(2)), update),
+				Error::<Test>::InvalidFeePercent
+			);
+		})
+	}
+}
+
+mod trading_halt {
+	use super::*;
+
+	fn bid_order(collection: CollectionId<Test>) -> OrderOf<Test> {
+		let fee_signer_pair = Pair::from_string("//Alice", None).unwrap();
+		let mut order = Order {
+			order_type: OrderType::Bid,
+			collection,
+			item: 1,
+			expires_at: get_valid_expiration(),
+			price: 10000,
+			fee: 1,
+			escrow_agent: None,
+			escrow_settlement: EscrowSettlement::Agent,
+			asset: None,
+			signature_data: SignatureData {
+				signature: raw_signature([0; 65]),
+				nonce: vec![1],
+				deadline: NONCE_DEADLINE,
+			},
+			buyer: None,
+		};
+		append_valid_signature(fee_signer_pair, &mut order);
+		order
+	}
+
+	#[test]
+	fn set_trading_paused_works() {
+		new_test_ext().execute_with(|| {
+			let (admin, _) = admin_accounts_setup();
+
+			assert_ok!(Marketplace::set_trading_paused(RuntimeOrigin::root(), true));
+			assert!(Paused::<Test>::get());
+			System::assert_last_event(Event::TradingPauseSet { paused: true }.into());
+
+			assert_ok!(Marketplace::set_trading_paused(RuntimeOrigin::signed(admin), false));
+			assert!(!Paused::<Test>::get());
+			System::assert_last_event(Event::TradingPauseSet { paused: false }.into());
+		})
+	}
+
+	#[test]
+	fn set_collection_halted_works() {
+		new_test_ext().execute_with(|| {
+			let (admin, _) = admin_accounts_setup();
+
+			assert_ok!(Marketplace::set_collection_halted(RuntimeOrigin::root(), 0, true));
+			assert!(HaltedCollections::<Test>::contains_key(0));
+			System::assert_last_event(
+				Event::CollectionHaltSet { collection: 0, halted: true }.into(),
+			);
+
+			assert_ok!(Marketplace::set_collection_halted(RuntimeOrigin::signed(admin), 0, false));
+			assert!(!HaltedCollections::<Test>::contains_key(0));
+		})
+	}
+
+	#[test]
+	fn fails_not_root_nor_authority() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+
+			assert_noop!(
+				Marketplace::set_trading_paused(RuntimeOrigin::signed(account(1)), true),
+				Error::<Test>::NotAuthority
+			);
+			assert_noop!(
+				Marketplace::set_collection_halted(RuntimeOrigin::signed(account(1)), 0, true),
+				Error::<Test>::NotAuthority
+			);
+			assert_noop!(Marketplace::set_trading_paused(RuntimeOrigin::none(), true), BadOrigin);
+		})
+	}
+
+	#[test]
+	fn paused_blocks_orders_but_not_cancellation() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			let seller = account(1);
+			create_valid_order(OrderType::Ask, seller, seller);
+			Balances::set_balance(&account(2), 100000);
+			assert_ok!(Marketplace::set_trading_paused(RuntimeOrigin::root(), true));
+
+			assert_noop!(
+				Marketplace::create_order(
+					RuntimeOrigin::signed(account(2)),
+					bid_order(0),
+					Execution::AllowCreation
+				),
+				Error::<Test>::TradingPaused
+			);
+
+			assert_ok!(Marketplace::cancel_order(
+				RuntimeOrigin::signed(seller),
+				OrderType::Ask,
+				0,
+				1,
+				10000
+			));
+
+			assert_ok!(Marketplace::set_trading_paused(RuntimeOrigin::root(), false));
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(2)),
+				bid_order(0),
+				Execution::AllowCreation
+			));
+		})
+	}
+
+	#[test]
+	fn halted_collection_blocks_orders() {
+		new_test_ext().execute_with(|| {
+			let _ = admin_accounts_setup();
+			mint_item(1, account(1));
+			Balances::set_balance(&account(2), 100000);
+			assert_ok!(Marketplace::set_collection_halted(RuntimeOrigin::root(), 0, true));
+
+			assert_noop!(
+				Marketplace::create_order(
+					RuntimeOrigin::signed(account(2)),
+					bid_order(0),
+					Execution::AllowCreation
+				),
+				Error::<Test>::CollectionHalted
+			);
+
+			assert_ok!(Marketplace::set_collection_halted(RuntimeOrigin::root(), 0, false));
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(2)),
+				bid_order(0),
+				Execution::AllowCreation
+			));
+		})
+	}
+}
+
+mod private_listings {
+	use super::*;
+
+	fn order(order_type: OrderType, buyer: Option<AccountIdOf<Test>>, nonce: u8) -> OrderOf<Test> {
+		let fee_signer_pair = Pair::from_string("//Alice", None).unwrap();
+		let mut order = Order {
+			order_type,
+			collection: 0,
+			item: 1,
+			expires_at: get_valid_expiration(),
+			price: 10000,
+			fee: 1,
+			escrow_agent: None,
+			escrow_settlement: EscrowSettlement::Agent,
+			asset: None,
+			signature_data: SignatureData {
+				signature: raw_signature([0; 65]),
+				nonce: vec![nonce],
+				deadline: NONCE_DEADLINE,
+			},
+			buyer,
+		};
+		append_valid_signature(fee_signer_pair, &mut order);
+		order
+	}
+
+	fn setup() {
+		let _ = admin_accounts_setup();
+		mint_item(1, account(1));
+		Balances::set_balance(&account(2), 100000);
+		Balances::set_balance(&account(3), 100000);
+	}
+
+	#[test]
+	fn private_ask_created() {
+		new_test_ext().execute_with(|| {
+			setup();
+
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(1)),
+				order(OrderType::Ask, Some(account(2)), 0),
+				Execution::AllowCreation
+			));
+
+			assert_eq!(Asks::<Test>::get(0, 1).unwrap().buyer, Some(account(2)));
+		})
+	}
+
+	#[test]
+	fn buyer_only_for_asks() {
+		new_test_ext().execute_with(|| {
+			setup();
+
+			assert_noop!(
+				Marketplace::create_order(
+					RuntimeOrigin::signed(account(2)),
+					order(OrderType::Bid, Some(account(3)), 0),
+					Execution::AllowCreation
+				),
+				Error::<Test>::BuyerOnlyForAsks
+			);
+		})
+	}
+
+	#[test]
+	fn buyer_is_seller() {
+		new_test_ext().execute_with(|| {
+			setup();
+
+			assert_noop!(
+				Marketplace::create_order(
+					RuntimeOrigin::signed(account(1)),
+					order(OrderType::Ask, Some(account(1)), 0),
+					Execution::AllowCreation
+				),
+				Error::<Test>::BuyerIsSeller
+			);
+		})
+	}
+
+	#[test]
+	fn buyer_is_signed() {
+		new_test_ext().execute_with(|| {
+			setup();
+			let mut ask = order(OrderType::Ask, Some(account(2)), 0);
+			ask.buyer = Some(account(3));
+
+			assert_noop!(
+				Marketplace::create_order(
+					RuntimeOrigin::signed(account(1)),
+					ask,
+					Execution::AllowCreation
+				),
+				Error::<Test>::BadSignedMessage
+			);
+		})
+	}
+
+	#[test]
+	fn only_buyer_can_buy() {
+		new_test_ext().execute_with(|| {
+			setup();
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(1)),
+				order(OrderType::Ask, Some(account(2)), 0),
+				Execution::AllowCreation
+			));
+
+			assert_noop!(
+				Marketplace::create_order(
+					RuntimeOrigin::signed(account(3)),
+					order(OrderType::Bid, None, 1),
+					Execution::Force
+				),
+				Error::<Test>::ValidMatchMustExist
+			);
+
+			// The bid of another account is stored without being executed
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(3)),
+				order(OrderType::Bid, None, 1),
+				Execution::AllowCreation
+			));
+			assert!(Asks::<Test>::contains_key(0, 1));
+			assert!(Bids::<Test>::contains_key((0, 1, 10000)));
+			assert_ok!(Marketplace::cancel_order(
+				RuntimeOrigin::signed(account(3)),
+				OrderType::Bid,
+				0,
+				1,
+				10000
+			));
+
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(2)),
+				order(OrderType::Bid, None, 2),
+				Execution::Force
+			));
+			assert!(!Asks::<Test>::contains_key(0, 1));
+			assert_eq!(Nfts::owner(0, 1), Some(account(2)));
+		})
+	}
+
+	#[test]
+	fn private_ask_only_matches_bid_of_buyer() {
+		new_test_ext().execute_with(|| {
+			setup();
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(3)),
+				order(OrderType::Bid, None, 0),
+				Execution::AllowCreation
+			));
+
+			assert_ok!(Marketplace::create_order(
+				RuntimeOrigin::signed(account(1)),
+				order(OrderType::Ask, Some(account(2)), 1),
+				Execution::AllowCreation
+			));
+
+			assert!(Asks::<Test>::contains_key(0, 1));
+			assert!(Bids::<Test>::contains_key((0, 1, 10000)));
+			assert_eq!(Nfts::owner(0, 1), Some(account(1)));
+		})
+	}
+}
+
+mod stats {
+	use super::*;
+
+	fn order(order_type: OrderType, item: u128, price: Balance<Test>, nonce: u8) -> OrderOf<Test> {
+		let fee_signer_pair = Pair::from_string("//Alice", None).unwrap();
+		let mut order = Order {
+			order_type,
+			collection: 0,
+			item,
+			expires_at: get_valid_expiration(),
+			price,
+			fee: 1,
+			escrow_agent: None,
+			escrow_settlement: EscrowSettlement::Agent,
+			asset: None,
+			signature_data: SignatureData {
+				signature: raw_signature([0; 65]),
+				nonce: vec![nonce],
+				deadline: NONCE_DEADLINE,
+			},
+			buyer: None,
+		};
+		append_valid_signature(fee_signer_pair, &mut order);
+		order
+	}
+
+	fn sell(item: u128, price: Balance<Test>) {
+		mint_item(item, account(1));
+		assert_ok!(Marketplace::create_order(
+			RuntimeOrigin::signed(account(1)),
+			order(OrderType::Ask, item, price, item as u8 * 2),
+			Execution::AllowCreation
+		));
+		assert_ok!(Marketplace::create_order(
+			RuntimeOrigin::signed(account(2)),
+			order(OrderType::Bid, item, price, item as u8 * 2 + 1),
+			Execution::Force
+		));
+	}
+
+	fn setup() {
+		let _ = admin_accounts_setup();
+		Balances::set_balance(&account(2), 100000);
+	}
+
+	#[test]
+	fn stats_updated_on_trades() {
+		new_test_ext().execute_with(|| {
+			setup();
+			assert_eq!(Marketplace::collection_stats(0, None), None);
+
+			sell(1, 1000);
+			sell(2, 3000);
+
+			assert_eq!(
+				Marketplace::collection_stats(0, None),
+				Some(CollectionStats { last_price: 3000, volume: 4000, trades: 2 })
+			);
+			assert_eq!(Marketplace::collection_stats(0, Some(0)), None);
+			assert_eq!(Marketplace::collection_stats(1, None), None);
+		})
+	}
+
+	#[test]
+	fn recent_sales_keep_the_newest() {
+		new_test_ext().execute_with(|| {
+			setup();
+
+			for item in 1..=4 {
+				sell(item, 1000 * item);
+			}
+
+			let sales = Marketplace::recent_sales(0);
+			assert_eq!(sales.len(), 3);
+			assert_eq!(
+				sales[0],
+				Sale {
+					item: 2,
+					seller: account(1),
+					buyer: account(2),
+					price: 2000,
+					asset: None,
+					timestamp: Timestamp::get(),
+				}
+			);
+			assert_eq!(sales.iter().map(|sale| sale.item).collect::<Vec<_>>(), vec![2, 3, 4]);
+			assert_eq!(Marketplace::collection_stats(0, None).unwrap().trades, 4);
+		})
+	}
+}
+
+mod escrow_settlement {
+	use super::*;
+
+	const SELLER_PROCEEDS: Balance<Test> = 10000 - 1;
+
+	fn order(
+		order_type: OrderType,
+		escrow_agent: Option<AccountIdOf<Test>>,
+		nonce: u8,
+	) -> OrderOf<Test> {
+		let fee_signer_pair = Pair::from_string("//Alice", None).unwrap();
+		let mut order = Order {
+			order_type,
+			collection: 0,
+			item: 1,
+			expires_at: get_valid_expiration(),
+			price: 10000,
+			fee: 1,
+			escrow_agent,
+			escrow_settlement: EscrowSettlement::Marketplace,
+			asset: None,
+			signature_data: SignatureData {
+				signature: raw_signature([0; 65]),
+				nonce: vec![nonce],
+				deadline: NONCE_DEADLINE,
+			},
+			buyer: None,
+		};
+		append_valid_signature(fee_signer_pair, &mut order);
+		order
+	}
+
+	/// Sells the item of `account(1)` to `account(2)`, settled in escrow with `account(3)`.
+	fn escrowed_trade() -> TradeEscrowOf<Test> {
+		let _ = admin_accounts_setup();
+		mint_item(1, account(1));
+		Balances::set_balance(&account(2), 100000);
+
+		assert_ok!(Marketplace::create_order(
+			RuntimeOrigin::signed(account(1)),
+			order(OrderType::Ask, Some(account(3)), 0),
+			Execution::AllowCreation
+		));
+		assert_ok!(Marketplace::create_order(
+			RuntimeOrigin::signed(account(2)),
+			order(OrderType::Bid, None, 1),
+			Execution::Force
+		));
+
+		Escrows::<Test>::get(0).unwrap()
+	}
+
+	#[test]
+	fn trade_linked_to_escrow() {
+		new_test_ext().execute_with(|| {
+			let escrow = escrowed_trade();
+
+			assert_eq!(
+				escrow,
+				TradeEscrow {
+					collection: 0,
+					item: 1,
+					seller: account(1),
+					buyer: account(2),
+					agent: account(3),
+					amount: SELLER_PROCEEDS,
+					release_at: Timestamp::get() + 100,
+					disputed: false,
+				}
+			);
+			assert_eq!(NextEscrowId::<Test>::get(), 1);
+			assert_eq!(
+				Balances::reserved_balance_named(ESCROW_RESERVE_NAME, &account(1)),
+				SELLER_PROCEEDS
+			);
+			// The proceeds are deposited for the escrow account of the trade, not for its agent
+			assert_eq!(
+				EscrowDeposits::get(account(1), Marketplace::escrow_account(0)),
+				SELLER_PROCEEDS
+			);
+			assert_eq!(EscrowDeposits::get(account(1), account(3)), 0);
+			System::assert_last_event(
+				Event::OrderExecuted {
+					collection: 0,
+					item: 1,
+					seller: account(1),
+					buyer: account(2),
+					price: 10000,
+					seller_fee: 1,
+					buyer_fee: 1,
+					royalty_recipient: None,
+					royalty: 0,
+					asset: None,
+					escrow_id: Some(0),
+				}
+				.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn buyer_confirms_receipt() {
+		new_test_ext().execute_with(|| {
+			let escrow = escrowed_trade();
+
+			assert_noop!(
+				Marketplace::confirm_receipt(RuntimeOrigin::signed(account(1)), 0),
+				Error::<Test>::NotEscrowBuyer
+			);
+			assert_noop!(
+				Marketplace::confirm_receipt(RuntimeOrigin::signed(account(2)), 1),
+				Error::<Test>::EscrowNotFound
+			);
+
+			assert_ok!(Marketplace::confirm_receipt(RuntimeOrigin::signed(account(2)), 0));
+
+			assert!(!Escrows::<Test>::contains_key(0));
+			assert_eq!(Balances::reserved_balance_named(ESCROW_RESERVE_NAME, &account(1)), 0);
+			System::assert_last_event(
+				Event::EscrowReleased {
+					escrow_id: 0,
+					seller: escrow.seller,
+					amount: escrow.amount,
+				}
+				.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn released_after_timeout() {
+		new_test_ext().execute_with(|| {
+			let escrow = escrowed_trade();
+
+			assert_noop!(
+				Marketplace::release_escrow(RuntimeOrigin::signed(account(4)), 0),
+				Error::<Test>::EscrowTimeoutNotPassed
+			);
+
+			Timestamp::set_timestamp(escrow.release_at);
+			assert_noop!(
+				Marketplace::dispute_escrow(RuntimeOrigin::signed(account(2)), 0),
+				Error::<Test>::EscrowTimeoutPassed
+			);
+			assert_ok!(Marketplace::release_escrow(RuntimeOrigin::signed(account(4)), 0));
+
+			assert!(!Escrows::<Test>::contains_key(0));
+			assert_eq!(Balances::reserved_balance_named(ESCROW_RESERVE_NAME, &account(1)), 0);
+		})
+	}
+
+	#[test]
+	fn disputed_trade_refunded_by_agent() {
+		new_test_ext().execute_with(|| {
+			let escrow = escrowed_trade();
+			let buyer_balance_before = Balances::balance(&account(2));
+
+			assert_noop!(
+				Marketplace::resolve_escrow_dispute(RuntimeOrigin::signed(account(3)), 0, true),
+				Error::<Test>::EscrowNotDisputed
+			);
+			assert_noop!(
+				Marketplace::dispute_escrow(RuntimeOrigin::signed(account(1)), 0),
+				Error::<Test>::NotEscrowBuyer
+			);
+
+			assert_ok!(Marketplace::dispute_escrow(RuntimeOrigin::signed(account(2)), 0));
+			System::assert_last_event(Event::EscrowDisputed { escrow_id: 0 }.into());
+			assert_noop!(
+				Marketplace::dispute_escrow(RuntimeOrigin::signed(account(2)), 0),
+				Error::<Test>::EscrowDisputed
+			);
+
+			// A disputed trade is not released at the timeout
+			Timestamp::set_timestamp(escrow.release_at);
+			assert_noop!(
+				Marketplace::release_escrow(RuntimeOrigin::signed(account(4)), 0),
+				Error::<Test>::EscrowDisputed
+			);
+
+			assert_noop!(
+				Marketplace::resolve_escrow_dispute(RuntimeOrigin::signed(account(2)), 0, true),
+				Error::<Test>::NotEscrowAgent
+			);
+			assert_ok!(Marketplace::resolve_escrow_dispute(
+				RuntimeOrigin::signed(account(3)),
+				0,
+				true
+			));
+
+			assert!(!Escrows::<Test>::contains_key(0));
+			assert_eq!(Balances::reserved_balance_named(ESCROW_RESERVE_NAME, &account(1)), 0);
+			assert_eq!(Balances::balance(&account(2)), buyer_balance_before + SELLER_PROCEEDS);
+			System::assert_last_event(
+				Event::EscrowRefunded { escrow_id: 0, buyer: account(2), amount: SELLER_PROCEEDS }
+					.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn disputed_trade_released_by_agent() {
+		new_test_ext().execute_with(|| {
+			let escrow = escrowed_trade();
+			assert_ok!(Marketplace::dispute_escrow(RuntimeOrigin::signed(account(2)), 0));
+
+			assert_ok!(Marketplace::resolve_escrow_dispute(
+				RuntimeOrigin::signed(account(3)),
+				0,
+				false
+			));
+
+			assert!(!Escrows::<Test>::contains_key(0));
+			assert_eq!(Balances::reserved_balance_named(ESCROW_RESERVE_NAME, &account(1)), 0);
+			System::assert_last_event(
+				Event::EscrowReleased {
+					escrow_id: 0,
+					seller: escrow.seller,
+					amount: escrow.amount,
+				}
+				.into(),
+			);
+		})
+	}
+}
+
+mod migrations {
+	use super::*;
+	use crate::migrations::{v1, v2, v3, v4, v5, v6};
+	use frame_support::{
+		migrations::SteppedMigration,
+		storage::unhashed,
+		traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+		weights::WeightMeter,
+	};
+
+	type MigrateToV6 = (
+		v1::MigrateToV1<Test>,
+		v2::MigrateToV2<Test>,
+		v3::MigrateToV3<Test>,
+		v4::MigrateToV4<Test>,
+		v5::MigrateToV5<Test>,
+		v6::MigrateToV6<Test>,
+	);
+
+	#[test]
+	fn migrates_asks_from_v0_to_v6() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(0).put::<Marketplace>();
+
+			let expiration = get_valid_expiration();
+			// The Asks and Bids as stored in version 0, without asset nor buyer.
+			let escrow_agent = |item| (item == 2).then(|| account(3));
+			for item in [1, 2] {
+				unhashed::put(
+					&Asks::<Test>::hashed_key_for(0, item),
+					&(account(1), 1000u128, expiration, 10u128, escrow_agent(item)),
+				);
+			}
+			unhashed::put(
+				&Bids::<Test>::hashed_key_for((0, 3, 500u128)),
+				&(account(2), expiration, 10u128),
+			);
+
+			MigrateToV6::on_runtime_upgrade();
+			let mut cursor = None;
+			loop {
+				cursor = v2::LazyIndexAccountOrders::<Test>::step(cursor, &mut WeightMeter::new())
+					.unwrap();
+				if cursor.is_none() {
+					break;
+				}
+			}
+
+			assert_eq!(Marketplace::on_chain_storage_version(), 6);
+			for item in [1, 2] {
+				assert_eq!(
+					Asks::<Test>::get(0, item),
+					Some(Ask {
+						seller: account(1),
+						price: 1000,
+						expiration,
+						fee: 10,
+						escrow_agent: escrow_agent(item),
+						// Existing escrowed Asks keep depositing for their escrow agent
+						escrow_settlement: EscrowSettlement::Agent,
+						asset: None,
+						buyer: None,
+					})
+				);
+			}
+
+			// The Asks are indexed once they have gained their buyer in version 5
+			let seller_orders = AccountOrders::<Test>::get(account(1));
+			assert_eq!(seller_orders.len(), 2);
+			assert!(seller_orders.contains(&OrderKey::Ask { collection: 0, item: 1 }));
+			assert!(seller_orders.contains(&OrderKey::Ask { collection: 0, item: 2 }));
+			assert_eq!(
+				AccountOrders::<Test>::get(account(2)).into_inner(),
+				vec![OrderKey::Bid { collection: 0, item: 3, price: 500 }]
+			);
+		})
+	}
+}
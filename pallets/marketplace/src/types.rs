@@ -1,6 +1,12 @@
-use frame_support::{pallet_prelude::DispatchResult, traits::fungible::Inspect};
+use frame_support::{
+	pallet_prelude::DispatchResult,
+	traits::{fungible::Inspect, fungibles},
+};
 use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{BoundedVec, Perbill};
 use sp_std::vec::Vec;
 
 use crate::Config;
@@ -8,6 +14,9 @@ use crate::Config;
 pub type BalanceOf<T> =
 	<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 
+pub type AssetIdOf<T> =
+	<<T as Config>::Assets as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
+
 pub type OrderOf<T> = Order<
 	<T as pallet_nfts::Config>::CollectionId,
 	pallet_nfts::ItemId,
@@ -16,6 +25,135 @@ pub type OrderOf<T> = Order<
 	<T as Config>::Signature,
 	<T as frame_system::Config>::AccountId,
 	Vec<u8>,
+	AssetIdOf<T>,
+>;
+
+pub type AskOf<T> = Ask<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	<T as pallet_timestamp::Config>::Moment,
+	<T as frame_system::Config>::AccountId,
+	AssetIdOf<T>,
+>;
+
+pub type BidOf<T> = Bid<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	<T as pallet_timestamp::Config>::Moment,
+	AssetIdOf<T>,
+>;
+
+pub type CollectionBidOf<T> = CollectionBid<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	<T as pallet_timestamp::Config>::Moment,
+	AssetIdOf<T>,
+>;
+
+pub type AuctionOf<T> = Auction<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	<T as pallet_timestamp::Config>::Moment,
+	AssetIdOf<T>,
+>;
+
+pub type AuctionOrderOf<T> = AuctionOrder<
+	<T as pallet_nfts::Config>::CollectionId,
+	pallet_nfts::ItemId,
+	BalanceOf<T>,
+	<T as pallet_timestamp::Config>::Moment,
+	<T as Config>::Signature,
+	Vec<u8>,
+	AssetIdOf<T>,
+>;
+
+pub type AuctionMessageOf<T> = AuctionMessage<
+	<T as pallet_nfts::Config>::CollectionId,
+	pallet_nfts::ItemId,
+	BalanceOf<T>,
+	<T as pallet_timestamp::Config>::Moment,
+	Vec<u8>,
+	AssetIdOf<T>,
+>;
+
+pub type AuctionBidMessageOf<T> = AuctionBidMessage<
+	<T as pallet_nfts::Config>::CollectionId,
+	pallet_nfts::ItemId,
+	BalanceOf<T>,
+	Vec<u8>,
+	AssetIdOf<T>,
+>;
+
+pub type OrderUpdateOf<T> = OrderUpdate<
+	<T as pallet_nfts::Config>::CollectionId,
+	pallet_nfts::ItemId,
+	BalanceOf<T>,
+	<T as pallet_timestamp::Config>::Moment,
+	<T as Config>::Signature,
+	Vec<u8>,
+>;
+
+pub type OrderUpdateMessageOf<T> = OrderUpdateMessage<
+	<T as pallet_nfts::Config>::CollectionId,
+	pallet_nfts::ItemId,
+	BalanceOf<T>,
+	<T as pallet_timestamp::Config>::Moment,
+	Vec<u8>,
+>;
+
+pub type BundleItemsOf<T> = BoundedVec<
+	(<T as pallet_nfts::Config>::CollectionId, pallet_nfts::ItemId),
+	<T as Config>::MaxBundleItems,
+>;
+
+pub type BundleAskOf<T> = BundleAsk<
+	<T as frame_system::Config>::AccountId,
+	BundleItemsOf<T>,
+	BalanceOf<T>,
+	<T as pallet_timestamp::Config>::Moment,
+	AssetIdOf<T>,
+>;
+
+pub type BundleOrderOf<T> = BundleOrder<
+	BundleItemsOf<T>,
+	BalanceOf<T>,
+	<T as pallet_timestamp::Config>::Moment,
+	<T as Config>::Signature,
+	Vec<u8>,
+	AssetIdOf<T>,
+>;
+
+pub type BundleOrderMessageOf<T> = BundleOrderMessage<
+	BundleItemsOf<T>,
+	BalanceOf<T>,
+	<T as pallet_timestamp::Config>::Moment,
+	Vec<u8>,
+	AssetIdOf<T>,
+>;
+
+pub type FeeSignerInfoOf<T> = FeeSignerInfo<<T as pallet_timestamp::Config>::Moment>;
+
+pub type FeeSignersOf<T> = BoundedVec<
+	(<T as frame_system::Config>::AccountId, FeeSignerInfoOf<T>),
+	<T as Config>::MaxFeeSigners,
+>;
+
+pub type CollectionStatsOf<T> = CollectionStats<BalanceOf<T>>;
+
+pub type SaleOf<T> = Sale<
+	<T as frame_system::Config>::AccountId,
+	pallet_nfts::ItemId,
+	BalanceOf<T>,
+	<T as pallet_timestamp::Config>::Moment,
+	AssetIdOf<T>,
+>;
+
+pub type TradeEscrowOf<T> = TradeEscrow<
+	<T as frame_system::Config>::AccountId,
+	<T as pallet_nfts::Config>::CollectionId,
+	pallet_nfts::ItemId,
+	BalanceOf<T>,
+	<T as pallet_timestamp::Config>::Moment,
 >;
 
 pub type OrderMessageOf<T> = OrderMessage<
@@ -28,36 +166,271 @@ pub type OrderMessageOf<T> = OrderMessage<
 >;
 
 #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
-pub struct Ask<AccountId, Amount, Expiration, Agent> {
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Ask<AccountId, Amount, Expiration, Agent, AssetId> {
 	pub seller: AccountId,
 	pub price: Amount,
 	pub expiration: Expiration,
 	pub fee: Amount,
 	pub escrow_agent: Option<Agent>,
+	/// How the proceeds are settled if the Ask has an escrow agent.
+	pub escrow_settlement: EscrowSettlement,
+	/// The asset the order is paid in, the native currency if `None`.
+	pub asset: Option<AssetId>,
+	/// The only account allowed to buy the item, anyone if `None`.
+	pub buyer: Option<AccountId>,
+}
+
+#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Bid<AccountId, Amount, Expiration, AssetId> {
+	pub buyer: AccountId,
+	pub expiration: Expiration,
+	pub fee: Amount,
+	/// The asset the order is paid in, the native currency if `None`.
+	pub asset: Option<AssetId>,
 }
 
+/// A bid on any item of a collection that can be filled up to `quantity` times.
 #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
-pub struct Bid<AccountId, Amount, Expiration> {
+pub struct CollectionBid<AccountId, Amount, Expiration, AssetId> {
 	pub buyer: AccountId,
 	pub expiration: Expiration,
+	/// The buyer fee that is still to be paid by the remaining fills.
 	pub fee: Amount,
+	/// The number of items that can still be bought through this bid.
+	pub quantity: u32,
+	/// The asset the order is paid in, the native currency if `None`.
+	pub asset: Option<AssetId>,
+}
+
+/// The royalty paid to the creator of a collection on every sale of its items.
+#[derive(
+	Clone, Encode, Decode, DecodeWithMemTracking, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen,
+)]
+pub struct Royalty<AccountId> {
+	/// The account receiving the royalty.
+	pub recipient: AccountId,
+	/// The part of the sale price deducted from the seller proceeds.
+	pub rate: Perbill,
+}
+
+/// The bounds of the fee of an order, in basis points of the order price.
+#[derive(
+	Clone, Encode, Decode, DecodeWithMemTracking, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen,
+)]
+pub struct FeePolicy {
+	/// The minimum fee, in basis points of the order price.
+	pub min_fee_bps: u16,
+	/// The maximum fee, in basis points of the order price.
+	pub max_fee_bps: u16,
+}
+
+impl FeePolicy {
+	/// The basis points of the whole order price.
+	pub const MAX_BPS: u16 = 10_000;
+
+	/// Whether the minimum fee is not above the maximum fee, nor the maximum fee above the price.
+	pub fn is_valid(&self) -> bool {
+		self.min_fee_bps <= self.max_fee_bps && self.max_fee_bps <= Self::MAX_BPS
+	}
+
+	/// The minimum fee, as a part of the order price.
+	pub fn min_fee(&self) -> Perbill {
+		Perbill::from_rational(u32::from(self.min_fee_bps), u32::from(Self::MAX_BPS))
+	}
+
+	/// The maximum fee, as a part of the order price.
+	pub fn max_fee(&self) -> Perbill {
+		Perbill::from_rational(u32::from(self.max_fee_bps), u32::from(Self::MAX_BPS))
+	}
+}
+
+/// The restrictions of an account allowed to sign orders.
+#[derive(
+	Clone,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Debug,
+	Default,
+	Eq,
+	PartialEq,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct FeeSignerInfo<Moment> {
+	/// The moment from which the signatures of the signer are accepted, if any.
+	pub valid_from: Option<Moment>,
+	/// The moment from which the signatures of the signer are rejected, if any.
+	pub valid_until: Option<Moment>,
+	/// The maximum fee the signer can approve, as a part of the order price, if any.
+	pub max_fee: Option<Perbill>,
+}
+
+impl<Moment: PartialOrd> FeeSignerInfo<Moment> {
+	/// Whether the signatures of the signer are accepted at `now`.
+	pub fn is_active(&self, now: &Moment) -> bool {
+		self.valid_from.as_ref().is_none_or(|from| now >= from)
+			&& self.valid_until.as_ref().is_none_or(|until| now < until)
+	}
+}
+
+/// How the seller proceeds of a trade with an escrow agent are settled.
+#[derive(
+	Clone,
+	Copy,
+	Default,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Debug,
+	Eq,
+	PartialEq,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum EscrowSettlement {
+	/// The proceeds are deposited through `Escrow` for the escrow agent, who releases them.
+	#[default]
+	Agent,
+	/// The proceeds are deposited for the escrow account of the trade and released once the
+	/// buyer confirms the receipt of the item or after `EscrowTimeout`, the escrow agent only
+	/// resolving disputes.
+	Marketplace,
 }
 
 #[derive(Clone, Encode, Decode, DecodeWithMemTracking, Debug, Eq, PartialEq, TypeInfo)]
 pub enum OrderType {
 	Ask,
 	Bid,
+	/// A bid on any item of the order collection, filling the Ask on the order item if there
+	/// is a matching one.
+	CollectionBid {
+		quantity: u32,
+	},
+}
+
+/// The trading statistics of a collection in one asset.
+#[derive(Clone, Encode, Decode, Debug, Default, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CollectionStats<Amount> {
+	/// The price of the most recent sale.
+	pub last_price: Amount,
+	/// The sum of the prices of all the sales.
+	pub volume: Amount,
+	/// The number of sales.
+	pub trades: u32,
+}
+
+/// A completed sale of an item, kept in the recent sales of its collection.
+#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Sale<AccountId, ItemId, Amount, Moment, AssetId> {
+	pub item: ItemId,
+	pub seller: AccountId,
+	pub buyer: AccountId,
+	pub price: Amount,
+	/// The asset the sale was paid in, the native currency if `None`.
+	pub asset: Option<AssetId>,
+	pub timestamp: Moment,
+}
+
+/// Identifies the escrowed proceeds of a trade.
+pub type EscrowId = u64;
+
+/// The seller proceeds of a trade, held in escrow until the buyer confirms the receipt of the
+/// item or `release_at` passes.
+#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct TradeEscrow<AccountId, CollectionId, ItemId, Amount, Moment> {
+	pub collection: CollectionId,
+	pub item: ItemId,
+	pub seller: AccountId,
+	pub buyer: AccountId,
+	/// The escrow agent of the trade, who resolves disputes.
+	pub agent: AccountId,
+	/// The seller proceeds deposited in escrow.
+	pub amount: Amount,
+	/// The time after which anyone can release the proceeds to the seller.
+	pub release_at: Moment,
+	/// Whether the buyer disputed the trade, the agent then decides on the proceeds.
+	pub disputed: bool,
+}
+
+/// Identifies an order stored in the marketplace.
+#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum OrderKey<CollectionId, ItemId, Amount, AccountId> {
+	Ask {
+		collection: CollectionId,
+		item: ItemId,
+	},
+	Bid {
+		collection: CollectionId,
+		item: ItemId,
+		price: Amount,
+	},
+	CollectionBid {
+		collection: CollectionId,
+		price: Amount,
+		buyer: AccountId,
+	},
+	/// An auction, which is settled instead of expired once it ends.
+	Auction {
+		collection: CollectionId,
+		item: ItemId,
+	},
+	BundleAsk {
+		bundle: BundleId,
+	},
+	BundleBid {
+		bundle: BundleId,
+		price: Amount,
+	},
+}
+
+pub type OrderKeyOf<T> = OrderKey<
+	<T as pallet_nfts::Config>::CollectionId,
+	pallet_nfts::ItemId,
+	BalanceOf<T>,
+	<T as frame_system::Config>::AccountId,
+>;
+
+/// Identifies a bundle of items, the hash of its encoded item list.
+pub type BundleId = [u8; 32];
+
+/// An Ask on a bundle of items that are sold together for a single price.
+#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct BundleAsk<AccountId, Items, Amount, Expiration, AssetId> {
+	pub seller: AccountId,
+	/// The `(collection, item)` pairs of the bundle.
+	pub items: Items,
+	pub price: Amount,
+	pub expiration: Expiration,
+	pub fee: Amount,
+	/// The asset the order is paid in, the native currency if `None`.
+	pub asset: Option<AssetId>,
 }
 
 #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo)]
-pub enum ExecOrder<AccountId, Amount, Expiration, Agent> {
-	Ask(Ask<AccountId, Amount, Expiration, Agent>),
-	Bid(Bid<AccountId, Amount, Expiration>),
+pub enum ExecOrder<AccountId, Amount, Expiration, Agent, AssetId> {
+	Ask(Ask<AccountId, Amount, Expiration, Agent, AssetId>),
+	Bid(Bid<AccountId, Amount, Expiration, AssetId>),
+	CollectionBid(CollectionBid<AccountId, Amount, Expiration, AssetId>),
 }
 
 #[derive(Clone, Encode, Decode, DecodeWithMemTracking, Debug, Eq, PartialEq, TypeInfo)]
-pub struct Order<CollectionId, ItemId, Amount, Expiration, OffchainSignature, Agent, BoundedString>
-{
+pub struct Order<
+	CollectionId,
+	ItemId,
+	Amount,
+	Expiration,
+	OffchainSignature,
+	Agent,
+	BoundedString,
+	AssetId,
+> {
 	pub order_type: OrderType,
 	pub collection: CollectionId,
 	pub item: ItemId,
@@ -65,16 +438,34 @@ pub struct Order<CollectionId, ItemId, Amount, Expiration, OffchainSignature, Ag
 	pub expires_at: Expiration,
 	pub fee: Amount,
 	pub escrow_agent: Option<Agent>,
-	pub signature_data: SignatureData<OffchainSignature, BoundedString>,
+	/// How the proceeds are settled if the order has an escrow agent.
+	pub escrow_settlement: EscrowSettlement,
+	pub signature_data: SignatureData<OffchainSignature, BoundedString, Expiration>,
+	/// The asset the order is paid in, the native currency if `None`.
+	pub asset: Option<AssetId>,
+	/// The only account allowed to buy the item of an Ask, anyone if `None`.
+	///
+	/// Only Asks can be restricted to a buyer.
+	pub buyer: Option<Agent>,
 }
 
 #[derive(Clone, Encode, Decode, DecodeWithMemTracking, Debug, Eq, PartialEq, TypeInfo)]
-pub struct SignatureData<OffchainSignature, BoundedString> {
+pub struct SignatureData<OffchainSignature, BoundedString, Moment> {
 	pub signature: OffchainSignature,
 	pub nonce: BoundedString,
+	/// The moment the nonce, and so the signature, stops being valid.
+	///
+	/// The fee signer signs the message followed by the encoded deadline. Used nonces are
+	/// pruned once their deadline has passed.
+	pub deadline: Moment,
 }
 
 ///Message data to be signed by the fee_signer account
+///
+/// For `OrderType::CollectionBid` orders the signed payload is the encoded
+/// `(message, quantity)` tuple. For orders paid in an asset the encoded asset id is appended
+/// to the payload, then for orders settled with `EscrowSettlement::Marketplace` the encoded
+/// settlement.
 #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo)]
 pub struct OrderMessage<CollectionId, ItemId, Amount, Expiration, Agent, BoundedString> {
 	pub collection: CollectionId,
@@ -84,14 +475,35 @@ pub struct OrderMessage<CollectionId, ItemId, Amount, Expiration, Agent, Bounded
 	pub fee: Amount,
 	pub escrow_agent: Option<Agent>,
 	pub nonce: BoundedString,
+	/// The only account allowed to buy the item of an Ask, anyone if `None`.
+	pub buyer: Option<Agent>,
 }
 
-impl<CollectionId, ItemId, Amount, Expiration, OffchainSignature, Agent, BoundedString>
-	From<Order<CollectionId, ItemId, Amount, Expiration, OffchainSignature, Agent, BoundedString>>
-	for OrderMessage<CollectionId, ItemId, Amount, Expiration, Agent, BoundedString>
+impl<CollectionId, ItemId, Amount, Expiration, OffchainSignature, Agent, BoundedString, AssetId>
+	From<
+		Order<
+			CollectionId,
+			ItemId,
+			Amount,
+			Expiration,
+			OffchainSignature,
+			Agent,
+			BoundedString,
+			AssetId,
+		>,
+	> for OrderMessage<CollectionId, ItemId, Amount, Expiration, Agent, BoundedString>
 {
 	fn from(
-		x: Order<CollectionId, ItemId, Amount, Expiration, OffchainSignature, Agent, BoundedString>,
+		x: Order<
+			CollectionId,
+			ItemId,
+			Amount,
+			Expiration,
+			OffchainSignature,
+			Agent,
+			BoundedString,
+			AssetId,
+		>,
 	) -> Self {
 		OrderMessage {
 			collection: x.collection,
@@ -101,10 +513,225 @@ impl<CollectionId, ItemId, Amount, Expiration, OffchainSignature, Agent, Bounded
 			fee: x.fee,
 			escrow_agent: x.escrow_agent,
 			nonce: x.signature_data.nonce,
+			buyer: x.buyer,
+		}
+	}
+}
+
+/// New price, expiration and fee of an existing Ask or Bid.
+#[derive(Clone, Encode, Decode, DecodeWithMemTracking, Debug, Eq, PartialEq, TypeInfo)]
+pub struct OrderUpdate<CollectionId, ItemId, Amount, Expiration, OffchainSignature, BoundedString> {
+	/// Either `OrderType::Ask` or `OrderType::Bid`.
+	pub order_type: OrderType,
+	pub collection: CollectionId,
+	pub item: ItemId,
+	/// The current price of the order, which identifies a Bid.
+	pub current_price: Amount,
+	pub price: Amount,
+	pub expires_at: Expiration,
+	pub fee: Amount,
+	pub signature_data: SignatureData<OffchainSignature, BoundedString, Expiration>,
+}
+
+///Message data to be signed by the fee_signer account to update an order
+#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo)]
+pub struct OrderUpdateMessage<CollectionId, ItemId, Amount, Expiration, BoundedString> {
+	/// The type of the updated order, so an approval can't be used to update another type.
+	pub order_type: OrderType,
+	pub collection: CollectionId,
+	pub item: ItemId,
+	pub current_price: Amount,
+	pub price: Amount,
+	pub expires_at: Expiration,
+	pub fee: Amount,
+	pub nonce: BoundedString,
+}
+
+impl<CollectionId, ItemId, Amount, Expiration, OffchainSignature, BoundedString>
+	From<OrderUpdate<CollectionId, ItemId, Amount, Expiration, OffchainSignature, BoundedString>>
+	for OrderUpdateMessage<CollectionId, ItemId, Amount, Expiration, BoundedString>
+{
+	fn from(
+		x: OrderUpdate<CollectionId, ItemId, Amount, Expiration, OffchainSignature, BoundedString>,
+	) -> Self {
+		OrderUpdateMessage {
+			order_type: x.order_type,
+			collection: x.collection,
+			item: x.item,
+			current_price: x.current_price,
+			price: x.price,
+			expires_at: x.expires_at,
+			fee: x.fee,
+			nonce: x.signature_data.nonce,
 		}
 	}
 }
 
+/// An Ask or Bid on a bundle of items, possibly from different collections, that are traded
+/// atomically for a single price.
+#[derive(Clone, Encode, Decode, DecodeWithMemTracking, Debug, Eq, PartialEq, TypeInfo)]
+pub struct BundleOrder<Items, Amount, Expiration, OffchainSignature, BoundedString, AssetId> {
+	/// Either `OrderType::Ask` or `OrderType::Bid`.
+	pub order_type: OrderType,
+	/// The `(collection, item)` pairs of the bundle.
+	pub items: Items,
+	pub price: Amount,
+	pub expires_at: Expiration,
+	pub fee: Amount,
+	pub signature_data: SignatureData<OffchainSignature, BoundedString, Expiration>,
+	/// The asset the order is paid in, the native currency if `None`.
+	pub asset: Option<AssetId>,
+}
+
+///Message data to be signed by the fee_signer account to create a bundle order
+#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo)]
+pub struct BundleOrderMessage<Items, Amount, Expiration, BoundedString, AssetId> {
+	pub items: Items,
+	pub price: Amount,
+	pub expires_at: Expiration,
+	pub fee: Amount,
+	pub nonce: BoundedString,
+	pub asset: Option<AssetId>,
+}
+
+impl<Items, Amount, Expiration, OffchainSignature, BoundedString, AssetId>
+	From<BundleOrder<Items, Amount, Expiration, OffchainSignature, BoundedString, AssetId>>
+	for BundleOrderMessage<Items, Amount, Expiration, BoundedString, AssetId>
+{
+	fn from(
+		x: BundleOrder<Items, Amount, Expiration, OffchainSignature, BoundedString, AssetId>,
+	) -> Self {
+		BundleOrderMessage {
+			items: x.items,
+			price: x.price,
+			expires_at: x.expires_at,
+			fee: x.fee,
+			nonce: x.signature_data.nonce,
+			asset: x.asset,
+		}
+	}
+}
+
+/// The price mechanism of an auction.
+#[derive(
+	Clone, Encode, Decode, DecodeWithMemTracking, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen,
+)]
+pub enum AuctionKind<Amount> {
+	/// Every bid must exceed the highest one by at least `min_increment`, the highest bidder
+	/// wins the item when the auction ends.
+	English { min_increment: Amount },
+	/// The price decays linearly from the start price at the start of the auction down to
+	/// `end_price` at its end, the first bidder wins the item at the current price.
+	Dutch { end_price: Amount },
+}
+
+#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct Auction<AccountId, Amount, Moment, AssetId> {
+	pub seller: AccountId,
+	pub kind: AuctionKind<Amount>,
+	pub start_price: Amount,
+	pub starts_at: Moment,
+	pub ends_at: Moment,
+	/// The seller fee.
+	pub fee: Amount,
+	pub highest_bid: Option<AuctionBid<AccountId, Amount>>,
+	/// The asset the auction is paid in, the native currency if `None`.
+	pub asset: Option<AssetId>,
+}
+
+#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct AuctionBid<AccountId, Amount> {
+	pub bidder: AccountId,
+	pub amount: Amount,
+	/// The bidder fee.
+	pub fee: Amount,
+}
+
+#[derive(Clone, Encode, Decode, DecodeWithMemTracking, Debug, Eq, PartialEq, TypeInfo)]
+pub struct AuctionOrder<
+	CollectionId,
+	ItemId,
+	Amount,
+	Moment,
+	OffchainSignature,
+	BoundedString,
+	AssetId,
+> {
+	pub collection: CollectionId,
+	pub item: ItemId,
+	pub kind: AuctionKind<Amount>,
+	pub start_price: Amount,
+	pub starts_at: Moment,
+	pub ends_at: Moment,
+	pub fee: Amount,
+	pub signature_data: SignatureData<OffchainSignature, BoundedString, Moment>,
+	/// The asset the auction is paid in, the native currency if `None`.
+	pub asset: Option<AssetId>,
+}
+
+///Message data to be signed by the fee_signer account to create an auction
+#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo)]
+pub struct AuctionMessage<CollectionId, ItemId, Amount, Moment, BoundedString, AssetId> {
+	pub collection: CollectionId,
+	pub item: ItemId,
+	pub kind: AuctionKind<Amount>,
+	pub start_price: Amount,
+	pub starts_at: Moment,
+	pub ends_at: Moment,
+	pub fee: Amount,
+	pub nonce: BoundedString,
+	pub asset: Option<AssetId>,
+}
+
+impl<CollectionId, ItemId, Amount, Moment, OffchainSignature, BoundedString, AssetId>
+	From<
+		AuctionOrder<
+			CollectionId,
+			ItemId,
+			Amount,
+			Moment,
+			OffchainSignature,
+			BoundedString,
+			AssetId,
+		>,
+	> for AuctionMessage<CollectionId, ItemId, Amount, Moment, BoundedString, AssetId>
+{
+	fn from(
+		x: AuctionOrder<
+			CollectionId,
+			ItemId,
+			Amount,
+			Moment,
+			OffchainSignature,
+			BoundedString,
+			AssetId,
+		>,
+	) -> Self {
+		AuctionMessage {
+			collection: x.collection,
+			item: x.item,
+			kind: x.kind,
+			start_price: x.start_price,
+			starts_at: x.starts_at,
+			ends_at: x.ends_at,
+			fee: x.fee,
+			nonce: x.signature_data.nonce,
+			asset: x.asset,
+		}
+	}
+}
+
+///Message data to be signed by the fee_signer account to bid on an auction
+#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo)]
+pub struct AuctionBidMessage<CollectionId, ItemId, Amount, BoundedString, AssetId> {
+	pub collection: CollectionId,
+	pub item: ItemId,
+	pub amount: Amount,
+	pub fee: Amount,
+	pub nonce: BoundedString,
+	pub asset: Option<AssetId>,
+}
+
 #[derive(Clone, Encode, Decode, DecodeWithMemTracking, Debug, Eq, PartialEq, TypeInfo)]
 pub enum Execution {
 	/// The order must be executed otherwise it should fail
@@ -129,4 +756,13 @@ pub trait Escrow<AccountId, Balance, Agent> {
 		value: Balance,
 		escrow_agent: &Agent,
 	) -> DispatchResult;
+	/// Releases `value` of the deposit held on `address` for `escrow_agent`.
+	fn release_deposit(address: &AccountId, value: Balance, escrow_agent: &Agent)
+	-> DispatchResult;
+	/// Moves the whole deposit held on `address` for `escrow_agent` to `destination`.
+	fn revoke_deposit(
+		address: &AccountId,
+		destination: &AccountId,
+		escrow_agent: &Agent,
+	) -> DispatchResult;
 }
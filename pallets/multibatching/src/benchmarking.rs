@@ -39,8 +39,33 @@ pub mod benchmarks {
 
 	use pallet_timestamp::Pallet as Timestamp;
 
-	#[benchmark]
-	fn batch(c: Linear<1, { T::MaxCalls::get() }>, s: Linear<1, { T::MaxCalls::get() }>) {
+	type BatchArgs<T> = (
+		AccountId20,
+		[u8; 8],
+		[u8; 32],
+		<T as pallet_timestamp::Config>::Moment,
+		BoundedVec<BatchedCall<T>, <T as Config>::MaxCalls>,
+		BoundedVec<Approval<T>, <T as Config>::MaxCalls>,
+	);
+
+	/// Prepares a batch of `c` calls from `s` signers, approved by signing the
+	/// pseudo-call built by `pseudo_call` in `format`.
+	fn setup_batch<T: Config>(
+		c: u32,
+		s: u32,
+		format: SigningFormat,
+		pseudo_call: impl FnOnce(
+			[u8; 8],
+			AccountId20,
+			[u8; 32],
+			<T as pallet_timestamp::Config>::Moment,
+			BoundedVec<BatchedCall<T>, <T as Config>::MaxCalls>,
+		) -> <T as Config>::RuntimeCall,
+	) -> BatchArgs<T>
+	where
+		T::Signer: From<EthereumSigner>,
+		T::Signature: From<EthereumSignature>,
+	{
 		let call_count = c as usize;
 		let signer_count = s as usize;
 
@@ -67,17 +92,9 @@ pub mod benchmarks {
 				.expect("Benchmark config must match runtime config for BoundedVec size");
 		}
 
-		let pseudo_call: <T as Config>::RuntimeCall = Call::<T>::batch {
-			domain,
-			sender: sender.into(),
-			bias,
-			expires_at,
-			calls: calls.clone(),
-			approvals: BoundedVec::new(),
-		}
-		.into();
-		let pseudo_call_bytes = pseudo_call.encode();
-		let hash = keccak_256(&pseudo_call_bytes);
+		let pseudo_call_bytes =
+			pseudo_call(domain, sender, bias, expires_at, calls.clone()).encode();
+		let hash = keccak_256(&format.signing_payload(pseudo_call_bytes));
 
 		let mut approvals = BoundedVec::new();
 		for (public, _signer, account) in &signers {
@@ -93,67 +110,74 @@ pub mod benchmarks {
 		}
 		approvals.sort_by_key(|a| a.from.clone());
 
+		(sender, domain, bias, expires_at, calls, approvals)
+	}
+
+	#[benchmark]
+	fn batch(c: Linear<1, { T::MaxCalls::get() }>, s: Linear<1, { T::MaxCalls::get() }>) {
+		let (sender, domain, bias, expires_at, calls, approvals) = setup_batch::<T>(
+			c,
+			s,
+			SigningFormat::Scale,
+			|domain, sender, bias, expires_at, calls| {
+				Call::<T>::batch {
+					domain,
+					sender: sender.into(),
+					bias,
+					expires_at,
+					calls,
+					approvals: BoundedVec::new(),
+				}
+				.into()
+			},
+		);
+
 		#[extrinsic_call]
 		_(RawOrigin::Signed(sender), domain, sender.into(), bias, expires_at, calls, approvals);
 	}
 
 	#[benchmark]
 	fn batch_v2(c: Linear<1, { T::MaxCalls::get() }>, s: Linear<1, { T::MaxCalls::get() }>) {
-		let call_count = c as usize;
-		let signer_count = s as usize;
-
-		let domain: [u8; 8] = T::Domain::get();
-		let bias = [0u8; 32];
-		let expires_at = Timestamp::<T>::get() + T::BenchmarkHelper::timestamp(100_000);
-
-		let sender: AccountId20 = whitelisted_caller();
-
-		let mut signers = Vec::<(Public, EthereumSigner, AccountId20)>::with_capacity(signer_count);
-		for _ in 0..signer_count {
-			let public: Public = ecdsa_generate(0.into(), None);
-			let signer: EthereumSigner = public.into();
-			let account = signer.clone().into_account();
-			signers.push((public, signer, account));
-		}
-
-		let mut calls = BoundedVec::new();
-		let iter = (0..call_count).zip(signers.iter().cycle());
-		for (_, (_, signer, _)) in iter {
-			let call = frame_system::Call::remark { remark: Default::default() }.into();
-			calls
-				.try_push(BatchedCall::<T> { from: signer.clone().into(), call })
-				.expect("Benchmark config must match runtime config for BoundedVec size");
-		}
+		let (sender, domain, bias, expires_at, calls, approvals) = setup_batch::<T>(
+			c,
+			s,
+			SigningFormat::Bytes,
+			|domain, sender, bias, expires_at, calls| {
+				Call::<T>::batch_v2 {
+					domain,
+					sender: sender.into(),
+					bias,
+					expires_at,
+					calls,
+					approvals: BoundedVec::new(),
+				}
+				.into()
+			},
+		);
 
-		let pseudo_call: <T as Config>::RuntimeCall = Call::<T>::batch_v2 {
-			domain,
-			sender: sender.into(),
-			bias,
-			expires_at,
-			calls: calls.clone(),
-			approvals: BoundedVec::new(),
-		}
-		.into();
-		let pseudo_call_bytes = pseudo_call.encode();
-		let pseudo_call_bytes = [b"<Bytes>", &pseudo_call_bytes[..], b"</Bytes>"].concat();
-		let hash = keccak_256(&pseudo_call_bytes);
+		#[extrinsic_call]
+		_(RawOrigin::Signed(sender), domain, sender.into(), bias, expires_at, calls, approvals);
+	}
 
-		let mut approvals = BoundedVec::new();
-		for (public, _signer, account) in &signers {
-			approvals
-				.try_push(Approval::<T> {
-					from: EthereumSigner::from(account.0).into(),
-					signature: EthereumSignature::from(
-						ecdsa_sign_prehashed(0.into(), public, &hash).unwrap(),
-					)
-					.into(),
-				})
-				.expect("Benchmark config must match runtime config for BoundedVec size");
-		}
-		approvals.sort_by_key(|a| a.from.clone());
+	#[benchmark]
+	fn batch_eip191(c: Linear<1, { T::MaxCalls::get() }>, s: Linear<1, { T::MaxCalls::get() }>) {
+		let format = SigningFormat::Eip191;
+		let (sender, domain, bias, expires_at, calls, approvals) =
+			setup_batch::<T>(c, s, format, |domain, sender, bias, expires_at, calls| {
+				Call::<T>::batch_with_format {
+					domain,
+					sender: sender.into(),
+					bias,
+					expires_at,
+					calls,
+					approvals: BoundedVec::new(),
+					format,
+				}
+				.into()
+			});
 
 		#[extrinsic_call]
-		Pallet::<T>::batch_v2(
+		Pallet::<T>::batch_with_format(
 			RawOrigin::Signed(sender),
 			domain,
 			sender.into(),
@@ -161,6 +185,7 @@ pub mod benchmarks {
 			expires_at,
 			calls,
 			approvals,
+			format,
 		);
 	}
 
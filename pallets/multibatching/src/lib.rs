@@ -1,5 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -16,6 +18,7 @@ pub use pallet::*;
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
+	use alloc::string::ToString;
 	use frame_support::{
 		dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo},
 		pallet_prelude::*,
@@ -136,7 +139,8 @@ pub mod pallet {
 	/// A batch of calls.
 	///
 	/// This structure is intended to mimic the structure of a full
-	/// formed call to `Pallet::batch` with empty approvals parameter.
+	/// formed call to `Pallet::batch` with empty approvals parameter,
+	/// up to the arguments following the approvals.
 	///
 	/// TODO: find a better and more future-proof way to do this
 	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
@@ -152,6 +156,46 @@ pub mod pallet {
 		pub approvals_zero: u8,
 	}
 
+	/// The format of the bytes signed by the approvers of a batch.
+	#[derive(
+		Clone,
+		Copy,
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		PartialEq,
+		Eq,
+		Debug,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub enum SigningFormat {
+		/// The SCALE encoded call, as is.
+		Scale,
+		/// The SCALE encoded call wrapped in between `<Bytes>` and `</Bytes>`, which is how
+		/// modern substrate clients implement `signRaw`.
+		Bytes,
+		/// The SCALE encoded call prefixed as an [EIP-191](https://eips.ethereum.org/EIPS/eip-191)
+		/// message, which is how Ethereum wallets implement `personal_sign`.
+		Eip191,
+	}
+
+	impl SigningFormat {
+		/// Returns the bytes to sign for the SCALE encoded call `bytes`.
+		pub fn signing_payload(&self, bytes: Vec<u8>) -> Vec<u8> {
+			match self {
+				Self::Scale => bytes,
+				Self::Bytes => [b"<Bytes>", &bytes[..], b"</Bytes>"].concat(),
+				Self::Eip191 => [
+					b"\x19Ethereum Signed Message:\n",
+					bytes.len().to_string().as_bytes(),
+					&bytes[..],
+				]
+				.concat(),
+			}
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Execute multiple calls from multiple callers in a single batch.
@@ -195,25 +239,10 @@ pub mod pallet {
 		/// - Send the `batch()` call with the same data and the collected
 		/// approvals.
 		///
+		/// Approvals are signed in the [SigningFormat::Scale] format.
+		///
 		#[pallet::call_index(0)]
-		#[pallet::weight({
-			let dispatch_infos = calls.iter().map(|call| call.call.get_dispatch_info()).collect::<Vec<_>>();
-			let dispatch_weight = dispatch_infos.iter()
-				.map(|di| di.call_weight)
-				.fold(Weight::zero(), |total: Weight, weight: Weight| total.saturating_add(weight))
-				.saturating_add(<T as Config>::WeightInfo::batch(calls.len() as u32, approvals.len() as u32));
-			let dispatch_class = {
-				let all_operational = dispatch_infos.iter()
-					.map(|di| di.class)
-					.all(|class| class == DispatchClass::Operational);
-				if all_operational {
-					DispatchClass::Operational
-				} else {
-					DispatchClass::Normal
-				}
-			};
-			(dispatch_weight, dispatch_class)
-        })]
+		#[pallet::weight(Pallet::<T>::batch_weight(SigningFormat::Scale, calls, approvals.len()))]
 		pub fn batch(
 			origin: OriginFor<T>,
 			domain: [u8; 8],
@@ -223,98 +252,8 @@ pub mod pallet {
 			calls: BoundedVec<BatchedCall<T>, <T as Config>::MaxCalls>,
 			approvals: BoundedVec<Approval<T>, <T as Config>::MaxCalls>,
 		) -> DispatchResultWithPostInfo {
-			if calls.is_empty() {
-				return Err(Error::<T>::NoCalls.into());
-			}
-			if approvals.is_empty() {
-				return Err(Error::<T>::NoApprovals.into());
-			}
-
-			if approvals.len() > 1 {
-				for pair in approvals.windows(2) {
-					match pair {
-						[a, b] if a.from < b.from => (),
-						_ => return Err(Error::<T>::UnsortedApprovals.into()),
-					};
-				}
-			}
-
-			// Origin must be `sender`.
-			match ensure_signed(origin) {
-				Ok(account_id) if account_id == sender => account_id,
-				Ok(_) => return Err(Error::<T>::BatchSenderIsNotOrigin.into()),
-				Err(e) => return Err(e.into()),
-			};
-
-			if pallet_timestamp::Pallet::<T>::get() > expires_at {
-				return Err(Error::<T>::Expired.into());
-			}
-
-			ensure!(domain == <T as Config>::Domain::get(), Error::<T>::InvalidDomain);
-
-			let bytes = Batch {
-				pallet_index: Self::index() as u8,
-				call_index: 0,
-				domain,
-				sender: sender.clone(),
-				bias,
-				expires_at,
-				calls: calls.clone(),
-				approvals_zero: 0,
-			}
-			.encode();
-			let hash = <<T as frame_system::Config>::Hashing>::hash(&bytes);
-
-			if Applied::<T>::contains_key(hash) {
-				return Err(Error::<T>::AlreadyApplied.into());
-			}
-
-			Applied::<T>::insert(hash, ());
-
-			// Check the signatures.
-			for (i, approval) in approvals.iter().enumerate() {
-				let ok = approval
-					.signature
-					.verify(bytes.as_ref(), &approval.from.clone().into_account());
-				if !ok {
-					return Err(Error::<T>::InvalidSignature(i as u16).into());
-				}
-			}
-
-			let mut weight = Weight::zero();
-
-			let calls_len = calls.len();
-
-			// Apply calls.
-			for (i, payload) in calls.into_iter().enumerate() {
-				let ok = approvals.binary_search_by_key(&&payload.from, |a| &a.from).is_ok();
-				if !ok {
-					return Err(Error::<T>::InvalidCallOrigin(i as u16).into());
-				}
-
-				let info = payload.call.get_dispatch_info();
-				let origin = <<T as frame_system::Config>::RuntimeOrigin>::from(
-					frame_system::RawOrigin::Signed(payload.from.into_account()),
-				);
-				let result = payload.call.dispatch(origin);
-				weight = weight.saturating_add(extract_actual_weight(&result, &info));
-				result.map_err(|mut err| {
-					// Take the weight of this function itself into account.
-					let base_weight = <T as Config>::WeightInfo::batch(
-						i.saturating_add(1) as u32,
-						approvals.len() as u32,
-					);
-					// Return the actual used weight + base_weight of this call.
-					err.post_info = Some(base_weight + weight).into();
-					err
-				})?;
-			}
-
-			Self::deposit_event(Event::BatchApplied { hash });
-
-			let base_weight =
-				<T as Config>::WeightInfo::batch(calls_len as u32, approvals.len() as u32);
-			Ok(Some(base_weight.saturating_add(weight)).into())
+			let batch = Self::signed_batch(0, domain, sender, bias, expires_at, calls);
+			Self::do_batch(origin, SigningFormat::Scale, batch, Vec::new(), approvals)
 		}
 
 		/// Execute multiple calls from multiple callers in a single batch.
@@ -325,15 +264,67 @@ pub mod pallet {
 		/// approvers must be wrapped in between <Bytes> ... </Bytes>.
 		/// This is how the rawSign is currently implemented in modern substrate clients.
 		///
+		/// Approvals are signed in the [SigningFormat::Bytes] format.
+		///
 		#[pallet::call_index(1)]
-		#[pallet::weight({
-			let dispatch_infos = calls.iter().map(|call| call.call.get_dispatch_info()).collect::<Vec<_>>();
-			let dispatch_weight = dispatch_infos.iter()
+		#[pallet::weight(Pallet::<T>::batch_weight(SigningFormat::Bytes, calls, approvals.len()))]
+		pub fn batch_v2(
+			origin: OriginFor<T>,
+			domain: [u8; 8],
+			sender: <T as frame_system::Config>::AccountId,
+			bias: [u8; 32],
+			expires_at: <T as pallet_timestamp::Config>::Moment,
+			calls: BoundedVec<BatchedCall<T>, <T as Config>::MaxCalls>,
+			approvals: BoundedVec<Approval<T>, <T as Config>::MaxCalls>,
+		) -> DispatchResultWithPostInfo {
+			let batch = Self::signed_batch(1, domain, sender, bias, expires_at, calls);
+			Self::do_batch(origin, SigningFormat::Bytes, batch, Vec::new(), approvals)
+		}
+
+		/// Execute multiple calls from multiple callers in a single batch.
+		///
+		/// If one of the calls fails, the whole batch reverts.
+		///
+		/// This function works the same as [Pallet::batch], but the bytes signed by
+		/// approvers are the encoded `batch_with_format()` call, with empty approvals,
+		/// in the given signing `format`.
+		///
+		#[pallet::call_index(2)]
+		#[pallet::weight(Pallet::<T>::batch_weight(*format, calls, approvals.len()))]
+		#[allow(clippy::too_many_arguments)]
+		pub fn batch_with_format(
+			origin: OriginFor<T>,
+			domain: [u8; 8],
+			sender: <T as frame_system::Config>::AccountId,
+			bias: [u8; 32],
+			expires_at: <T as pallet_timestamp::Config>::Moment,
+			calls: BoundedVec<BatchedCall<T>, <T as Config>::MaxCalls>,
+			approvals: BoundedVec<Approval<T>, <T as Config>::MaxCalls>,
+			format: SigningFormat,
+		) -> DispatchResultWithPostInfo {
+			let batch = Self::signed_batch(2, domain, sender, bias, expires_at, calls);
+			Self::do_batch(origin, format, batch, format.encode(), approvals)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Returns the weight and the dispatch class of a batch of `calls` approved by
+		/// `approvals` signers in `format`.
+		pub fn batch_weight(
+			format: SigningFormat,
+			calls: &[BatchedCall<T>],
+			approvals: usize,
+		) -> (Weight, DispatchClass) {
+			let dispatch_infos =
+				calls.iter().map(|call| call.call.get_dispatch_info()).collect::<Vec<_>>();
+			let dispatch_weight = dispatch_infos
+				.iter()
 				.map(|di| di.call_weight)
 				.fold(Weight::zero(), |total: Weight, weight: Weight| total.saturating_add(weight))
-				.saturating_add(<T as Config>::WeightInfo::batch_v2(calls.len() as u32, approvals.len() as u32));
+				.saturating_add(Self::base_weight(format, calls.len(), approvals));
 			let dispatch_class = {
-				let all_operational = dispatch_infos.iter()
+				let all_operational = dispatch_infos
+					.iter()
 					.map(|di| di.class)
 					.all(|class| class == DispatchClass::Operational);
 				if all_operational {
@@ -343,17 +334,51 @@ pub mod pallet {
 				}
 			};
 			(dispatch_weight, dispatch_class)
-        })]
-		pub fn batch_v2(
-			origin: OriginFor<T>,
+		}
+
+		/// Returns the weight of a batch in `format`, excluding the weight of its calls.
+		fn base_weight(format: SigningFormat, calls: usize, approvals: usize) -> Weight {
+			let (calls, approvals) = (calls as u32, approvals as u32);
+			match format {
+				SigningFormat::Scale => <T as Config>::WeightInfo::batch(calls, approvals),
+				SigningFormat::Bytes => <T as Config>::WeightInfo::batch_v2(calls, approvals),
+				SigningFormat::Eip191 => <T as Config>::WeightInfo::batch_eip191(calls, approvals),
+			}
+		}
+
+		/// Returns the batch signed by the approvers of the call at `call_index`.
+		fn signed_batch(
+			call_index: u8,
 			domain: [u8; 8],
 			sender: <T as frame_system::Config>::AccountId,
 			bias: [u8; 32],
 			expires_at: <T as pallet_timestamp::Config>::Moment,
 			calls: BoundedVec<BatchedCall<T>, <T as Config>::MaxCalls>,
+		) -> Batch<T> {
+			Batch {
+				pallet_index: Self::index() as u8,
+				call_index,
+				domain,
+				sender,
+				bias,
+				expires_at,
+				calls,
+				approvals_zero: 0,
+			}
+		}
+
+		/// Executes `batch` if approved by all of its callers.
+		///
+		/// The approvals are signatures, in `format`, of the encoded `batch` followed by
+		/// `trailing_args`, i.e. the encoded call arguments that come after the approvals.
+		fn do_batch(
+			origin: OriginFor<T>,
+			format: SigningFormat,
+			batch: Batch<T>,
+			trailing_args: Vec<u8>,
 			approvals: BoundedVec<Approval<T>, <T as Config>::MaxCalls>,
 		) -> DispatchResultWithPostInfo {
-			if calls.is_empty() {
+			if batch.calls.is_empty() {
 				return Err(Error::<T>::NoCalls.into());
 			}
 			if approvals.is_empty() {
@@ -371,29 +396,18 @@ pub mod pallet {
 
 			// Origin must be `sender`.
 			match ensure_signed(origin) {
-				Ok(account_id) if account_id == sender => account_id,
+				Ok(account_id) if account_id == batch.sender => account_id,
 				Ok(_) => return Err(Error::<T>::BatchSenderIsNotOrigin.into()),
 				Err(e) => return Err(e.into()),
 			};
 
-			if pallet_timestamp::Pallet::<T>::get() > expires_at {
+			if pallet_timestamp::Pallet::<T>::get() > batch.expires_at {
 				return Err(Error::<T>::Expired.into());
 			}
 
-			ensure!(domain == <T as Config>::Domain::get(), Error::<T>::InvalidDomain);
+			ensure!(batch.domain == <T as Config>::Domain::get(), Error::<T>::InvalidDomain);
 
-			let bytes = Batch {
-				pallet_index: Self::index() as u8,
-				call_index: 1,
-				domain,
-				sender: sender.clone(),
-				bias,
-				expires_at,
-				calls: calls.clone(),
-				approvals_zero: 0,
-			}
-			.encode();
-			let bytes = [b"<Bytes>", &bytes[..], b"</Bytes>"].concat();
+			let bytes = format.signing_payload([batch.encode(), trailing_args].concat());
 			let hash = <<T as frame_system::Config>::Hashing>::hash(&bytes);
 
 			if Applied::<T>::contains_key(hash) {
@@ -414,10 +428,10 @@ pub mod pallet {
 
 			let mut weight = Weight::zero();
 
-			let calls_len = calls.len();
+			let calls_len = batch.calls.len();
 
 			// Apply calls.
-			for (i, payload) in calls.into_iter().enumerate() {
+			for (i, payload) in batch.calls.into_iter().enumerate() {
 				let ok = approvals.binary_search_by_key(&&payload.from, |a| &a.from).is_ok();
 				if !ok {
 					return Err(Error::<T>::InvalidCallOrigin(i as u16).into());
@@ -431,10 +445,8 @@ pub mod pallet {
 				weight = weight.saturating_add(extract_actual_weight(&result, &info));
 				result.map_err(|mut err| {
 					// Take the weight of this function itself into account.
-					let base_weight = <T as Config>::WeightInfo::batch(
-						i.saturating_add(1) as u32,
-						approvals.len() as u32,
-					);
+					let base_weight =
+						Self::base_weight(format, i.saturating_add(1), approvals.len());
 					// Return the actual used weight + base_weight of this call.
 					err.post_info = Some(base_weight + weight).into();
 					err
@@ -443,8 +455,7 @@ pub mod pallet {
 
 			Self::deposit_event(Event::BatchApplied { hash });
 
-			let base_weight =
-				<T as Config>::WeightInfo::batch(calls_len as u32, approvals.len() as u32);
+			let base_weight = Self::base_weight(format, calls_len, approvals.len());
 			Ok(Some(base_weight.saturating_add(weight)).into())
 		}
 	}
@@ -1057,4 +1057,222 @@ mod multibatching_test {
 			);
 		})
 	}
+
+	type BatchArgs = (
+		AccountIdOf<Test>,
+		[u8; 8],
+		[u8; 32],
+		<Test as pallet_timestamp::Config>::Moment,
+		BoundedVec<BatchedCall<Test>, <Test as Config>::MaxCalls>,
+		BoundedVec<Approval<Test>, <Test as Config>::MaxCalls>,
+	);
+
+	/// Prepares a `batch_with_format()` call in `format`, approved by signatures
+	/// of `payload` applied to the encoded pseudo-call.
+	fn batch_with_format_args(format: SigningFormat, payload: fn(Vec<u8>) -> Vec<u8>) -> BatchArgs {
+		let call_count = 10;
+		let signer_count = 10;
+
+		let domain: [u8; 8] = *b"MYTH_NET";
+		let bias = [0u8; 32];
+		let expires_at = Timestamp::get()
+			.saturating_add(<Test as pallet_timestamp::Config>::Moment::from(1_000_000_000_u64));
+
+		let sender = account(0);
+
+		let mut signers =
+			Vec::<(EthereumPair, EthereumSigner, AccountId20)>::with_capacity(signer_count);
+		for _ in 0..signer_count {
+			let pair: EthereumPair = EthereumPair::generate().0;
+			let signer: EthereumSigner = pair.public().into();
+			let account = signer.clone().into_account();
+			signers.push((pair, signer, account));
+		}
+
+		let mut calls = BoundedVec::new();
+		let iter = (0..call_count).zip(signers.iter().cycle());
+		for (_, (_, signer, _)) in iter {
+			let call = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(calls.try_push(BatchedCall::<Test> { from: signer.clone(), call }));
+		}
+
+		let pseudo_call: <Test as Config>::RuntimeCall = Call::<Test>::batch_with_format {
+			domain,
+			sender,
+			bias,
+			expires_at,
+			calls: calls.clone(),
+			approvals: BoundedVec::new(),
+			format,
+		}
+		.into();
+		let hash = keccak_256(&payload(pseudo_call.encode()));
+
+		let mut approvals = BoundedVec::new();
+		for (pair, _, account) in &signers {
+			assert_ok!(approvals.try_push(Approval::<Test> {
+				from: EthereumSigner::from(account.0),
+				signature: EthereumSignature::from(pair.sign_prehashed(&hash)),
+			}));
+		}
+		approvals.sort_by_key(|a| a.from.clone());
+
+		(sender, domain, bias, expires_at, calls, approvals)
+	}
+
+	fn eip191_message(bytes: Vec<u8>) -> Vec<u8> {
+		let prefix = format!("\x19Ethereum Signed Message:\n{}", bytes.len());
+		[prefix.as_bytes(), &bytes[..]].concat()
+	}
+
+	#[test]
+	fn multibatching_batch_with_format_scale_should_work() {
+		new_test_ext().execute_with(|| {
+			let (sender, domain, bias, expires_at, calls, approvals) =
+				batch_with_format_args(SigningFormat::Scale, |bytes| bytes);
+
+			assert_ok!(Multibatching::batch_with_format(
+				RuntimeOrigin::signed(sender),
+				domain,
+				sender,
+				bias,
+				expires_at,
+				calls,
+				approvals,
+				SigningFormat::Scale,
+			));
+		})
+	}
+
+	#[test]
+	fn multibatching_batch_with_format_bytes_should_work() {
+		new_test_ext().execute_with(|| {
+			let (sender, domain, bias, expires_at, calls, approvals) =
+				batch_with_format_args(SigningFormat::Bytes, |bytes| {
+					[b"<Bytes>", &bytes[..], b"</Bytes>"].concat()
+				});
+
+			assert_ok!(Multibatching::batch_with_format(
+				RuntimeOrigin::signed(sender),
+				domain,
+				sender,
+				bias,
+				expires_at,
+				calls,
+				approvals,
+				SigningFormat::Bytes,
+			));
+		})
+	}
+
+	#[test]
+	fn multibatching_batch_with_format_eip191_should_work() {
+		new_test_ext().execute_with(|| {
+			let (sender, domain, bias, expires_at, calls, approvals) =
+				batch_with_format_args(SigningFormat::Eip191, eip191_message);
+
+			assert_ok!(Multibatching::batch_with_format(
+				RuntimeOrigin::signed(sender),
+				domain,
+				sender,
+				bias,
+				expires_at,
+				calls,
+				approvals,
+				SigningFormat::Eip191,
+			));
+		})
+	}
+
+	#[test]
+	fn multibatching_batch_with_format_eip191_fails_if_already_applied() {
+		new_test_ext().execute_with(|| {
+			let (sender, domain, bias, expires_at, calls, approvals) =
+				batch_with_format_args(SigningFormat::Eip191, eip191_message);
+
+			assert_ok!(Multibatching::batch_with_format(
+				RuntimeOrigin::signed(sender),
+				domain,
+				sender,
+				bias,
+				expires_at,
+				calls.clone(),
+				approvals.clone(),
+				SigningFormat::Eip191,
+			));
+			assert_noop!(
+				Multibatching::batch_with_format(
+					RuntimeOrigin::signed(sender),
+					domain,
+					sender,
+					bias,
+					expires_at,
+					calls,
+					approvals,
+					SigningFormat::Eip191,
+				),
+				Error::<Test>::AlreadyApplied
+			);
+		})
+	}
+
+	#[test]
+	fn multibatching_batch_with_format_fails_if_signed_in_another_format() {
+		new_test_ext().execute_with(|| {
+			// Signed as raw SCALE bytes, while the batch claims the EIP-191 format.
+			let (sender, domain, bias, expires_at, calls, approvals) =
+				batch_with_format_args(SigningFormat::Eip191, |bytes| bytes);
+
+			assert_noop!(
+				Multibatching::batch_with_format(
+					RuntimeOrigin::signed(sender),
+					domain,
+					sender,
+					bias,
+					expires_at,
+					calls,
+					approvals,
+					SigningFormat::Eip191,
+				),
+				Error::<Test>::InvalidSignature(0)
+			);
+		})
+	}
+
+	#[test]
+	fn multibatching_batch_with_format_fails_if_format_changed() {
+		new_test_ext().execute_with(|| {
+			// The format is part of the signed call, so it can't be swapped after signing.
+			let (sender, domain, bias, expires_at, calls, approvals) =
+				batch_with_format_args(SigningFormat::Scale, eip191_message);
+
+			assert_noop!(
+				Multibatching::batch_with_format(
+					RuntimeOrigin::signed(sender),
+					domain,
+					sender,
+					bias,
+					expires_at,
+					calls,
+					approvals,
+					SigningFormat::Eip191,
+				),
+				Error::<Test>::InvalidSignature(0)
+			);
+		})
+	}
+
+	#[test]
+	fn signing_payload_formats() {
+		let bytes = vec![1, 2, 3];
+		assert_eq!(SigningFormat::Scale.signing_payload(bytes.clone()), bytes);
+		assert_eq!(
+			SigningFormat::Bytes.signing_payload(bytes.clone()),
+			b"<Bytes>\x01\x02\x03</Bytes>"
+		);
+		assert_eq!(
+			SigningFormat::Eip191.signing_payload(bytes),
+			b"\x19Ethereum Signed Message:\n3\x01\x02\x03"
+		);
+	}
 }
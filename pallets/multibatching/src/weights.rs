@@ -40,6 +40,7 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn batch(c: u32, s: u32, ) -> Weight;
 	fn batch_v2(c: u32, s: u32, ) -> Weight;
+	fn batch_eip191(c: u32, s: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_multibatching` using the Substrate node and recommended hardware.
@@ -88,6 +89,28 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Multibatching::Domain` (r:1 w:0)
+	/// Proof: `Multibatching::Domain` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Multibatching::Applied` (r:1 w:1)
+	/// Proof: `Multibatching::Applied` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 1000]`.
+	/// The range of component `s` is `[1, 10]`.
+	fn batch_eip191(c: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `67`
+		//  Estimated: `3497`
+		// Minimum execution time: 273_710_000 picoseconds.
+		Weight::from_parts(287_850_000, 3497)
+			// Standard Error: 1_615
+			.saturating_add(Weight::from_parts(1_687_188, 0).saturating_mul(c.into()))
+			// Standard Error: 160_912
+			.saturating_add(Weight::from_parts(27_710_194, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -135,4 +158,26 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Multibatching::Domain` (r:1 w:0)
+	/// Proof: `Multibatching::Domain` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Multibatching::Applied` (r:1 w:1)
+	/// Proof: `Multibatching::Applied` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 1000]`.
+	/// The range of component `s` is `[1, 10]`.
+	fn batch_eip191(c: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `67`
+		//  Estimated: `3497`
+		// Minimum execution time: 273_710_000 picoseconds.
+		Weight::from_parts(287_850_000, 3497)
+			// Standard Error: 1_615
+			.saturating_add(Weight::from_parts(1_687_188, 0).saturating_mul(c.into()))
+			// Standard Error: 160_912
+			.saturating_add(Weight::from_parts(27_710_194, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
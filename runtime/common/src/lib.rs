@@ -74,6 +74,7 @@ pub const MAXIMUM_BLOCK_WEIGHT: Weight =
 	MaxEncodedLen,
 	Debug,
 )]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct IncrementableU256(U256);
 
 impl Incrementable for IncrementableU256 {
@@ -58,7 +58,9 @@ use frame_system::{
 	EnsureRoot, EnsureSigned, EnsureWithSuccess,
 	limits::{BlockLength, BlockWeights},
 };
-use pallet_dmarket::{Item, TradeParams};
+use pallet_dmarket::{
+	BatchTradeParams, FeeRecipient, Item, TradeError, TradeParams, TradeSignatures,
+};
 use pallet_nfts::PalletFeatures;
 use parachains_common::message_queue::{NarrowOriginToSibling, ParaIdToSibling};
 use polkadot_primitives::Moment;
@@ -126,7 +128,24 @@ pub type UncheckedExtrinsic =
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, TxExtension>;
 
 /// Pending migrations to be applied.
-pub type Migrations = ();
+pub type Migrations = (
+	pallet_marketplace::migrations::v1::MigrateToV1<Runtime>,
+	pallet_marketplace::migrations::v2::MigrateToV2<Runtime>,
+	pallet_marketplace::migrations::v3::MigrateToV3<Runtime>,
+	pallet_marketplace::migrations::v4::MigrateToV4<Runtime>,
+	pallet_marketplace::migrations::v5::MigrateToV5<Runtime>,
+	pallet_marketplace::migrations::v6::MigrateToV6<Runtime>,
+	pallet_dmarket::migrations::v1::MigrateToV1<Runtime>,
+	pallet_dmarket::migrations::v2::MigrateToV2<Runtime>,
+);
+
+/// Pending multi-block migrations, run by `pallet-migrations` after the migrations above.
+pub type SteppedMigrations = (
+	pallet_marketplace::migrations::v2::LazyIndexAccountOrders<Runtime>,
+	pallet_marketplace::migrations::v3::LazyClearLegacyNonces<Runtime>,
+	pallet_marketplace::migrations::expirations::LazyIndexOrderExpirations<Runtime>,
+	pallet_dmarket::migrations::v1::LazyClearLegacyClosedOrders<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -396,6 +415,8 @@ impl frame_system::Config for Runtime {
 	/// The maximum number of consumers allowed on a single account.
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 	type SystemWeightInfo = weights::frame_system::WeightInfo<Runtime>;
+	/// The migrations run over several blocks, suspending the extrinsics meanwhile.
+	type MultiBlockMigrator = MultiBlockMigrations;
 }
 
 impl pallet_timestamp::Config for Runtime {
@@ -759,15 +780,92 @@ impl pallet_marketplace::Escrow<AccountId, Balance, AccountId> for EscrowImpl {
 	) -> DispatchResult {
 		Escrow::make_deposit(depositor, destination, value, escrow_agent)
 	}
+
+	fn release_deposit(
+		address: &AccountId,
+		value: Balance,
+		escrow_agent: &AccountId,
+	) -> DispatchResult {
+		Escrow::do_release(address, escrow_agent, value)
+	}
+
+	fn revoke_deposit(
+		address: &AccountId,
+		destination: &AccountId,
+		escrow_agent: &AccountId,
+	) -> DispatchResult {
+		Escrow::do_revoke(address, escrow_agent, destination, Vec::new())
+	}
+}
+parameter_types! {
+	pub const AssetDeposit: Balance = deposit(1, 190);
+	pub const AssetAccountDeposit: Balance = deposit(1, 16);
+	pub const AssetsMetadataDepositBase: Balance = deposit(1, 68);
+	pub const AssetsMetadataDepositPerByte: Balance = deposit(0, 1);
+	pub const AssetsApprovalDeposit: Balance = EXISTENTIAL_DEPOSIT;
+}
+
+/// The fungible assets, such as reserve-backed stablecoins, the marketplace orders can be paid
+/// in besides the native currency.
+///
+/// Only the root origin can create an asset, owned by the treasury, so that the marketplace
+/// can't be paid in assets created by arbitrary accounts. The force origin can also create
+/// assets with any owner through `force_create`.
+impl pallet_assets::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type AssetId = u32;
+	type AssetIdParameter = parity_scale_codec::Compact<u32>;
+	type Currency = Balances;
+	type CreateOrigin =
+		AsEnsureOriginWithArg<frame_system::EnsureRootWithSuccess<AccountId, TreasuryAccount>>;
+	type ForceOrigin = RootOrCouncilTwoThirdsMajority;
+	type AssetDeposit = AssetDeposit;
+	type AssetAccountDeposit = AssetAccountDeposit;
+	type MetadataDepositBase = AssetsMetadataDepositBase;
+	type MetadataDepositPerByte = AssetsMetadataDepositPerByte;
+	type ApprovalDeposit = AssetsApprovalDeposit;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Holder = AssetsHolder;
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+impl pallet_assets_holder::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
 }
+
+parameter_types! {
+	pub const MarketplaceMaxRoyalty: Perbill = Perbill::from_percent(10);
+	// One week, in milliseconds.
+	pub const MarketplaceEscrowTimeout: Moment = 7 * 24 * 60 * 60 * 1000;
+	// One week, in milliseconds.
+	pub const MarketplaceMaxNonceLifetime: Moment = 7 * 24 * 60 * 60 * 1000;
+}
+
 impl pallet_marketplace::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type Currency = Balances;
+	type Assets = pallet_marketplace::AssetsWithHolds<AssetsHolder, Assets>;
 	type Escrow = EscrowImpl;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type MinOrderDuration = ConstU64<10>;
+	type ExpirationGranularity = ConstU64<60_000>;
+	type MaxRoyalty = MarketplaceMaxRoyalty;
+	type MaxOrdersPerAccount = ConstU32<100>;
+	type MaxBundleItems = ConstU32<10>;
+	type MaxFeeSigners = ConstU32<5>;
+	type MaxRecentSales = ConstU32<20>;
+	type EscrowTimeout = MarketplaceEscrowTimeout;
 	type NonceStringLimit = ConstU32<50>;
+	type MaxNonceLifetime = MarketplaceMaxNonceLifetime;
 	type Signature = Signature;
 	type Signer = <Signature as Verify>::Signer;
 	type WeightInfo = weights::pallet_marketplace::WeightInfo<Runtime>;
@@ -788,9 +886,13 @@ impl pallet_dmarket::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type Currency = Balances;
+	type Assets = Assets;
 	type Signature = Signature;
 	type Signer = <Signature as Verify>::Signer;
 	type Domain = DOMAIN;
+	type ExpirationGranularity = ConstU64<60_000>;
+	type MaxBatchItems = ConstU32<50>;
+	type MaxFeeRecipients = ConstU32<5>;
 	type WeightInfo = weights::pallet_dmarket::WeightInfo<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
@@ -824,6 +926,24 @@ impl pallet_vesting::Config for Runtime {
 	const MAX_VESTING_SCHEDULES: u32 = 28;
 }
 
+parameter_types! {
+	pub MbmServiceWeight: Weight = Perbill::from_percent(80) * RuntimeBlockWeights::get().max_block;
+}
+
+impl pallet_migrations::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Migrations = SteppedMigrations;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
+	type CursorMaxLen = ConstU32<65_536>;
+	type IdentifierMaxLen = ConstU32<256>;
+	type MigrationStatusHandler = ();
+	type FailedMigrationHandler = frame_support::migrations::FreezeChainOnFailedMigration;
+	type MaxServiceWeight = MbmServiceWeight;
+	type WeightInfo = pallet_migrations::weights::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
 	pub const ProxyDepositBase: Balance = deposit(1, 8);
 	pub const ProxyDepositFactor: Balance = deposit(0, 33);
@@ -1132,6 +1252,9 @@ construct_runtime!(
 		// Other pallets.
 		Proxy: pallet_proxy = 40,
 		Vesting: pallet_vesting = 41,
+		MultiBlockMigrations: pallet_migrations = 42,
+		Assets: pallet_assets = 43,
+		AssetsHolder: pallet_assets_holder = 44,
 
 		Escrow: pallet_escrow = 50,
 		MythProxy: pallet_myth_proxy = 51,
@@ -1145,6 +1268,7 @@ mod benches {
 		[cumulus_pallet_parachain_system, ParachainSystem]
 		[cumulus_pallet_xcmp_queue, XcmpQueue]
 		[frame_system, SystemBench::<Runtime>]
+		[pallet_assets, Assets]
 		[pallet_balances, Balances]
 		[pallet_nfts, Nfts]
 		[pallet_marketplace, Marketplace]
@@ -1156,6 +1280,7 @@ mod benches {
 		[pallet_escrow, Escrow]
 		[pallet_marketplace, Marketplace]
 		[pallet_message_queue, MessageQueue]
+		[pallet_migrations, MultiBlockMigrations]
 		[pallet_multibatching, Multibatching]
 		[pallet_multisig, Multisig]
 		[pallet_myth_proxy, MythProxy]
@@ -1481,16 +1606,58 @@ impl_runtime_apis! {
 		}
 	}
 
-	impl pallet_dmarket::DmarketApi<Block, AccountId, Balance, Moment, Hash> for Runtime {
-		fn hash_ask_bid_data(trade: TradeParams<Balance, Item, u64>)-> (Hash, Hash) {
-			Dmarket::hash_ask_bid_data(&trade)
+	impl pallet_dmarket::DmarketApi<Block, AccountId, Balance, Moment, Hash, CollectionId, u32, Signature> for Runtime {
+		fn hash_ask_bid_data(seller: AccountId, buyer: AccountId, trade: TradeParams<CollectionId, Balance, Item, Moment, Vec<FeeRecipient<AccountId, Balance>>, u32>)-> (Hash, Hash) {
+			Dmarket::hash_ask_bid_data(&seller, &buyer, &trade)
 		}
-		fn get_ask_message(caller: AccountId, fee_address: AccountId, trade: TradeParams<Balance, Item, Moment>) -> Vec<u8> {
+		fn get_ask_message(caller: AccountId, fee_address: AccountId, trade: TradeParams<CollectionId, Balance, Item, Moment, Vec<FeeRecipient<AccountId, Balance>>, u32>) -> Vec<u8> {
 			Dmarket::get_ask_message(&caller, &fee_address, &trade)
 		}
-		fn get_bid_message(caller: AccountId, fee_address: AccountId, trade: TradeParams<Balance, Item, Moment>) -> Vec<u8> {
+		fn get_bid_message(caller: AccountId, fee_address: AccountId, trade: TradeParams<CollectionId, Balance, Item, Moment, Vec<FeeRecipient<AccountId, Balance>>, u32>) -> Vec<u8> {
 			Dmarket::get_bid_message(&caller, &fee_address, &trade)
 		}
+		fn get_ask_typed_data(caller: AccountId, fee_address: AccountId, trade: TradeParams<CollectionId, Balance, Item, Moment, Vec<FeeRecipient<AccountId, Balance>>, u32>) -> Vec<u8> {
+			Dmarket::get_ask_typed_data(&caller, &fee_address, &trade)
+		}
+		fn get_bid_typed_data(caller: AccountId, fee_address: AccountId, trade: TradeParams<CollectionId, Balance, Item, Moment, Vec<FeeRecipient<AccountId, Balance>>, u32>) -> Vec<u8> {
+			Dmarket::get_bid_typed_data(&caller, &fee_address, &trade)
+		}
+		fn hash_batch_ask_bid_data(seller: AccountId, buyer: AccountId, trade: BatchTradeParams<CollectionId, Balance, Vec<Item>, Moment, Vec<FeeRecipient<AccountId, Balance>>, u32>) -> (Hash, Hash) {
+			Dmarket::hash_batch_ask_bid_data(&seller, &buyer, &trade)
+		}
+		fn get_batch_ask_message(caller: AccountId, fee_address: AccountId, trade: BatchTradeParams<CollectionId, Balance, Vec<Item>, Moment, Vec<FeeRecipient<AccountId, Balance>>, u32>) -> Vec<u8> {
+			Dmarket::get_batch_ask_message(&caller, &fee_address, &trade)
+		}
+		fn get_batch_bid_message(caller: AccountId, fee_address: AccountId, trade: BatchTradeParams<CollectionId, Balance, Vec<Item>, Moment, Vec<FeeRecipient<AccountId, Balance>>, u32>) -> Vec<u8> {
+			Dmarket::get_batch_bid_message(&caller, &fee_address, &trade)
+		}
+		fn validate_trade(caller: AccountId, seller: AccountId, buyer: AccountId, trade: TradeParams<CollectionId, Balance, Item, Moment, Vec<FeeRecipient<AccountId, Balance>>, u32>, signatures: TradeSignatures<Signature>, fee_address: AccountId) -> Result<(), TradeError> {
+			Dmarket::validate_trade(&caller, &seller, &buyer, trade, signatures, &fee_address)
+		}
+	}
+
+	impl pallet_marketplace::MarketplaceApi<Block, AccountId, Balance, Moment, CollectionId, u32> for Runtime {
+		fn ask_for(collection: CollectionId, item: pallet_nfts::ItemId) -> Option<pallet_marketplace::Ask<AccountId, Balance, Moment, AccountId, u32>> {
+			Marketplace::ask_for(collection, item)
+		}
+		fn bids_for(collection: CollectionId, item: pallet_nfts::ItemId) -> Vec<(Balance, pallet_marketplace::Bid<AccountId, Balance, Moment, u32>)> {
+			Marketplace::bids_for(collection, item)
+		}
+		fn best_bid(collection: CollectionId, item: pallet_nfts::ItemId) -> Option<(Balance, pallet_marketplace::Bid<AccountId, Balance, Moment, u32>)> {
+			Marketplace::best_bid(collection, item)
+		}
+		fn orders_by_account(who: AccountId) -> Vec<pallet_marketplace::OrderKey<CollectionId, pallet_nfts::ItemId, Balance, AccountId>> {
+			Marketplace::orders_by_account(&who)
+		}
+		fn bid_payment(price: Balance, fee: Balance) -> Option<Balance> {
+			Marketplace::calc_bid_payment(&price, &fee).ok()
+		}
+		fn collection_stats(collection: CollectionId, asset: Option<u32>) -> Option<pallet_marketplace::CollectionStats<Balance>> {
+			Marketplace::collection_stats(collection, asset)
+		}
+		fn recent_sales(collection: CollectionId) -> Vec<pallet_marketplace::Sale<AccountId, pallet_nfts::ItemId, Balance, Moment, u32>> {
+			Marketplace::recent_sales(collection)
+		}
 	}
 
 	impl cumulus_primitives_aura::AuraUnincludedSegmentApi<Block> for Runtime {
@@ -51,8 +51,8 @@ impl<T: frame_system::Config> pallet_marketplace::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: `Marketplace::Authority` (r:1 w:0)
 	/// Proof: `Marketplace::Authority` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
-	/// Storage: `Marketplace::FeeSigner` (r:1 w:1)
-	/// Proof: `Marketplace::FeeSigner` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::FeeSigners` (r:1 w:1)
+	/// Proof: `Marketplace::FeeSigners` (`max_values`: Some(1), `max_size`: Some(216), added: 711, mode: `MaxEncodedLen`)
 	fn set_fee_signer_address() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `87`
@@ -77,20 +77,26 @@ impl<T: frame_system::Config> pallet_marketplace::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Marketplace::AccountOrders` (r:1 w:1)
+	/// Proof: `Marketplace::AccountOrders` (`max_values`: None, `max_size`: Some(7269), added: 9744, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Item` (r:1 w:1)
 	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(637), added: 3112, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Marketplace::Nonces` (r:1 w:1)
-	/// Proof: `Marketplace::Nonces` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
-	/// Storage: `Marketplace::FeeSigner` (r:1 w:0)
-	/// Proof: `Marketplace::FeeSigner` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Proof: `Marketplace::Nonces` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::NextNonceExpirationSlot` (r:1 w:1)
+	/// Proof: `Marketplace::NextNonceExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::NonceExpirations` (r:0 w:1)
+	/// Proof: `Marketplace::NonceExpirations` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::FeeSigners` (r:1 w:0)
+	/// Proof: `Marketplace::FeeSigners` (`max_values`: Some(1), `max_size`: Some(216), added: 711, mode: `MaxEncodedLen`)
 	/// Storage: `Marketplace::Bids` (r:1 w:1)
 	/// Proof: `Marketplace::Bids` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:2 w:2)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
 	/// Storage: `Marketplace::Asks` (r:1 w:1)
-	/// Proof: `Marketplace::Asks` (`max_values`: None, `max_size`: Some(161), added: 2636, mode: `MaxEncodedLen`)
+	/// Proof: `Marketplace::Asks` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
 	/// Storage: `Marketplace::PayoutAddress` (r:1 w:0)
 	/// Proof: `Marketplace::PayoutAddress` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
 	/// Storage: `Escrow::Deposits` (r:1 w:1)
@@ -109,6 +115,24 @@ impl<T: frame_system::Config> pallet_marketplace::WeightInfo for WeightInfo<T> {
 	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
 	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(151), added: 2626, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Royalties` (r:1 w:0)
+	/// Proof: `Marketplace::Royalties` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::CollectionFeePolicies` (r:1 w:0)
+	/// Proof: `Marketplace::CollectionFeePolicies` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::DefaultFeePolicy` (r:1 w:0)
+	/// Proof: `Marketplace::DefaultFeePolicy` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Paused` (r:1 w:0)
+	/// Proof: `Marketplace::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::HaltedCollections` (r:1 w:0)
+	/// Proof: `Marketplace::HaltedCollections` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::TradeStats` (r:1 w:1)
+	/// Proof: `Marketplace::TradeStats` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::RecentSales` (r:1 w:1)
+	/// Proof: `Marketplace::RecentSales` (`max_values`: None, `max_size`: Some(1733), added: 4208, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::NextEscrowId` (r:1 w:1)
+	/// Proof: `Marketplace::NextEscrowId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Escrows` (r:0 w:1)
+	/// Proof: `Marketplace::Escrows` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
 	fn create_order() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1778`
@@ -116,9 +140,11 @@ impl<T: frame_system::Config> pallet_marketplace::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 498_464_000 picoseconds.
 		Weight::from_parts(507_963_000, 0)
 			.saturating_add(Weight::from_parts(0, 6338))
-			.saturating_add(T::DbWeight::get().reads(14))
-			.saturating_add(T::DbWeight::get().writes(13))
+			.saturating_add(T::DbWeight::get().reads(24))
+			.saturating_add(T::DbWeight::get().writes(20))
 	}
+	/// Storage: `Marketplace::AccountOrders` (r:1 w:1)
+	/// Proof: `Marketplace::AccountOrders` (`max_values`: None, `max_size`: Some(7269), added: 9744, mode: `MaxEncodedLen`)
 	/// Storage: `Marketplace::Authority` (r:1 w:0)
 	/// Proof: `Marketplace::Authority` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
 	/// Storage: `Marketplace::Bids` (r:1 w:1)
@@ -132,7 +158,577 @@ impl<T: frame_system::Config> pallet_marketplace::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 87_731_000 picoseconds.
 		Weight::from_parts(89_821_000, 0)
 			.saturating_add(Weight::from_parts(0, 3664))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Marketplace::NextExpirationSlot` (r:1 w:0)
+	/// Proof: `Marketplace::NextExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::OrderExpirations` (r:1 w:1)
+	/// Proof: `Marketplace::OrderExpirations` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Auctions` (r:1 w:1)
+	/// Proof: `Marketplace::Auctions` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Royalties` (r:1 w:0)
+	/// Proof: `Marketplace::Royalties` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::PayoutAddress` (r:1 w:0)
+	/// Proof: `Marketplace::PayoutAddress` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:1 w:1)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(597), added: 3072, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Attribute` (r:1 w:1)
+	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(455), added: 2930, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Collection` (r:1 w:1)
+	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Account` (r:0 w:2)
+	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
+	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
+	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Paused` (r:1 w:0)
+	/// Proof: `Marketplace::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::HaltedCollections` (r:1 w:0)
+	/// Proof: `Marketplace::HaltedCollections` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::TradeStats` (r:1 w:1)
+	/// Proof: `Marketplace::TradeStats` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::RecentSales` (r:1 w:1)
+	/// Proof: `Marketplace::RecentSales` (`max_values`: None, `max_size`: Some(1733), added: 4208, mode: `MaxEncodedLen`)
+	fn expire_next_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1450`
+		//  Estimated: `4326`
+		// Minimum execution time: 340_187_000 picoseconds.
+		Weight::from_parts(350_392_000, 0)
+			.saturating_add(Weight::from_parts(0, 4326))
+			.saturating_add(T::DbWeight::get().reads(16))
+			.saturating_add(T::DbWeight::get().writes(14))
+	}
+	/// Storage: `Nfts::Collection` (r:1 w:0)
+	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Authority` (r:1 w:0)
+	/// Proof: `Marketplace::Authority` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Royalties` (r:0 w:1)
+	/// Proof: `Marketplace::Royalties` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn set_collection_royalty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `352`
+		//  Estimated: `3549`
+		// Minimum execution time: 17_320_000 picoseconds.
+		Weight::from_parts(17_940_000, 0)
+			.saturating_add(Weight::from_parts(0, 3549))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:1 w:0)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(597), added: 3072, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Auctions` (r:1 w:1)
+	/// Proof: `Marketplace::Auctions` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Nonces` (r:1 w:1)
+	/// Proof: `Marketplace::Nonces` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::NextNonceExpirationSlot` (r:1 w:1)
+	/// Proof: `Marketplace::NextNonceExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::NonceExpirations` (r:0 w:1)
+	/// Proof: `Marketplace::NonceExpirations` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::FeeSigners` (r:1 w:0)
+	/// Proof: `Marketplace::FeeSigners` (`max_values`: Some(1), `max_size`: Some(216), added: 711, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Attribute` (r:1 w:1)
+	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(455), added: 2930, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Collection` (r:1 w:1)
+	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::NextExpirationSlot` (r:1 w:1)
+	/// Proof: `Marketplace::NextExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::OrderExpirations` (r:0 w:1)
+	/// Proof: `Marketplace::OrderExpirations` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::CollectionFeePolicies` (r:1 w:0)
+	/// Proof: `Marketplace::CollectionFeePolicies` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::DefaultFeePolicy` (r:1 w:0)
+	/// Proof: `Marketplace::DefaultFeePolicy` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Paused` (r:1 w:0)
+	/// Proof: `Marketplace::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::HaltedCollections` (r:1 w:0)
+	/// Proof: `Marketplace::HaltedCollections` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn create_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `795`
+		//  Estimated: `4062`
+		// Minimum execution time: 201_433_000 picoseconds.
+		Weight::from_parts(207_475_000, 0)
+			.saturating_add(Weight::from_parts(0, 4062))
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `Marketplace::Auctions` (r:1 w:1)
+	/// Proof: `Marketplace::Auctions` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Nonces` (r:1 w:1)
+	/// Proof: `Marketplace::Nonces` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::NextNonceExpirationSlot` (r:1 w:1)
+	/// Proof: `Marketplace::NextNonceExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::NonceExpirations` (r:0 w:1)
+	/// Proof: `Marketplace::NonceExpirations` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::FeeSigners` (r:1 w:0)
+	/// Proof: `Marketplace::FeeSigners` (`max_values`: Some(1), `max_size`: Some(216), added: 711, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Royalties` (r:1 w:0)
+	/// Proof: `Marketplace::Royalties` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::PayoutAddress` (r:1 w:0)
+	/// Proof: `Marketplace::PayoutAddress` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:1 w:1)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(597), added: 3072, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Attribute` (r:1 w:1)
+	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(455), added: 2930, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Collection` (r:1 w:1)
+	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::OrderExpirations` (r:0 w:1)
+	/// Proof: `Marketplace::OrderExpirations` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Account` (r:0 w:2)
+	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
+	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
+	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::CollectionFeePolicies` (r:1 w:0)
+	/// Proof: `Marketplace::CollectionFeePolicies` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::DefaultFeePolicy` (r:1 w:0)
+	/// Proof: `Marketplace::DefaultFeePolicy` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Paused` (r:1 w:0)
+	/// Proof: `Marketplace::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::HaltedCollections` (r:1 w:0)
+	/// Proof: `Marketplace::HaltedCollections` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::TradeStats` (r:1 w:1)
+	/// Proof: `Marketplace::TradeStats` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::RecentSales` (r:1 w:1)
+	/// Proof: `Marketplace::RecentSales` (`max_values`: None, `max_size`: Some(1733), added: 4208, mode: `MaxEncodedLen`)
+	fn bid_on_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1512`
+		//  Estimated: `6050`
+		// Minimum execution time: 428_551_000 picoseconds.
+		Weight::from_parts(441_407_000, 0)
+			.saturating_add(Weight::from_parts(0, 6050))
+			.saturating_add(T::DbWeight::get().reads(21))
+			.saturating_add(T::DbWeight::get().writes(17))
+	}
+	/// Storage: `Marketplace::Auctions` (r:1 w:1)
+	/// Proof: `Marketplace::Auctions` (`max_values`: None, `max_size`: Some(190), added: 2665, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Royalties` (r:1 w:0)
+	/// Proof: `Marketplace::Royalties` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::PayoutAddress` (r:1 w:0)
+	/// Proof: `Marketplace::PayoutAddress` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:1 w:1)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(597), added: 3072, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Attribute` (r:1 w:1)
+	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(455), added: 2930, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Collection` (r:1 w:1)
+	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::OrderExpirations` (r:0 w:1)
+	/// Proof: `Marketplace::OrderExpirations` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Account` (r:0 w:2)
+	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
+	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
+	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Paused` (r:1 w:0)
+	/// Proof: `Marketplace::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::HaltedCollections` (r:1 w:0)
+	/// Proof: `Marketplace::HaltedCollections` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::TradeStats` (r:1 w:1)
+	/// Proof: `Marketplace::TradeStats` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::RecentSales` (r:1 w:1)
+	/// Proof: `Marketplace::RecentSales` (`max_values`: None, `max_size`: Some(1733), added: 4208, mode: `MaxEncodedLen`)
+	fn settle_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1398`
+		//  Estimated: `4062`
+		// Minimum execution time: 331_276_000 picoseconds.
+		Weight::from_parts(341_214_000, 0)
+			.saturating_add(Weight::from_parts(0, 4062))
+			.saturating_add(T::DbWeight::get().reads(14))
+			.saturating_add(T::DbWeight::get().writes(13))
+	}
+	/// Storage: `Marketplace::AccountOrders` (r:1 w:1)
+	/// Proof: `Marketplace::AccountOrders` (`max_values`: None, `max_size`: Some(7269), added: 9744, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Bids` (r:100 w:100)
+	/// Proof: `Marketplace::Bids` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::OrderExpirations` (r:0 w:100)
+	/// Proof: `Marketplace::OrderExpirations` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 99]`.
+	/// The range of component `b` is `[0, 10]`.
+	fn cancel_all_orders(n: u32, b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `231 + n * (131 ±0)`
+		//  Estimated: `10734 + n * (2609 ±0)`
+		// Minimum execution time: 31_204_000 picoseconds.
+		Weight::from_parts(32_140_000, 0)
+			.saturating_add(Weight::from_parts(0, 10734))
+			// Standard Error: 2_165_000
+			.saturating_add(Weight::from_parts(43_312_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(19_744_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 3072).saturating_mul(b.into()))
+	}
+	/// Storage: `Marketplace::Authority` (r:1 w:0)
+	/// Proof: `Marketplace::Authority` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::AccountOrders` (r:1 w:1)
+	/// Proof: `Marketplace::AccountOrders` (`max_values`: None, `max_size`: Some(7269), added: 9744, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Bids` (r:100 w:100)
+	/// Proof: `Marketplace::Bids` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::OrderExpirations` (r:0 w:100)
+	/// Proof: `Marketplace::OrderExpirations` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 99]`.
+	/// The range of component `b` is `[0, 10]`.
+	fn force_cancel_all_orders(n: u32, b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `274 + n * (131 ±0)`
+		//  Estimated: `10734 + n * (2609 ±0)`
+		// Minimum execution time: 34_987_000 picoseconds.
+		Weight::from_parts(36_036_000, 0)
+			.saturating_add(Weight::from_parts(0, 10734))
+			// Standard Error: 2_169_000
+			.saturating_add(Weight::from_parts(43_380_000, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(19_744_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 3072).saturating_mul(b.into()))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Nonces` (r:1 w:1)
+	/// Proof: `Marketplace::Nonces` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::NextNonceExpirationSlot` (r:1 w:1)
+	/// Proof: `Marketplace::NextNonceExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::NonceExpirations` (r:0 w:1)
+	/// Proof: `Marketplace::NonceExpirations` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::FeeSigners` (r:1 w:0)
+	/// Proof: `Marketplace::FeeSigners` (`max_values`: Some(1), `max_size`: Some(216), added: 711, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::BundleAsks` (r:1 w:1)
+	/// Proof: `Marketplace::BundleAsks` (`max_values`: None, `max_size`: Some(578), added: 3053, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::BundleBids` (r:1 w:0)
+	/// Proof: `Marketplace::BundleBids` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:10 w:0)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(597), added: 3072, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Attribute` (r:10 w:10)
+	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(455), added: 2930, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionConfigOf` (r:10 w:0)
+	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::AccountOrders` (r:1 w:1)
+	/// Proof: `Marketplace::AccountOrders` (`max_values`: None, `max_size`: Some(7269), added: 9744, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::NextExpirationSlot` (r:1 w:1)
+	/// Proof: `Marketplace::NextExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::OrderExpirations` (r:0 w:1)
+	/// Proof: `Marketplace::OrderExpirations` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::CollectionFeePolicies` (r:10 w:0)
+	/// Proof: `Marketplace::CollectionFeePolicies` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::DefaultFeePolicy` (r:1 w:0)
+	/// Proof: `Marketplace::DefaultFeePolicy` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Paused` (r:1 w:0)
+	/// Proof: `Marketplace::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::HaltedCollections` (r:10 w:0)
+	/// Proof: `Marketplace::HaltedCollections` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::TradeStats` (r:10 w:10)
+	/// Proof: `Marketplace::TradeStats` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::RecentSales` (r:10 w:10)
+	/// Proof: `Marketplace::RecentSales` (`max_values`: None, `max_size`: Some(1733), added: 4208, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 10]`.
+	fn create_bundle_order(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612 + n * (214 ±0)`
+		//  Estimated: `10734 + n * (3072 ±0)`
+		// Minimum execution time: 148_211_000 picoseconds.
+		Weight::from_parts(152_657_000, 0)
+			.saturating_add(Weight::from_parts(0, 10734))
+			// Standard Error: 1_740_000
+			.saturating_add(Weight::from_parts(34_817_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3072).saturating_mul(n.into()))
+	}
+	/// Storage: `Marketplace::BundleAsks` (r:1 w:1)
+	/// Proof: `Marketplace::BundleAsks` (`max_values`: None, `max_size`: Some(578), added: 3053, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Authority` (r:1 w:0)
+	/// Proof: `Marketplace::Authority` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:10 w:0)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(597), added: 3072, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Attribute` (r:10 w:10)
+	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(455), added: 2930, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionConfigOf` (r:10 w:0)
+	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::AccountOrders` (r:1 w:1)
+	/// Proof: `Marketplace::AccountOrders` (`max_values`: None, `max_size`: Some(7269), added: 9744, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::OrderExpirations` (r:0 w:1)
+	/// Proof: `Marketplace::OrderExpirations` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 10]`.
+	fn cancel_bundle_order(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `547 + n * (214 ±0)`
+		//  Estimated: `10734 + n * (3072 ±0)`
+		// Minimum execution time: 41_902_000 picoseconds.
+		Weight::from_parts(43_159_000, 0)
+			.saturating_add(Weight::from_parts(0, 10734))
+			// Standard Error: 987_000
+			.saturating_add(Weight::from_parts(19_744_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3072).saturating_mul(n.into()))
+	}
+	/// Storage: `Marketplace::AccountOrders` (r:2 w:2)
+	/// Proof: `Marketplace::AccountOrders` (`max_values`: None, `max_size`: Some(7269), added: 9744, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:1 w:1)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(597), added: 3072, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Nonces` (r:1 w:1)
+	/// Proof: `Marketplace::Nonces` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::NextNonceExpirationSlot` (r:1 w:1)
+	/// Proof: `Marketplace::NextNonceExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::NonceExpirations` (r:0 w:1)
+	/// Proof: `Marketplace::NonceExpirations` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::FeeSigners` (r:1 w:0)
+	/// Proof: `Marketplace::FeeSigners` (`max_values`: Some(1), `max_size`: Some(216), added: 711, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Bids` (r:2 w:2)
+	/// Proof: `Marketplace::Bids` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Asks` (r:1 w:1)
+	/// Proof: `Marketplace::Asks` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::PayoutAddress` (r:1 w:0)
+	/// Proof: `Marketplace::PayoutAddress` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Attribute` (r:1 w:1)
+	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(455), added: 2930, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Collection` (r:1 w:1)
+	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Account` (r:0 w:2)
+	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
+	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
+	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Royalties` (r:1 w:0)
+	/// Proof: `Marketplace::Royalties` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::OrderExpirations` (r:0 w:2)
+	/// Proof: `Marketplace::OrderExpirations` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::CollectionFeePolicies` (r:1 w:0)
+	/// Proof: `Marketplace::CollectionFeePolicies` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::DefaultFeePolicy` (r:1 w:0)
+	/// Proof: `Marketplace::DefaultFeePolicy` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Paused` (r:1 w:0)
+	/// Proof: `Marketplace::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::HaltedCollections` (r:1 w:0)
+	/// Proof: `Marketplace::HaltedCollections` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::TradeStats` (r:1 w:1)
+	/// Proof: `Marketplace::TradeStats` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::RecentSales` (r:1 w:1)
+	/// Proof: `Marketplace::RecentSales` (`max_values`: None, `max_size`: Some(1733), added: 4208, mode: `MaxEncodedLen`)
+	fn update_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1391`
+		//  Estimated: `10734`
+		// Minimum execution time: 402_318_000 picoseconds.
+		Weight::from_parts(414_387_000, 0)
+			.saturating_add(Weight::from_parts(0, 10734))
+			.saturating_add(T::DbWeight::get().reads(23))
+			.saturating_add(T::DbWeight::get().writes(20))
+	}
+	/// Storage: `Marketplace::NextNonceExpirationSlot` (r:1 w:0)
+	/// Proof: `Marketplace::NextNonceExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::NonceExpirations` (r:1 w:1)
+	/// Proof: `Marketplace::NonceExpirations` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Nonces` (r:0 w:1)
+	/// Proof: `Marketplace::Nonces` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	fn prune_next_nonce() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `198`
+		//  Estimated: `3532`
+		// Minimum execution time: 14_207_000 picoseconds.
+		Weight::from_parts(14_633_000, 0)
+			.saturating_add(Weight::from_parts(0, 3532))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Marketplace::Authority` (r:1 w:0)
+	/// Proof: `Marketplace::Authority` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::FeeSigners` (r:1 w:1)
+	/// Proof: `Marketplace::FeeSigners` (`max_values`: Some(1), `max_size`: Some(216), added: 711, mode: `MaxEncodedLen`)
+	fn add_fee_signer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180`
+		//  Estimated: `1701`
+		// Minimum execution time: 13_912_000 picoseconds.
+		Weight::from_parts(14_329_000, 0)
+			.saturating_add(Weight::from_parts(0, 1701))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Marketplace::Authority` (r:1 w:0)
+	/// Proof: `Marketplace::Authority` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::FeeSigners` (r:1 w:1)
+	/// Proof: `Marketplace::FeeSigners` (`max_values`: Some(1), `max_size`: Some(216), added: 711, mode: `MaxEncodedLen`)
+	fn remove_fee_signer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `223`
+		//  Estimated: `1701`
+		// Minimum execution time: 14_375_000 picoseconds.
+		Weight::from_parts(14_806_000, 0)
+			.saturating_add(Weight::from_parts(0, 1701))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Marketplace::Authority` (r:1 w:0)
+	/// Proof: `Marketplace::Authority` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::CollectionFeePolicies` (r:0 w:1)
+	/// Proof: `Marketplace::CollectionFeePolicies` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	fn set_fee_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180`
+		//  Estimated: `1505`
+		// Minimum execution time: 12_483_000 picoseconds.
+		Weight::from_parts(12_857_000, 0)
+			.saturating_add(Weight::from_parts(0, 1505))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Marketplace::Authority` (r:1 w:0)
+	/// Proof: `Marketplace::Authority` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::Paused` (r:0 w:1)
+	/// Proof: `Marketplace::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_trading_paused() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180`
+		//  Estimated: `1505`
+		// Minimum execution time: 11_264_000 picoseconds.
+		Weight::from_parts(11_601_000, 0)
+			.saturating_add(Weight::from_parts(0, 1505))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Marketplace::Authority` (r:1 w:0)
+	/// Proof: `Marketplace::Authority` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `Marketplace::HaltedCollections` (r:0 w:1)
+	/// Proof: `Marketplace::HaltedCollections` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn set_collection_halted() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180`
+		//  Estimated: `1505`
+		// Minimum execution time: 11_893_000 picoseconds.
+		Weight::from_parts(12_249_000, 0)
+			.saturating_add(Weight::from_parts(0, 1505))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Marketplace::Escrows` (r:1 w:1)
+	/// Proof: `Marketplace::Escrows` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `Escrow::Deposits` (r:1 w:1)
+	/// Proof: `Escrow::Deposits` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	fn confirm_receipt() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `3664`
+		// Minimum execution time: 48_213_000 picoseconds.
+		Weight::from_parts(49_659_000, 0)
+			.saturating_add(Weight::from_parts(0, 3664))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Marketplace::Escrows` (r:1 w:1)
+	/// Proof: `Marketplace::Escrows` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Escrow::Deposits` (r:1 w:1)
+	/// Proof: `Escrow::Deposits` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	fn release_escrow() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `3664`
+		// Minimum execution time: 50_127_000 picoseconds.
+		Weight::from_parts(51_630_000, 0)
+			.saturating_add(Weight::from_parts(0, 3664))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Marketplace::Escrows` (r:1 w:1)
+	/// Proof: `Marketplace::Escrows` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn dispute_escrow() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `245`
+		//  Estimated: `3586`
+		// Minimum execution time: 14_382_000 picoseconds.
+		Weight::from_parts(14_813_000, 0)
+			.saturating_add(Weight::from_parts(0, 3586))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Marketplace::Escrows` (r:1 w:1)
+	/// Proof: `Marketplace::Escrows` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+	/// Storage: `Escrow::Deposits` (r:1 w:1)
+	/// Proof: `Escrow::Deposits` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn resolve_escrow_dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `764`
+		//  Estimated: `6172`
+		// Minimum execution time: 82_541_000 picoseconds.
+		Weight::from_parts(85_017_000, 0)
+			.saturating_add(Weight::from_parts(0, 6172))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 }
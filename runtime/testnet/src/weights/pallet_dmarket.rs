@@ -39,9 +39,9 @@ pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_dmarket::WeightInfo for WeightInfo<T> {
 	/// Storage: `Nfts::Collection` (r:1 w:0)
 	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(169), added: 2644, mode: `MaxEncodedLen`)
-	/// Storage: `Dmarket::DmarketCollection` (r:1 w:1)
-	/// Proof: `Dmarket::DmarketCollection` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
-	fn force_set_collection() -> Weight {
+	/// Storage: `Dmarket::DmarketCollections` (r:1 w:1)
+	/// Proof: `Dmarket::DmarketCollections` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn force_enable_collection() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `409`
 		//  Estimated: `3634`
@@ -51,10 +51,24 @@ impl<T: frame_system::Config> pallet_dmarket::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Dmarket::DmarketCollections` (r:1 w:1)
+	/// Proof: `Dmarket::DmarketCollections` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn force_disable_collection() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `215`
+		//  Estimated: `3513`
+		// Minimum execution time: 15_270_000 picoseconds.
+		Weight::from_parts(15_910_000, 0)
+			.saturating_add(Weight::from_parts(0, 3513))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `Dmarket::DmarketCollection` (r:1 w:0)
-	/// Proof: `Dmarket::DmarketCollection` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::Nonces` (r:2 w:0)
+	/// Proof: `Dmarket::Nonces` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::DmarketCollections` (r:1 w:0)
+	/// Proof: `Dmarket::DmarketCollections` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Item` (r:1 w:1)
 	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(637), added: 3112, mode: `MaxEncodedLen`)
 	/// Storage: `Dmarket::ClosedAsks` (r:1 w:1)
@@ -75,14 +89,152 @@ impl<T: frame_system::Config> pallet_dmarket::WeightInfo for WeightInfo<T> {
 	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
 	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(151), added: 2626, mode: `MaxEncodedLen`)
-	fn execute_trade() -> Weight {
+	/// Storage: `Dmarket::NextExpirationSlot` (r:1 w:1)
+	/// Proof: `Dmarket::NextExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedOrderExpirations` (r:0 w:2)
+	/// Proof: `Dmarket::ClosedOrderExpirations` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:5 w:5)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 5]`.
+	fn execute_trade(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1203 + n * (20 ±0)`
+		//  Estimated: `4102 + n * (2591 ±0)`
+		// Minimum execution time: 449_022_000 picoseconds.
+		Weight::from_parts(463_762_000, 0)
+			.saturating_add(Weight::from_parts(0, 4102))
+			// Standard Error: 24_000
+			.saturating_add(Weight::from_parts(58_914_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(10))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(n.into()))
+	}
+	/// Storage: `Dmarket::NextExpirationSlot` (r:1 w:1)
+	/// Proof: `Dmarket::NextExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedOrderExpirations` (r:2 w:1)
+	/// Proof: `Dmarket::ClosedOrderExpirations` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedAsks` (r:0 w:1)
+	/// Proof: `Dmarket::ClosedAsks` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::RemainingAskQuantity` (r:0 w:1)
+	/// Proof: `Dmarket::RemainingAskQuantity` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn prune_next_closed_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `3522`
+		// Minimum execution time: 21_304_000 picoseconds.
+		Weight::from_parts(22_018_000, 0)
+			.saturating_add(Weight::from_parts(0, 3522))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::Nonces` (r:2 w:0)
+	/// Proof: `Dmarket::Nonces` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::DmarketCollections` (r:1 w:0)
+	/// Proof: `Dmarket::DmarketCollections` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedAsks` (r:1 w:1)
+	/// Proof: `Dmarket::ClosedAsks` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedBids` (r:1 w:1)
+	/// Proof: `Dmarket::ClosedBids` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::RemainingAskQuantity` (r:1 w:1)
+	/// Proof: `Dmarket::RemainingAskQuantity` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::NextExpirationSlot` (r:1 w:1)
+	/// Proof: `Dmarket::NextExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:50 w:50)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(637), added: 3112, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Collection` (r:1 w:0)
+	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(169), added: 2644, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Attribute` (r:50 w:0)
+	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(495), added: 2970, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(142), added: 2617, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemConfigOf` (r:50 w:0)
+	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Account` (r:0 w:100)
+	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:50)
+	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:50)
+	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(151), added: 2626, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedOrderExpirations` (r:0 w:2)
+	/// Proof: `Dmarket::ClosedOrderExpirations` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:8 w:8)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 50]`.
+	/// The range of component `f` is `[0, 5]`.
+	fn execute_batch_trade(n: u32, f: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1117 + f * (20 ±0) + n * (182 ±0)`
+		//  Estimated: `3634 + f * (2591 ±0) + n * (3112 ±0)`
+		// Minimum execution time: 219_805_000 picoseconds.
+		Weight::from_parts(181_277_000, 0)
+			.saturating_add(Weight::from_parts(0, 3634))
+			// Standard Error: 58_000
+			.saturating_add(Weight::from_parts(51_420_000, 0).saturating_mul(n.into()))
+			// Standard Error: 24_000
+			.saturating_add(Weight::from_parts(41_372_000, 0).saturating_mul(f.into()))
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(f.into())))
+			.saturating_add(T::DbWeight::get().writes(9))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(f.into())))
+			.saturating_add(Weight::from_parts(0, 3112).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(f.into()))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:1 w:0)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(637), added: 3112, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedAsks` (r:1 w:1)
+	/// Proof: `Dmarket::ClosedAsks` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::NextExpirationSlot` (r:1 w:1)
+	/// Proof: `Dmarket::NextExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedOrderExpirations` (r:0 w:1)
+	/// Proof: `Dmarket::ClosedOrderExpirations` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	fn cancel_ask() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1203`
+		//  Measured:  `179`
 		//  Estimated: `4102`
-		// Minimum execution time: 342_372_000 picoseconds.
-		Weight::from_parts(357_072_000, 0)
+		// Minimum execution time: 131_651_000 picoseconds.
+		Weight::from_parts(133_203_000, 0)
 			.saturating_add(Weight::from_parts(0, 4102))
-			.saturating_add(T::DbWeight::get().reads(9))
-			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedBids` (r:1 w:1)
+	/// Proof: `Dmarket::ClosedBids` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::NextExpirationSlot` (r:1 w:1)
+	/// Proof: `Dmarket::NextExpirationSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Dmarket::ClosedOrderExpirations` (r:0 w:1)
+	/// Proof: `Dmarket::ClosedOrderExpirations` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	fn cancel_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `179`
+		//  Estimated: `3553`
+		// Minimum execution time: 132_102_000 picoseconds.
+		Weight::from_parts(133_770_000, 0)
+			.saturating_add(Weight::from_parts(0, 3553))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Dmarket::Nonces` (r:1 w:1)
+	/// Proof: `Dmarket::Nonces` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn bump_nonce() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3505`
+		// Minimum execution time: 11_480_000 picoseconds.
+		Weight::from_parts(11_902_000, 0)
+			.saturating_add(Weight::from_parts(0, 3505))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
 	}
 }